@@ -0,0 +1,336 @@
+//! The AES block cipher, computed with the AES-NI instructions.
+
+use v128::{i32x4, u8x16};
+use x86::{
+    Aes, __m128i, _mm_aesdec_si128, _mm_aesdeclast_si128, _mm_aesenc_si128,
+    _mm_aesenclast_si128, _mm_aesimc_si128, _mm_aeskeygenassist_si128,
+    _mm_shuffle_epi32, _mm_slli_si128, _mm_xor_si128,
+};
+
+/// The AES block cipher with a 128-bit key, as specified in FIPS-197.
+///
+/// The key is expanded once, when the cipher is created. Creating it
+/// requires an `Aes` token, so the CPU is known to support AES-NI and the
+/// blocks are encrypted without any further checks.
+///
+/// ```rust
+/// use stdsimd::cipher::Aes128;
+/// use stdsimd::vendor::Aes;
+///
+/// if let Some(aes) = Aes::detect() {
+///     let cipher = Aes128::new(aes, b"YELLOW SUBMARINE");
+///     let block = cipher.encrypt_block(b"sixteen byte msg");
+///     assert_eq!(&cipher.decrypt_block(&block), b"sixteen byte msg");
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Aes128 {
+    /// The round keys of the cipher.
+    enc: [__m128i; 11],
+    /// The round keys of the equivalent inverse cipher.
+    dec: [__m128i; 11],
+}
+
+impl Aes128 {
+    /// Expands `key` into the round keys for encryption and decryption.
+    pub fn new(_: Aes, key: &[u8; 16]) -> Aes128 {
+        let mut cipher = Aes128 {
+            enc: [__m128i::splat(0); 11],
+            dec: [__m128i::splat(0); 11],
+        };
+        unsafe {
+            expand_key_128(key, &mut cipher.enc);
+            inverse_keys(&cipher.enc, &mut cipher.dec);
+        }
+        cipher
+    }
+
+    /// Encrypts the 16 bytes of `block`.
+    pub fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        unsafe { encrypt(&self.enc, block) }
+    }
+
+    /// Decrypts the 16 bytes of `block`.
+    pub fn decrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        unsafe { decrypt(&self.dec, block) }
+    }
+}
+
+/// The AES block cipher with a 256-bit key, as specified in FIPS-197.
+///
+/// See `Aes128`, which it mirrors apart from the key length and the number
+/// of rounds.
+#[derive(Clone)]
+pub struct Aes256 {
+    /// The round keys of the cipher.
+    enc: [__m128i; 15],
+    /// The round keys of the equivalent inverse cipher.
+    dec: [__m128i; 15],
+}
+
+impl Aes256 {
+    /// Expands `key` into the round keys for encryption and decryption.
+    pub fn new(_: Aes, key: &[u8; 32]) -> Aes256 {
+        let mut cipher = Aes256 {
+            enc: [__m128i::splat(0); 15],
+            dec: [__m128i::splat(0); 15],
+        };
+        unsafe {
+            expand_key_256(key, &mut cipher.enc);
+            inverse_keys(&cipher.enc, &mut cipher.dec);
+        }
+        cipher
+    }
+
+    /// Encrypts the 16 bytes of `block`.
+    pub fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        unsafe { encrypt(&self.enc, block) }
+    }
+
+    /// Decrypts the 16 bytes of `block`.
+    pub fn decrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        unsafe { decrypt(&self.dec, block) }
+    }
+}
+
+/// The round constants of the key expansion.
+const RCON: [u8; 10] =
+    [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80, 0x1B, 0x36];
+
+/// Returns the four words following the words of `key` in the key
+/// expansion, given the word `t` they are derived from, broadcast to all
+/// four lanes.
+#[target_feature = "+aes"]
+unsafe fn next_words(key: __m128i, t: i32x4) -> __m128i {
+    // Word `i` of the result is the xor of `t` and the words `0..i + 1` of
+    // `key`, which the shifts accumulate.
+    let mut key = key;
+    key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+    _mm_xor_si128(key, __m128i::from(t))
+}
+
+/// Expands a 128-bit key into the 11 round keys of AES-128.
+#[target_feature = "+aes"]
+unsafe fn expand_key_128(key: &[u8; 16], enc: &mut [__m128i; 11]) {
+    enc[0] = __m128i::from(u8x16::load(key, 0));
+    for i in 0..10 {
+        let t = _mm_aeskeygenassist_si128(enc[i], RCON[i] as i32);
+        let t = _mm_shuffle_epi32(i32x4::from(t), 0xFF);
+        enc[i + 1] = next_words(enc[i], t);
+    }
+}
+
+/// Expands a 256-bit key into the 15 round keys of AES-256.
+#[target_feature = "+aes"]
+unsafe fn expand_key_256(key: &[u8; 32], enc: &mut [__m128i; 15]) {
+    enc[0] = __m128i::from(u8x16::load(key, 0));
+    enc[1] = __m128i::from(u8x16::load(key, 16));
+    for i in 0..7 {
+        // Even round keys rotate and substitute the last word of the
+        // previous round key, odd ones only substitute it.
+        let t = _mm_aeskeygenassist_si128(enc[2 * i + 1], RCON[i] as i32);
+        let t = _mm_shuffle_epi32(i32x4::from(t), 0xFF);
+        enc[2 * i + 2] = next_words(enc[2 * i], t);
+        if i < 6 {
+            let t = _mm_aeskeygenassist_si128(enc[2 * i + 2], 0);
+            let t = _mm_shuffle_epi32(i32x4::from(t), 0xAA);
+            enc[2 * i + 3] = next_words(enc[2 * i + 1], t);
+        }
+    }
+}
+
+/// Derives the round keys of the equivalent inverse cipher from the round
+/// keys `enc` of the cipher.
+#[target_feature = "+aes"]
+unsafe fn inverse_keys(enc: &[__m128i], dec: &mut [__m128i]) {
+    let n = enc.len() - 1;
+    dec[0] = enc[n];
+    for i in 1..n {
+        dec[i] = _mm_aesimc_si128(enc[n - i]);
+    }
+    dec[n] = enc[0];
+}
+
+/// Encrypts `block` with the round keys `keys`.
+#[target_feature = "+aes"]
+unsafe fn encrypt(keys: &[__m128i], block: &[u8; 16]) -> [u8; 16] {
+    let n = keys.len() - 1;
+    let mut x = __m128i::from(u8x16::load(block, 0));
+    x = _mm_xor_si128(x, keys[0]);
+    for &key in &keys[1..n] {
+        x = _mm_aesenc_si128(x, key);
+    }
+    let mut out = [0; 16];
+    u8x16::from(_mm_aesenclast_si128(x, keys[n])).store(&mut out, 0);
+    out
+}
+
+/// Decrypts `block` with the round keys `keys` of the equivalent inverse
+/// cipher.
+#[target_feature = "+aes"]
+unsafe fn decrypt(keys: &[__m128i], block: &[u8; 16]) -> [u8; 16] {
+    let n = keys.len() - 1;
+    let mut x = __m128i::from(u8x16::load(block, 0));
+    x = _mm_xor_si128(x, keys[0]);
+    for &key in &keys[1..n] {
+        x = _mm_aesdec_si128(x, key);
+    }
+    let mut out = [0; 16];
+    u8x16::from(_mm_aesdeclast_si128(x, keys[n])).store(&mut out, 0);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use clmul::Ghash;
+    use v128::u8x16;
+    use x86::{Aes, __m128i};
+
+    use super::{Aes128, Aes256};
+
+    /// Parses the hexadecimal notation of FIPS-197 into bytes.
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len() / 2)
+            .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn block(s: &str) -> [u8; 16] {
+        let mut b = [0; 16];
+        b.copy_from_slice(&hex(s));
+        b
+    }
+
+    fn key_256(s: &str) -> [u8; 32] {
+        let mut k = [0; 32];
+        k.copy_from_slice(&hex(s));
+        k
+    }
+
+    fn bytes(key: __m128i) -> [u8; 16] {
+        let mut b = [0; 16];
+        u8x16::from(key).store(&mut b, 0);
+        b
+    }
+
+    #[test]
+    fn aes128_fips197() {
+        let aes = match Aes::detect() {
+            Some(aes) => aes,
+            None => return,
+        };
+
+        // Appendix C.1.
+        let key = block("000102030405060708090a0b0c0d0e0f");
+        let cipher = Aes128::new(aes, &key);
+        let pt = block("00112233445566778899aabbccddeeff");
+        let ct = block("69c4e0d86a7b0430d8cdb78070b4c55a");
+        assert_eq!(cipher.encrypt_block(&pt), ct);
+        assert_eq!(cipher.decrypt_block(&ct), pt);
+
+        // Appendix B.
+        let key = block("2b7e151628aed2a6abf7158809cf4f3c");
+        let cipher = Aes128::new(aes, &key);
+        let pt = block("3243f6a8885a308d313198a2e0370734");
+        let ct = block("3925841d02dc09fbdc118597196a0b32");
+        assert_eq!(cipher.encrypt_block(&pt), ct);
+        assert_eq!(cipher.decrypt_block(&ct), pt);
+    }
+
+    #[test]
+    fn aes256_fips197() {
+        let aes = match Aes::detect() {
+            Some(aes) => aes,
+            None => return,
+        };
+
+        // Appendix C.3.
+        let key = key_256(
+            "000102030405060708090a0b0c0d0e0f\
+             101112131415161718191a1b1c1d1e1f",
+        );
+        let cipher = Aes256::new(aes, &key);
+        let pt = block("00112233445566778899aabbccddeeff");
+        let ct = block("8ea2b7ca516745bfeafc49904b496089");
+        assert_eq!(cipher.encrypt_block(&pt), ct);
+        assert_eq!(cipher.decrypt_block(&ct), pt);
+    }
+
+    #[test]
+    fn key_expansion() {
+        let aes = match Aes::detect() {
+            Some(aes) => aes,
+            None => return,
+        };
+
+        // Appendix A.1.
+        let key = block("2b7e151628aed2a6abf7158809cf4f3c");
+        let cipher = Aes128::new(aes, &key);
+        let last = block("d014f9a8c9ee2589e13f0cc8b6630ca6");
+        assert_eq!(bytes(cipher.enc[0]), key);
+        assert_eq!(bytes(cipher.enc[10]), last);
+        assert_eq!(bytes(cipher.dec[0]), bytes(cipher.enc[10]));
+        assert_eq!(bytes(cipher.dec[10]), bytes(cipher.enc[0]));
+
+        // Appendix A.3.
+        let key = key_256(
+            "603deb1015ca71be2b73aef0857d7781\
+             1f352c073b6108d72d9810a30914dff4",
+        );
+        let cipher = Aes256::new(aes, &key);
+        let last = block("fe4890d1e6188d0b046df344706c631e");
+        assert_eq!(bytes(cipher.enc[14]), last);
+    }
+
+    #[test]
+    fn gcm_tag() {
+        let aes = match Aes::detect() {
+            Some(aes) => aes,
+            None => return,
+        };
+
+        // Test case 3 of the GCM specification, whose hash key is the
+        // encryption of the zero block and whose tag is the encryption of
+        // the initial counter block xored with the GHASH.
+        let key = block("feffe9928665731c6d6a8f9467308308");
+        let cipher = Aes128::new(aes, &key);
+        let key = cipher.encrypt_block(&[0; 16]);
+        assert_eq!(key, block("b83b533708bf535d0aa6e52980d53b78"));
+
+        let mut ghash = Ghash::new(&key);
+        ghash.update(&hex(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+             21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091473f5985",
+        ));
+        ghash.update(&hex("00000000000000000000000000000200"));
+        let y0 = block("cafebabefacedbaddecaf88800000001");
+        let mut tag = cipher.encrypt_block(&y0);
+        for (t, h) in tag.iter_mut().zip(&ghash.finalize()) {
+            *t ^= *h;
+        }
+        assert_eq!(tag, block("4d5c2af327cd64a62cf35abd2ba6fab4"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let aes = match Aes::detect() {
+            Some(aes) => aes,
+            None => return,
+        };
+
+        let cipher128 = Aes128::new(aes, &[0x5A; 16]);
+        let cipher256 = Aes256::new(aes, &[0xA5; 32]);
+        let mut pt = [0; 16];
+        for i in 0..64 {
+            pt[i % 16] ^= i as u8 + 1;
+            let ct = cipher128.encrypt_block(&pt);
+            assert!(ct != pt);
+            assert_eq!(cipher128.decrypt_block(&ct), pt);
+            let ct = cipher256.encrypt_block(&pt);
+            assert!(ct != pt);
+            assert_eq!(cipher256.decrypt_block(&ct), pt);
+        }
+    }
+}
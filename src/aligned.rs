@@ -0,0 +1,156 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::slice;
+
+/// Alignment marker for 16-byte (128-bit) alignment.
+pub type A16 = ::v128::u8x16;
+/// Alignment marker for 32-byte (256-bit) alignment.
+pub type A32 = ::v256::u8x32;
+/// Alignment marker for 64-byte (512-bit) alignment.
+pub type A64 = ::v512::u8x64;
+
+/// A fixed-size heap buffer of `T` whose first element is aligned like `A`.
+///
+/// `A` is usually one of `A16`, `A32` or `A64`, but any type can be used:
+/// `AlignedBuf<f32, f32x8>` is suitably aligned for `f32x8::load_aligned`.
+///
+/// The buffer dereferences to a slice, so every chunk of
+/// `size_of::<A>() / size_of::<T>()` elements starting at offset zero can be
+/// loaded and stored with aligned instructions.
+pub struct AlignedBuf<T: Copy, A> {
+    ptr: *mut T,
+    len: usize,
+    cap: usize,
+    _marker: PhantomData<(T, A)>,
+}
+
+impl<T: Copy, A> AlignedBuf<T, A> {
+    /// Creates a buffer of `len` elements, each set to `value`.
+    pub fn new(len: usize, value: T) -> AlignedBuf<T, A> {
+        assert!(mem::size_of::<T>() != 0, "zero-sized types are unsupported");
+        assert!(mem::size_of::<A>() != 0, "zero-sized alignment type");
+        let bytes = len
+            .checked_mul(mem::size_of::<T>())
+            .expect("capacity overflow");
+        let cap = (bytes + mem::size_of::<A>() - 1) / mem::size_of::<A>();
+        let mut storage: Vec<A> = Vec::with_capacity(cap);
+        let ptr = storage.as_mut_ptr() as *mut T;
+        mem::forget(storage);
+        for i in 0..len {
+            unsafe {
+                *ptr.offset(i as isize) = value;
+            }
+        }
+        AlignedBuf { ptr: ptr, len: len, cap: cap, _marker: PhantomData }
+    }
+
+    /// Creates a buffer holding a copy of `values`.
+    pub fn from_slice(values: &[T]) -> AlignedBuf<T, A> {
+        let mut buf = match values.first() {
+            Some(&v) => AlignedBuf::new(values.len(), v),
+            None => return AlignedBuf::new_empty(),
+        };
+        buf.copy_from_slice(values);
+        buf
+    }
+
+    fn new_empty() -> AlignedBuf<T, A> {
+        let mut storage: Vec<A> = Vec::new();
+        let ptr = storage.as_mut_ptr() as *mut T;
+        mem::forget(storage);
+        AlignedBuf { ptr: ptr, len: 0, cap: 0, _marker: PhantomData }
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T: Copy, A> Drop for AlignedBuf<T, A> {
+    fn drop(&mut self) {
+        unsafe {
+            Vec::from_raw_parts(self.ptr as *mut A, 0, self.cap);
+        }
+    }
+}
+
+impl<T: Copy, A> Deref for AlignedBuf<T, A> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy, A> DerefMut for AlignedBuf<T, A> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<T: Copy, A> Clone for AlignedBuf<T, A> {
+    fn clone(&self) -> AlignedBuf<T, A> {
+        AlignedBuf::from_slice(self)
+    }
+}
+
+impl<T: Copy + fmt::Debug, A> fmt::Debug for AlignedBuf<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+unsafe impl<T: Copy + Send, A> Send for AlignedBuf<T, A> {}
+unsafe impl<T: Copy + Sync, A> Sync for AlignedBuf<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use super::{AlignedBuf, A16, A32, A64};
+    use v256::f32x8;
+
+    fn is_aligned<T: Copy, A>(buf: &AlignedBuf<T, A>) -> bool {
+        buf.as_ptr() as usize % mem::align_of::<A>() == 0
+    }
+
+    #[test]
+    fn alignment() {
+        for len in 0..100 {
+            assert!(is_aligned(&AlignedBuf::<u8, A16>::new(len, 1)));
+            assert!(is_aligned(&AlignedBuf::<u16, A32>::new(len, 1)));
+            assert!(is_aligned(&AlignedBuf::<f64, A64>::new(len, 1.0)));
+        }
+    }
+
+    #[test]
+    fn contents() {
+        let mut buf = AlignedBuf::<u32, A32>::new(5, 7);
+        assert_eq!(&*buf, &[7, 7, 7, 7, 7]);
+        buf[2] = 3;
+        assert_eq!(&*buf.clone(), &[7, 7, 3, 7, 7]);
+        let empty = AlignedBuf::<u32, A32>::from_slice(&[]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn aligned_load_store() {
+        let mut buf = AlignedBuf::<f32, f32x8>::from_slice(
+            &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        unsafe {
+            let v = f32x8::load_aligned(&buf, 0);
+            assert_eq!(v, f32x8::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0));
+            (v + v).store_aligned(&mut buf, 0);
+        }
+        assert_eq!(buf[7], 14.0);
+        assert_eq!(buf[8], 8.0);
+    }
+}
@@ -0,0 +1,329 @@
+//! Carry-less multiplication, and the GHASH and CRC folding built on it.
+
+/// Returns the carry-less product of `a` and `b`, i.e. their product as
+/// polynomials over GF(2) whose coefficients are the bits of the operands.
+///
+/// On x86 CPUs supporting `PCLMULQDQ` the product is computed with a single
+/// instruction. Everywhere else it is computed a bit at a time.
+///
+/// ```rust
+/// # #![feature(i128_type)]
+/// use stdsimd::hash::clmul;
+///
+/// // (x + 1) * (x + 1) = x^2 + 1
+/// assert_eq!(clmul(0b11, 0b11), 0b101);
+/// ```
+pub fn clmul(a: u64, b: u64) -> u128 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if ::x86::cpu_features().pclmulqdq() {
+            return unsafe { clmul_pclmulqdq(a, b) };
+        }
+    }
+    clmul_soft(a, b)
+}
+
+/// The software fallback, processing a bit of `b` at a time.
+fn clmul_soft(a: u64, mut b: u64) -> u128 {
+    let mut a = a as u128;
+    let mut p = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        a <<= 1;
+        b >>= 1;
+    }
+    p
+}
+
+/// Returns the carry-less product of `a` and `b` using `PCLMULQDQ`.
+///
+/// Must only be called if the CPU supports PCLMULQDQ.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature = "+pclmul"]
+unsafe fn clmul_pclmulqdq(a: u64, b: u64) -> u128 {
+    use v128::u64x2;
+    use x86::{__m128i, _mm_clmulepi64_si128};
+
+    let a = __m128i::from(u64x2::new(a, 0));
+    let b = __m128i::from(u64x2::new(b, 0));
+    let p = u64x2::from(_mm_clmulepi64_si128(a, b, 0x00));
+    (p.extract(0) as u128) | ((p.extract(1) as u128) << 64)
+}
+
+/// GHASH, the universal hash function of the Galois/Counter Mode (GCM), as
+/// specified in NIST SP 800-38D.
+///
+/// The input is hashed in blocks of 16 bytes, and each call to `update` zero
+/// pads its bytes to a whole number of blocks. That is how GCM hashes its
+/// input: the additional authenticated data, then the ciphertext, then a
+/// block of their lengths in bits, each a big-endian `u64`.
+///
+/// ```rust
+/// use stdsimd::hash::Ghash;
+///
+/// // Test case 2 of the GCM specification: no additional authenticated
+/// // data, and 16 bytes (128 bits) of ciphertext.
+/// let key = [
+///     0x66, 0xe9, 0x4b, 0xd4, 0xef, 0x8a, 0x2c, 0x3b,
+///     0x88, 0x4c, 0xfa, 0x59, 0xca, 0x34, 0x2b, 0x2e,
+/// ];
+/// let ciphertext = [
+///     0x03, 0x88, 0xda, 0xce, 0x60, 0xb6, 0xa3, 0x92,
+///     0xf3, 0x28, 0xc2, 0xb9, 0x71, 0xb2, 0xfe, 0x78,
+/// ];
+/// let mut lengths = [0; 16];
+/// lengths[15] = 128;
+///
+/// let mut ghash = Ghash::new(&key);
+/// ghash.update(&ciphertext);
+/// ghash.update(&lengths);
+/// assert_eq!(ghash.finalize(), [
+///     0xf3, 0x8c, 0xbb, 0x1a, 0xd6, 0x92, 0x23, 0xdc,
+///     0xc3, 0x45, 0x7a, 0xe5, 0xb6, 0xb0, 0xf8, 0x85,
+/// ]);
+/// ```
+#[derive(Clone)]
+pub struct Ghash {
+    /// The hash key. As for all elements of GF(2^128) in GCM, the
+    /// coefficient of `x^0` is the most significant bit.
+    key: u128,
+    /// The hash of the blocks appended so far.
+    state: u128,
+}
+
+impl Ghash {
+    /// Creates a hash of the empty input under the hash key `key`, which GCM
+    /// derives by encrypting the all-zero block.
+    pub fn new(key: &[u8; 16]) -> Ghash {
+        Ghash {
+            key: from_be_bytes(key),
+            state: 0,
+        }
+    }
+
+    /// Appends `bytes`, zero padded to a multiple of 16 bytes, to the input.
+    pub fn update(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(16) {
+            let mut block = [0; 16];
+            block[..chunk.len()].copy_from_slice(chunk);
+            let x = self.state ^ from_be_bytes(&block);
+            self.state = gf128_mul(x, self.key);
+        }
+    }
+
+    /// Returns the hash of the blocks appended so far.
+    ///
+    /// The input is not reset, so more blocks can be appended afterwards.
+    pub fn finalize(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = (self.state >> (120 - 8 * i)) as u8;
+        }
+        bytes
+    }
+}
+
+fn from_be_bytes(bytes: &[u8; 16]) -> u128 {
+    bytes.iter().fold(0, |x, &b| (x << 8) | b as u128)
+}
+
+/// Multiplies `a` and `b` in GF(2^128) modulo `x^128 + x^7 + x^2 + x + 1`,
+/// in the bit-reflected representation of GCM.
+fn gf128_mul(a: u128, b: u128) -> u128 {
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+
+    // The 255-bit product, as `hi:lo`.
+    let mid = clmul(a0, b1) ^ clmul(a1, b0);
+    let lo = clmul(a0, b0) ^ (mid << 64);
+    let hi = clmul(a1, b1) ^ (mid >> 64);
+
+    // Reflected, the product is one bit short of 256 bits, so shift it into
+    // place before reducing the low half, which holds the coefficients of
+    // `x^128` and up.
+    let hi = (hi << 1) | (lo >> 127);
+    let lo = lo << 1;
+
+    let overflow = (lo << 127) ^ (lo << 126) ^ (lo << 121);
+    let lo = lo ^ overflow;
+    hi ^ lo ^ (lo >> 1) ^ (lo >> 2) ^ (lo >> 7)
+}
+
+/// Folds the input of a bit-reflected 32-bit CRC with carry-less
+/// multiplication, 16 bytes at a time.
+///
+/// The CRC register after some bytes only depends on their remainder modulo
+/// the CRC polynomial. `fold` replaces 16 bytes followed by another 16 bytes
+/// with 16 bytes of the same remainder, so any input that is a multiple of
+/// 16 bytes can be folded down to 16 bytes and then reduced to the register
+/// by `reduce`. Blocks are read as little-endian `u128`s, and the initial
+/// register is xored into the first block.
+///
+/// ```rust
+/// # #![feature(i128_type)]
+/// use stdsimd::hash::CrcFold;
+///
+/// // The CRC-32 of zlib and Ethernet, of 32 zero bytes.
+/// let crc32 = CrcFold::new(0xEDB88320);
+/// // The first block, xored with the initial register `!0`, and the second.
+/// let acc = crc32.fold(0xFFFF_FFFF, 0);
+/// assert_eq!(!crc32.reduce(acc), 0x190A55AD);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct CrcFold {
+    /// The bit-reflected CRC polynomial.
+    poly: u32,
+    /// `x^191 mod P`, multiplied into the low 64 bits of the accumulator.
+    k1: u64,
+    /// `x^127 mod P`, multiplied into the high 64 bits of the accumulator.
+    k2: u64,
+}
+
+impl CrcFold {
+    /// Creates the folding constants for the bit-reflected polynomial
+    /// `poly`, e.g. `0x82F63B78` for CRC-32C or `0xEDB88320` for CRC-32.
+    pub fn new(poly: u32) -> CrcFold {
+        // Both constants are reflected 32-bit values, i.e. the coefficient of
+        // `x^0` is their bit 31, which the shift moves to bit 63 to line up
+        // with the 64-bit halves of the accumulator.
+        let xpow = |n| {
+            let mut r = 1u32 << 31;
+            for _ in 0..n {
+                r = if r & 1 != 0 { (r >> 1) ^ poly } else { r >> 1 };
+            }
+            r as u64
+        };
+        CrcFold {
+            poly: poly,
+            k1: xpow(191) << 32,
+            k2: xpow(127) << 32,
+        }
+    }
+
+    /// Returns 16 bytes with the same remainder as the bytes of `acc`
+    /// followed by the bytes of `next`.
+    pub fn fold(&self, acc: u128, next: u128) -> u128 {
+        clmul(acc as u64, self.k1) ^ clmul((acc >> 64) as u64, self.k2) ^ next
+    }
+
+    /// Returns the CRC register after the 16 bytes of `acc`, starting from a
+    /// zero register.
+    pub fn reduce(&self, acc: u128) -> u32 {
+        let mut crc = 0;
+        for i in 0..16 {
+            crc ^= (acc >> (8 * i)) as u8 as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ self.poly
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+        crc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crc32c::Crc32c;
+
+    use super::{clmul, clmul_soft, CrcFold, Ghash};
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len() / 2)
+            .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn block(s: &str) -> [u8; 16] {
+        let mut b = [0; 16];
+        b.copy_from_slice(&hex(s));
+        b
+    }
+
+    /// The CRC of `bytes`, a multiple of 16 bytes, with the initial register
+    /// and final inversion of CRC-32 and CRC-32C.
+    fn fold_all(fold: &CrcFold, bytes: &[u8]) -> u32 {
+        let le = |block: &[u8]| {
+            block.iter().rev().fold(0, |x, &b| (x << 8) | b as u128)
+        };
+        let mut acc = le(&bytes[..16]) ^ 0xFFFF_FFFF;
+        for chunk in bytes[16..].chunks(16) {
+            acc = fold.fold(acc, le(chunk));
+        }
+        !fold.reduce(acc)
+    }
+
+    #[test]
+    fn clmul_values() {
+        assert_eq!(clmul(0, 0x1234), 0);
+        assert_eq!(clmul(1, 0x1234), 0x1234);
+        assert_eq!(clmul(0b11, 0b11), 0b101);
+        assert_eq!(clmul(1 << 63, 1 << 63), 1 << 126);
+        assert_eq!(clmul(!0, !0), 0x5555_5555_5555_5555_5555_5555_5555_5555);
+
+        let mut x = 0x2545F4914F6CDD1Du64;
+        for _ in 0..1000 {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            let y = x.rotate_left(23) ^ 0x9E3779B97F4A7C15;
+            assert_eq!(clmul(x, y), clmul_soft(x, y));
+            assert_eq!(clmul(x, y), clmul(y, x));
+        }
+    }
+
+    #[test]
+    fn ghash_gcm() {
+        // Test case 2.
+        let mut ghash = Ghash::new(&block("66e94bd4ef8a2c3b884cfa59ca342b2e"));
+        ghash.update(&hex("0388dace60b6a392f328c2b971b2fe78"));
+        ghash.update(&hex("00000000000000000000000000000080"));
+        assert_eq!(ghash.finalize(), block("f38cbb1ad69223dcc3457ae5b6b0f885"));
+
+        // Test case 3.
+        let mut ghash = Ghash::new(&block("b83b533708bf535d0aa6e52980d53b78"));
+        ghash.update(&hex(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+             21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091473f5985",
+        ));
+        ghash.update(&hex("00000000000000000000000000000200"));
+        assert_eq!(ghash.finalize(), block("7f1b32b81b820d02614f8895ac1d4eac"));
+    }
+
+    #[test]
+    fn ghash_padding() {
+        let key = block("b83b533708bf535d0aa6e52980d53b78");
+        let bytes = hex("42831ec2217774244b7221b784d0d49ce3aa");
+
+        let mut padded = Ghash::new(&key);
+        padded.update(&bytes);
+        let mut zeros = Ghash::new(&key);
+        zeros.update(&bytes[..16]);
+        zeros.update(&[bytes[16], bytes[17], 0, 0, 0, 0, 0, 0]);
+        assert_eq!(padded.finalize(), zeros.finalize());
+
+        assert_eq!(Ghash::new(&key).finalize(), [0; 16]);
+    }
+
+    #[test]
+    fn crc_fold() {
+        let crc32c = CrcFold::new(0x82F63B78);
+        let crc32 = CrcFold::new(0xEDB88320);
+        let bytes: Vec<u8> = (0..64).collect();
+
+        assert_eq!(fold_all(&crc32c, &[0; 32]), 0x8A9136AA);
+        assert_eq!(fold_all(&crc32c, &bytes), 0xFB6D36EB);
+        assert_eq!(fold_all(&crc32, &[0; 32]), 0x190A55AD);
+        assert_eq!(fold_all(&crc32, &bytes[..32]), 0x91267E8A);
+
+        let bytes: Vec<u8> = (0..4096).map(|i| (i * 167 + 13) as u8).collect();
+        for len in (1..257).map(|n| 16 * n) {
+            let expected = Crc32c::checksum(&bytes[..len]);
+            assert_eq!(fold_all(&crc32c, &bytes[..len]), expected);
+        }
+    }
+}
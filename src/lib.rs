@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 #![feature(
-    const_fn, link_llvm_intrinsics, platform_intrinsics, repr_simd, simd_ffi,
-    target_feature,
+    asm, const_fn, core_intrinsics, i128_type, link_llvm_intrinsics,
+    platform_intrinsics, repr_simd, simd_ffi, target_feature,
 )]
 
 /// Platform independent SIMD vector types and operations.
@@ -10,6 +10,30 @@ pub mod simd {
     pub use v256::*;
     pub use v512::*;
     pub use v64::*;
+
+    pub use aligned::{AlignedBuf, A16, A32, A64};
+
+    /// Lane-wise transcendental functions on floating point vectors.
+    pub mod math {
+        pub use math::*;
+    }
+}
+
+/// Checksums and hashes accelerated with vendor intrinsics where available.
+pub mod hash {
+    pub use clmul::{clmul, CrcFold, Ghash};
+    pub use crc32c::Crc32c;
+}
+
+/// Block ciphers accelerated with vendor intrinsics.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub mod cipher {
+    pub use aes::{Aes128, Aes256};
+}
+
+/// Substring search accelerated with vendor intrinsics where available.
+pub mod search {
+    pub use memmem::{find, find_iter, rfind, FindIter};
 }
 
 /// Platform dependent vendor intrinsics.
@@ -18,8 +42,71 @@ pub mod vendor {
     pub use x86::*;
 }
 
+#[doc(hidden)]
+pub mod __shuffle {
+    pub use simd_llvm::{
+        simd_shuffle2, simd_shuffle4, simd_shuffle8, simd_shuffle16,
+        simd_shuffle32, simd_shuffle64,
+    };
+}
+
+#[doc(hidden)]
+pub mod __multiversion {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Returns `true` if the running CPU supports every feature in
+    /// `features`, a comma separated list like `"+sse4.2,+popcnt"`.
+    ///
+    /// Features that this crate cannot detect are reported as unsupported.
+    /// Disabling a feature (`"-avx"`) never stops code from running, so
+    /// those entries are always supported.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub fn supports(features: &str) -> bool {
+        features.split(',').map(|f| f.trim()).all(|f| {
+            if f.starts_with('-') {
+                return true;
+            }
+            let name = f.trim_left_matches('+');
+            ::x86::feature_detected(name).unwrap_or(false)
+        })
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    pub fn supports(_features: &str) -> bool {
+        false
+    }
+
+    /// Returns the index of the first entry of `versions` that the running
+    /// CPU supports.
+    pub fn first_supported(versions: &[&str]) -> Option<usize> {
+        versions.iter().position(|features| supports(features))
+    }
+
+    /// Returns the function pointer cached in `cache`, calling `select` to
+    /// fill it in on the first call.
+    pub fn dispatch(cache: &AtomicUsize, select: fn() -> usize) -> usize {
+        let mut f = cache.load(Ordering::Relaxed);
+        if f == 0 {
+            f = select();
+            cache.store(f, Ordering::Relaxed);
+        }
+        f
+    }
+}
+
 #[macro_use]
 mod macros;
+#[macro_use]
+mod shuffle;
+#[macro_use]
+mod multiversion;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod aes;
+mod aligned;
+mod clmul;
+mod crc32c;
+mod math;
+mod memmem;
 mod simd_llvm;
 mod v128;
 mod v256;
@@ -92,6 +92,120 @@ macro_rules! define_impl {
                 x
             }
 
+            /// Stores `self` into `slice` starting at `offset` with an
+            /// aligned store.
+            ///
+            /// `&slice[offset]` must be aligned to the size of the vector.
+            /// This is only checked in debug builds.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice[offset..]` has fewer elements than there are
+            /// lanes in the vector.
+            #[inline]
+            pub unsafe fn store_aligned(
+                self,
+                slice: &mut [$elemty],
+                offset: usize,
+            ) {
+                use std::mem::align_of;
+
+                assert!(slice[offset..].len() >= $nelems);
+                let p = slice[offset..].as_mut_ptr() as *mut $name;
+                debug_assert!(
+                    p as usize % align_of::<$name>() == 0,
+                    "unaligned store");
+                *p = self;
+            }
+
+            /// Loads a vector from `slice` starting at `offset` with an
+            /// aligned load.
+            ///
+            /// `&slice[offset]` must be aligned to the size of the vector.
+            /// This is only checked in debug builds.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `slice[offset..]` has fewer elements than there are
+            /// lanes in the vector.
+            #[inline]
+            pub unsafe fn load_aligned(
+                slice: &[$elemty],
+                offset: usize,
+            ) -> $name {
+                use std::mem::align_of;
+
+                assert!(slice[offset..].len() >= $nelems);
+                let p = slice[offset..].as_ptr() as *const $name;
+                debug_assert!(
+                    p as usize % align_of::<$name>() == 0,
+                    "unaligned load");
+                *p
+            }
+
+            /// Loads as many lanes as fit from `slice` starting at `offset`,
+            /// taking the remaining lanes from `default`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `offset > slice.len()`.
+            #[inline]
+            pub fn load_or(
+                slice: &[$elemty],
+                offset: usize,
+                default: $name,
+            ) -> $name {
+                use std::cmp;
+                use std::ptr;
+
+                let count = cmp::min(slice[offset..].len(), $nelems);
+                if count == $nelems {
+                    return unsafe { $name::load_unchecked(slice, offset) };
+                }
+                let mut x = default;
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        slice[offset..].as_ptr(),
+                        &mut x as *mut $name as *mut $elemty,
+                        count);
+                }
+                x
+            }
+
+            /// Loads as many lanes as fit from `slice` starting at `offset`,
+            /// setting the remaining lanes to zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `offset > slice.len()`.
+            #[inline]
+            pub fn load_partial(slice: &[$elemty], offset: usize) -> $name {
+                $name::load_or(slice, offset, $name::splat(0 as $elemty))
+            }
+
+            /// Stores as many lanes as fit into `slice` starting at `offset`,
+            /// leaving the rest of `slice` untouched.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `offset > slice.len()`.
+            #[inline]
+            pub fn store_partial(self, slice: &mut [$elemty], offset: usize) {
+                use std::cmp;
+                use std::ptr;
+
+                let count = cmp::min(slice[offset..].len(), $nelems);
+                if count == $nelems {
+                    return unsafe { self.store_unchecked(slice, offset) };
+                }
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        &self as *const $name as *const $elemty,
+                        slice[offset..].as_mut_ptr(),
+                        count);
+                }
+            }
+
             #[inline]
             pub fn eq(self, other: $name) -> $boolname {
                 unsafe { simd_eq(self, other) }
@@ -121,6 +235,57 @@ macro_rules! define_impl {
             pub fn ge(self, other: $name) -> $boolname {
                 unsafe { simd_ge(self, other) }
             }
+
+            /// Returns a vector whose lanes are taken from `a` where the
+            /// corresponding lane of `mask` is set, and from `b` where it is
+            /// not.
+            ///
+            /// This is branch free: when compiled for SSE4.1 (128-bit
+            /// vectors) or AVX2 (256-bit vectors) it is a single `pblendvb`,
+            /// and otherwise an `and`/`andnot`/`or` sequence.
+            #[inline]
+            pub fn select(mask: $boolname, a: $name, b: $name) -> $name {
+                use std::mem::transmute;
+
+                // `pblendvb` picks whole bytes by their high bit, and every
+                // byte of a set mask lane is `0xFF`, so it works for any
+                // lane size. The sizes are checked before `transmute_copy`.
+                #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                          target_feature = "sse4.1"))]
+                {
+                    use std::mem::{size_of, transmute_copy};
+
+                    if size_of::<$name>() == 16 {
+                        return unsafe {
+                            let r: ::x86::__m128i = ::x86::_mm_blendv_epi8(
+                                transmute_copy(&b),
+                                transmute_copy(&a),
+                                transmute_copy(&mask));
+                            transmute_copy(&r)
+                        };
+                    }
+                }
+                #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                          target_feature = "avx2"))]
+                {
+                    use std::mem::{size_of, transmute_copy};
+
+                    if size_of::<$name>() == 32 {
+                        return unsafe {
+                            let r: ::simd::i8x32 = ::x86::_mm256_blendv_epi8(
+                                transmute_copy(&b),
+                                transmute_copy(&a),
+                                transmute_copy(&mask));
+                            transmute_copy(&r)
+                        };
+                    }
+                }
+                unsafe {
+                    let a: $boolname = transmute(a);
+                    let b: $boolname = transmute(b);
+                    transmute(simd_or(simd_and(mask, a), simd_and(!mask, b)))
+                }
+            }
         }
     }
 }
@@ -168,7 +333,7 @@ macro_rules! define_common_ops {
 }
 
 macro_rules! define_float_ops {
-    ($($ty:ident),+) => {
+    ($(($ty:ident, $elem:ident)),+) => {
         $(
             impl ::std::ops::Div for $ty {
                 type Output = Self;
@@ -177,10 +342,69 @@ macro_rules! define_float_ops {
                     unsafe { simd_div(self, other) }
                 }
             }
+
+            impl $ty {
+                /// Returns the sum of all lanes.
+                ///
+                /// Lanes are added in order, i.e., the result is
+                /// `((x0 + x1) + x2) + ...`, so that the result is the same
+                /// on every target.
+                #[inline]
+                pub fn sum(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a + b
+                    })
+                }
+
+                /// Returns the product of all lanes.
+                ///
+                /// Lanes are multiplied in order, i.e., the result is
+                /// `((x0 * x1) * x2) * ...`.
+                #[inline]
+                pub fn product(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a * b
+                    })
+                }
+
+                /// Returns the smallest lane.
+                ///
+                /// `NaN` lanes are ignored, unless every lane is `NaN`.
+                #[inline]
+                pub fn min_element(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a.min(b)
+                    })
+                }
+
+                /// Returns the largest lane.
+                ///
+                /// `NaN` lanes are ignored, unless every lane is `NaN`.
+                #[inline]
+                pub fn max_element(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a.max(b)
+                    })
+                }
+            }
         )+
     }
 }
 
+macro_rules! define_reduction {
+    ($v:expr, $ty:ident, $elem:ident, $f:expr) => {{
+        let v = $v;
+        let f = $f;
+        let n = ::std::mem::size_of::<$ty>() / ::std::mem::size_of::<$elem>();
+        let mut acc: $elem = unsafe { simd_extract(v, 0) };
+        for i in 1..n as u32 {
+            let x: $elem = unsafe { simd_extract(v, i) };
+            acc = f(acc, x);
+        }
+        acc
+    }}
+}
+
 macro_rules! define_shifts {
     ($ty:ident, $elem:ident, $($by:ident),+) => {
         $(
@@ -233,6 +457,64 @@ macro_rules! define_integer_ops {
                     $ty::splat(!0) ^ self
                 }
             }
+            impl $ty {
+                /// Returns the sum of all lanes, wrapping on overflow.
+                #[inline]
+                pub fn sum(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a.wrapping_add(b)
+                    })
+                }
+
+                /// Returns the product of all lanes, wrapping on overflow.
+                #[inline]
+                pub fn product(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a.wrapping_mul(b)
+                    })
+                }
+
+                /// Returns the smallest lane.
+                #[inline]
+                pub fn min_element(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        ::std::cmp::min(a, b)
+                    })
+                }
+
+                /// Returns the largest lane.
+                #[inline]
+                pub fn max_element(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        ::std::cmp::max(a, b)
+                    })
+                }
+
+                /// Returns the bitwise AND of all lanes.
+                #[inline]
+                pub fn and(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a & b
+                    })
+                }
+
+                /// Returns the bitwise OR of all lanes.
+                #[inline]
+                pub fn or(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a | b
+                    })
+                }
+
+                /// Returns the bitwise XOR of all lanes.
+                #[inline]
+                pub fn xor(self) -> $elem {
+                    define_reduction!(self, $ty, $elem, |a: $elem, b: $elem| {
+                        a ^ b
+                    })
+                }
+            }
+
             define_shifts!(
                 $ty, $elem,
                 u8, u16, u32, u64, usize,
@@ -267,3 +549,473 @@ macro_rules! define_casts {
         )+
     }
 }
+
+macro_rules! define_mask {
+    (
+        $name:ident, $elemty:ident, $nelems:expr, $bitsname:ident,
+        $($elname:ident),+
+    ) => {
+        impl $name {
+            #[inline]
+            pub fn new($($elname: bool),*) -> $name {
+                $name($(if $elname { -1 } else { 0 }),*)
+            }
+
+            #[inline]
+            pub fn splat(value: bool) -> $name {
+                let value: $elemty = if value { -1 } else { 0 };
+                $name($({
+                    #[allow(non_camel_case_types, dead_code)]
+                    struct $elname;
+                    value
+                }),*)
+            }
+
+            #[inline]
+            pub fn extract(self, idx: u32) -> bool {
+                assert!(idx < $nelems);
+                let lane: $elemty = unsafe { simd_extract(self, idx) };
+                lane != 0
+            }
+
+            #[inline]
+            pub fn replace(self, idx: u32, val: bool) -> $name {
+                assert!(idx < $nelems);
+                let lane: $elemty = if val { -1 } else { 0 };
+                unsafe { simd_insert(self, idx, lane) }
+            }
+
+            /// Returns true if any lane of this mask is set.
+            #[inline]
+            pub fn any(self) -> bool {
+                (0..$nelems).any(|i| self.extract(i))
+            }
+
+            /// Returns true if every lane of this mask is set.
+            #[inline]
+            pub fn all(self) -> bool {
+                (0..$nelems).all(|i| self.extract(i))
+            }
+
+            /// Returns true if no lane of this mask is set.
+            #[inline]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            /// Convert an integer vector to a mask.
+            ///
+            /// Returns `None` if any lane of `bits` is neither all zeros nor
+            /// all ones.
+            #[inline]
+            pub fn from_bits(bits: ::simd::$bitsname) -> Option<$name> {
+                let valid = (0..$nelems).all(|i| {
+                    let lane: $elemty = unsafe { simd_extract(bits, i) };
+                    lane == 0 || lane == -1
+                });
+                if valid {
+                    Some(unsafe { $name::from_bits_unchecked(bits) })
+                } else {
+                    None
+                }
+            }
+
+            /// Convert an integer vector to a mask without checking that
+            /// every lane is either all zeros or all ones.
+            #[inline]
+            pub unsafe fn from_bits_unchecked(
+                bits: ::simd::$bitsname,
+            ) -> $name {
+                ::std::mem::transmute(bits)
+            }
+        }
+
+        impl ::std::ops::BitAnd for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn bitand(self, other: Self) -> Self {
+                unsafe { simd_and(self, other) }
+            }
+        }
+
+        impl ::std::ops::BitOr for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn bitor(self, other: Self) -> Self {
+                unsafe { simd_or(self, other) }
+            }
+        }
+
+        impl ::std::ops::BitXor for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn bitxor(self, other: Self) -> Self {
+                unsafe { simd_xor(self, other) }
+            }
+        }
+
+        impl ::std::ops::Not for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn not(self) -> Self {
+                $name::splat(true) ^ self
+            }
+        }
+    }
+}
+
+macro_rules! define_float_math {
+    (
+        $ty:ident, $bits:ident,
+        $sqrt:expr, $fabs:expr, $fma:expr, $floor:expr, $ceil:expr,
+        $round:expr, $trunc:expr, $copysign:expr
+    ) => {
+        // These defer to LLVM's generic vector intrinsics, which are lowered
+        // to native instructions when the target supports them (e.g.,
+        // `sqrtps` or `roundps`) and to calls into libm for each lane
+        // otherwise.
+        impl $ty {
+            /// Return the square root of each lane.
+            #[inline]
+            pub fn sqrt(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $sqrt]
+                    fn sqrt(a: $ty) -> $ty;
+                }
+                unsafe { sqrt(self) }
+            }
+
+            /// Return the absolute value of each lane.
+            #[inline]
+            pub fn abs(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $fabs]
+                    fn fabs(a: $ty) -> $ty;
+                }
+                unsafe { fabs(self) }
+            }
+
+            /// Compute `(self * a) + b` for each lane with only one rounding
+            /// error.
+            #[inline]
+            pub fn mul_add(self, a: $ty, b: $ty) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $fma]
+                    fn fma(a: $ty, b: $ty, c: $ty) -> $ty;
+                }
+                unsafe { fma(self, a, b) }
+            }
+
+            /// Return the largest integer less than or equal to each lane.
+            #[inline]
+            pub fn floor(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $floor]
+                    fn floor(a: $ty) -> $ty;
+                }
+                unsafe { floor(self) }
+            }
+
+            /// Return the smallest integer greater than or equal to each lane.
+            #[inline]
+            pub fn ceil(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $ceil]
+                    fn ceil(a: $ty) -> $ty;
+                }
+                unsafe { ceil(self) }
+            }
+
+            /// Return the nearest integer to each lane, rounding half-way
+            /// cases away from `0.0`.
+            #[inline]
+            pub fn round(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $round]
+                    fn round(a: $ty) -> $ty;
+                }
+                unsafe { round(self) }
+            }
+
+            /// Return the integer part of each lane.
+            #[inline]
+            pub fn trunc(self) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $trunc]
+                    fn trunc(a: $ty) -> $ty;
+                }
+                unsafe { trunc(self) }
+            }
+
+            /// Return each lane with the magnitude of `self` and the sign of
+            /// the corresponding lane in `sign`.
+            #[inline]
+            pub fn copysign(self, sign: $ty) -> $ty {
+                #[allow(improper_ctypes)]
+                extern {
+                    #[link_name = $copysign]
+                    fn copysign(a: $ty, b: $ty) -> $ty;
+                }
+                unsafe { copysign(self, sign) }
+            }
+
+            /// Return `1.0` for each positive lane (including `+0.0` and
+            /// `+INFINITY`), `-1.0` for each negative lane (including `-0.0`
+            /// and `-INFINITY`) and `NaN` for each `NaN` lane.
+            #[inline]
+            pub fn signum(self) -> $ty {
+                use std::mem::transmute;
+
+                let one = $ty::splat(1.0).copysign(self);
+                let nan = ::simd::$bits::from(self.ne(self));
+                unsafe {
+                    let one: ::simd::$bits = transmute(one);
+                    let x: ::simd::$bits = transmute(self);
+                    transmute((nan & x) | (!nan & one))
+                }
+            }
+
+            /// Return the reciprocal (`1.0 / x`) of each lane.
+            #[inline]
+            pub fn recip(self) -> $ty {
+                $ty::splat(1.0) / self
+            }
+        }
+    }
+}
+
+macro_rules! define_gather_scatter {
+    (
+        $name:ident, $elemty:ident, $nelems:expr, $idxty:ident, $boolname:ident
+    ) => {
+        define_gather_scatter!(
+            @impl $name, $elemty, $nelems, $idxty, $boolname);
+
+        impl $name {
+            #[inline(always)]
+            #[allow(unused_variables)]
+            unsafe fn gather_fast(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+                default: $name,
+            ) -> Option<$name> {
+                None
+            }
+        }
+    };
+    (
+        $name:ident, $elemty:ident, $nelems:expr, $idxty:ident, $boolname:ident,
+        $avx2:ident
+    ) => {
+        define_gather_scatter!(
+            @impl $name, $elemty, $nelems, $idxty, $boolname);
+
+        impl $name {
+            #[inline(always)]
+            #[allow(unused_variables)]
+            unsafe fn gather_fast(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+                default: $name,
+            ) -> Option<$name> {
+                #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                          target_feature = "avx2"))]
+                {
+                    use std::mem::{size_of, transmute};
+                    use std::ptr;
+
+                    // The hardware treats the indices as signed, so only
+                    // use it when every valid index fits in an `i32`.
+                    if slice.len() <= ::std::i32::MAX as usize {
+                        // The instructions take four or eight indices, of
+                        // which 2-lane vectors only use the low two, so
+                        // the indices are copied into a zero-padded buffer.
+                        let mut offsets = [0u32; 8];
+                        ptr::copy_nonoverlapping(
+                            &indices as *const _ as *const u32,
+                            offsets.as_mut_ptr(),
+                            $nelems);
+                        return Some(transmute(::x86::$avx2(
+                            transmute(default),
+                            slice.as_ptr() as *const _,
+                            ptr::read_unaligned(offsets.as_ptr() as *const _),
+                            transmute(mask),
+                            size_of::<$elemty>() as i32,
+                        )));
+                    }
+                }
+                None
+            }
+        }
+    };
+    (@impl $name:ident, $elemty:ident, $nelems:expr, $idxty:ident,
+     $boolname:ident) => {
+        impl $name {
+            /// Loads the elements of `slice` at `indices` into a new vector.
+            ///
+            /// When compiled for AVX2, 128-bit and 256-bit vectors load with
+            /// a single gather instruction. Other vectors load lane by lane.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of bounds.
+            #[inline]
+            pub fn gather(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+            ) -> $name {
+                $name::gather_masked(
+                    slice, indices, $boolname::splat(true),
+                    $name::splat(0 as $elemty))
+            }
+
+            /// Loads the elements of `slice` at `indices` into a new vector,
+            /// without doing bounds checking.
+            ///
+            /// Every index must be in bounds for `slice`.
+            #[inline]
+            pub unsafe fn gather_unchecked(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+            ) -> $name {
+                $name::gather_masked_unchecked(
+                    slice, indices, $boolname::splat(true),
+                    $name::splat(0 as $elemty))
+            }
+
+            /// Loads the elements of `slice` at `indices` for the lanes that
+            /// are set in `mask`. The other lanes are taken from `default`
+            /// and their indices are ignored.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index of any lane set in `mask` is out of
+            /// bounds.
+            #[inline]
+            pub fn gather_masked(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+                default: $name,
+            ) -> $name {
+                for i in 0..$nelems {
+                    assert!(
+                        !mask.extract(i)
+                            || (indices.extract(i) as usize) < slice.len(),
+                        "gather index out of bounds");
+                }
+                unsafe {
+                    $name::gather_masked_unchecked(
+                        slice, indices, mask, default)
+                }
+            }
+
+            /// Like `gather_masked`, but without doing bounds checking.
+            ///
+            /// The index of every lane set in `mask` must be in bounds for
+            /// `slice`.
+            #[inline]
+            pub unsafe fn gather_masked_unchecked(
+                slice: &[$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+                default: $name,
+            ) -> $name {
+                if let Some(r) =
+                    $name::gather_fast(slice, indices, mask, default) {
+                    return r;
+                }
+                let mut r = default;
+                for i in 0..$nelems {
+                    if mask.extract(i) {
+                        let idx = indices.extract(i) as usize;
+                        r = simd_insert(r, i, *slice.get_unchecked(idx));
+                    }
+                }
+                r
+            }
+
+            /// Stores the lanes of `self` into `slice` at `indices`.
+            ///
+            /// Lanes are stored in order, so if several lanes have the same
+            /// index, the highest of them wins.
+            ///
+            /// # Panics
+            ///
+            /// Panics if any of the indices is out of bounds.
+            #[inline]
+            pub fn scatter(
+                self,
+                slice: &mut [$elemty],
+                indices: ::simd::$idxty,
+            ) {
+                self.scatter_masked(slice, indices, $boolname::splat(true))
+            }
+
+            /// Stores the lanes of `self` into `slice` at `indices`, without
+            /// doing bounds checking.
+            ///
+            /// Every index must be in bounds for `slice`.
+            #[inline]
+            pub unsafe fn scatter_unchecked(
+                self,
+                slice: &mut [$elemty],
+                indices: ::simd::$idxty,
+            ) {
+                self.scatter_masked_unchecked(
+                    slice, indices, $boolname::splat(true))
+            }
+
+            /// Stores the lanes of `self` that are set in `mask` into `slice`
+            /// at `indices`. The indices of the other lanes are ignored.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the index of any lane set in `mask` is out of
+            /// bounds.
+            #[inline]
+            pub fn scatter_masked(
+                self,
+                slice: &mut [$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+            ) {
+                for i in 0..$nelems {
+                    assert!(
+                        !mask.extract(i)
+                            || (indices.extract(i) as usize) < slice.len(),
+                        "scatter index out of bounds");
+                }
+                unsafe { self.scatter_masked_unchecked(slice, indices, mask) }
+            }
+
+            /// Like `scatter_masked`, but without doing bounds checking.
+            ///
+            /// The index of every lane set in `mask` must be in bounds for
+            /// `slice`.
+            #[inline]
+            pub unsafe fn scatter_masked_unchecked(
+                self,
+                slice: &mut [$elemty],
+                indices: ::simd::$idxty,
+                mask: $boolname,
+            ) {
+                for i in 0..$nelems {
+                    if mask.extract(i) {
+                        let idx = indices.extract(i) as usize;
+                        *slice.get_unchecked_mut(idx) = simd_extract(self, i);
+                    }
+                }
+            }
+        }
+    };
+}
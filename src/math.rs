@@ -0,0 +1,966 @@
+//! Lane-wise transcendental functions on floating point vectors.
+//!
+//! The functions in this module are exposed as methods of the
+//! `Transcendental` trait, which is implemented for `f32x4`, `f32x8`,
+//! `f32x16`, `f64x2`, `f64x4` and `f64x8`. The approximations are the
+//! polynomial and rational approximations from the Cephes library, evaluated
+//! on every lane at once.
+//!
+//! Every function handles `NaN`, infinities and denormal inputs and outputs
+//! the same way as the corresponding scalar function in `std`. The maximum
+//! error of each function is documented on the trait, measured in units in
+//! the last place (ULP) of the exact result.
+
+use std::mem::transmute;
+
+use simd_llvm::{simd_cast, simd_shuffle8, simd_shuffle16};
+use v128::*;
+use v256::*;
+use v512::*;
+
+/// Lane-wise transcendental functions.
+pub trait Transcendental: Sized {
+    /// Return `e^x` for each lane.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn exp(self) -> Self;
+
+    /// Return `2^x` for each lane.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn exp2(self) -> Self;
+
+    /// Return the natural logarithm of each lane.
+    ///
+    /// Negative lanes produce `NaN`, and zero lanes produce `-INFINITY`.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn ln(self) -> Self;
+
+    /// Return the base 2 logarithm of each lane.
+    ///
+    /// Negative lanes produce `NaN`, and zero lanes produce `-INFINITY`.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn log2(self) -> Self;
+
+    /// Return the sine of each lane (in radians).
+    ///
+    /// Lanes whose magnitude exceeds `8192` for `f32` or `2^30` for `f64`
+    /// are computed with the scalar function from `std`.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn sin(self) -> Self;
+
+    /// Return the cosine of each lane (in radians).
+    ///
+    /// Lanes whose magnitude exceeds `8192` for `f32` or `2^30` for `f64`
+    /// are computed with the scalar function from `std`.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn cos(self) -> Self;
+
+    /// Return the sine and cosine of each lane (in radians).
+    ///
+    /// This is faster than calling `sin` and `cos` separately. The error
+    /// bounds are the same as those of `sin` and `cos`.
+    fn sincos(self) -> (Self, Self);
+
+    /// Return the tangent of each lane (in radians).
+    ///
+    /// Lanes whose magnitude exceeds `8192` for `f32` or `2^30` for `f64`
+    /// are computed with the scalar function from `std`.
+    ///
+    /// Maximum error: 3 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn tan(self) -> Self;
+
+    /// Return the arctangent of each lane, in the range `[-pi/2, pi/2]`.
+    ///
+    /// Maximum error: 2 ULP for `f32` lanes and 2 ULP for `f64` lanes.
+    fn atan(self) -> Self;
+
+    /// Return the four quadrant arctangent of `self` (`y`) and `x` for each
+    /// lane, in the range `[-pi, pi]`.
+    ///
+    /// Maximum error: 3 ULP for `f32` lanes and 3 ULP for `f64` lanes.
+    fn atan2(self, x: Self) -> Self;
+
+    /// Raise each lane to the power of the corresponding lane in `n`.
+    ///
+    /// Negative lanes raised to a non-integer power produce `NaN`.
+    ///
+    /// `f32` lanes are computed in double precision, and have a maximum
+    /// error of 1 ULP. The error for `f64` lanes grows with the magnitude of
+    /// the exponent of the result: it is at most `2 + |n * log2(self)|` ULP.
+    fn pow(self, n: Self) -> Self;
+}
+
+/// Pick each lane from `$a` if the corresponding lane of `$mask` is set, and
+/// from `$b` otherwise.
+macro_rules! select {
+    ($ty:ident, $ity:ident, $mask:expr, $a:expr, $b:expr) => {{
+        let mask = $ity::from($mask);
+        unsafe {
+            let a: $ity = transmute($a);
+            let b: $ity = transmute($b);
+            transmute::<$ity, $ty>((mask & a) | (!mask & b))
+        }
+    }}
+}
+
+/// Recompute every lane of `$r` for which `$ok` is not set with the scalar
+/// function `$f` applied to the same lane of `$x`.
+macro_rules! scalar_fallback {
+    ($ty:ident, $elem:ident, $ok:expr, $x:expr, $r:expr, $f:expr) => {{
+        let (ok, x, mut r) = ($ok, $x, $r);
+        if !ok.all() {
+            let n = ::std::mem::size_of::<$ty>() / ::std::mem::size_of::<$elem>();
+            for i in 0..n as u32 {
+                if !ok.extract(i) {
+                    r = r.replace(i, $f(x.extract(i)));
+                }
+            }
+        }
+        r
+    }}
+}
+
+macro_rules! impl_f32 {
+    ($ty:ident, $ity:ident, $to_int:ident, $to_float:ident, $pow:ident) => {
+        impl $ty {
+            /// Compute `y * 2^n` for `n` in `[-152, 130]`, rounding once
+            /// when the result is denormal.
+            #[inline(always)]
+            fn scale(y: $ty, n: $ity) -> $ty {
+                let n1 = n >> 1u32;
+                let n2 = n - n1;
+                let bias = $ity::splat(127);
+                unsafe {
+                    let p1: $ty = transmute((n1 + bias) << 23u32);
+                    let p2: $ty = transmute((n2 + bias) << 23u32);
+                    y * p1 * p2
+                }
+            }
+
+            /// Split `x` into a mantissa in `[sqrt(0.5) - 1, sqrt(2) - 1)`
+            /// (shifted down by one) and an exponent. `x` must be positive
+            /// and finite.
+            #[inline(always)]
+            fn frexp(x: $ty) -> ($ty, $ty) {
+                let s = $ty::splat;
+                let denormal = x.lt(s(::std::f32::MIN_POSITIVE));
+                let x = select!($ty, $ity, denormal, x * s(8388608.0), x);
+                let adjust = $ity::from(denormal) & $ity::splat(23);
+                let bits: $ity = unsafe { transmute(x) };
+                let e = ((bits >> 23u32) & $ity::splat(0xff))
+                    - $ity::splat(126) - adjust;
+                let m: $ty = unsafe {
+                    transmute((bits & $ity::splat(!0x7f800000))
+                              | $ity::splat(0x3f000000))
+                };
+                let small = m.lt(s(0.707106781186547524));
+                let e = e + $ity::from(small);
+                let m = m - s(1.0) + select!($ty, $ity, small, m, s(0.0));
+                (m, e.$to_float())
+            }
+
+            /// Evaluate the core of the logarithm for a mantissa `m` from
+            /// `frexp`, returning `ln(1 + m) - m`.
+            #[inline(always)]
+            fn log_poly(m: $ty) -> $ty {
+                let s = $ty::splat;
+                let z = m * m;
+                let y = ((((((((s(7.0376836292E-2) * m
+                    - s(1.1514610310E-1)) * m
+                    + s(1.1676998740E-1)) * m
+                    - s(1.2420140846E-1)) * m
+                    + s(1.4249322787E-1)) * m
+                    - s(1.6668057665E-1)) * m
+                    + s(2.0000714765E-1)) * m
+                    - s(2.4999993993E-1)) * m
+                    + s(3.3333331174E-1)) * m * z;
+                y - s(0.5) * z
+            }
+
+            /// Apply the results of the special cases shared by `ln` and
+            /// `log2` to `r`.
+            #[inline(always)]
+            fn log_special(x: $ty, r: $ty) -> $ty {
+                let s = $ty::splat;
+                let r = select!($ty, $ity, x.eq(s(0.0)),
+                                s(::std::f32::NEG_INFINITY), r);
+                let r = select!($ty, $ity, x.eq(s(::std::f32::INFINITY)),
+                                x, r);
+                select!($ty, $ity, x.lt(s(0.0)) | x.ne(x),
+                        s(::std::f32::NAN), r)
+            }
+        }
+
+        impl Transcendental for $ty {
+            #[inline]
+            fn exp(self) -> $ty {
+                let s = $ty::splat;
+                let nan = self.ne(self);
+                let x = select!($ty, $ity, nan, s(0.0), self);
+                // Beyond these bounds, the result is `0` or `INFINITY`.
+                let x = select!($ty, $ity, x.lt(s(-104.0)), s(-104.0), x);
+                let x = select!($ty, $ity, x.gt(s(89.0)), s(89.0), x);
+
+                let fx = x * s(::std::f32::consts::LOG2_E);
+                let n = (fx + s(0.5).copysign(fx)).$to_int();
+                let nf = n.$to_float();
+                let r = x - nf * s(0.693359375) - nf * s(-2.12194440e-4);
+                let z = r * r;
+                let y = (((((s(1.9875691500E-4) * r
+                    + s(1.3981999507E-3)) * r
+                    + s(8.3334519073E-3)) * r
+                    + s(4.1665795894E-2)) * r
+                    + s(1.6666665459E-1)) * r
+                    + s(5.0000001201E-1)) * z + r + s(1.0);
+                select!($ty, $ity, nan, self, $ty::scale(y, n))
+            }
+
+            #[inline]
+            fn exp2(self) -> $ty {
+                let s = $ty::splat;
+                let nan = self.ne(self);
+                let x = select!($ty, $ity, nan, s(0.0), self);
+                let x = select!($ty, $ity, x.lt(s(-151.0)), s(-151.0), x);
+                let x = select!($ty, $ity, x.gt(s(129.0)), s(129.0), x);
+
+                let n = (x + s(0.5).copysign(x)).$to_int();
+                let f = x - n.$to_float();
+                let y = ((((((s(1.535336188319500E-4) * f
+                    + s(1.339887440266574E-3)) * f
+                    + s(9.618437357674640E-3)) * f
+                    + s(5.550332471162809E-2)) * f
+                    + s(2.402264791363012E-1)) * f
+                    + s(6.931472028550421E-1)) * f) + s(1.0);
+                select!($ty, $ity, nan, self, $ty::scale(y, n))
+            }
+
+            #[inline]
+            fn ln(self) -> $ty {
+                let s = $ty::splat;
+                let valid = self.gt(s(0.0))
+                    & self.lt(s(::std::f32::INFINITY));
+                let x = select!($ty, $ity, valid, self, s(1.0));
+                let (m, e) = $ty::frexp(x);
+                let y = $ty::log_poly(m) + e * s(-2.12194440e-4);
+                let r = m + y + e * s(0.693359375);
+                $ty::log_special(self, r)
+            }
+
+            #[inline]
+            fn log2(self) -> $ty {
+                let s = $ty::splat;
+                let valid = self.gt(s(0.0))
+                    & self.lt(s(::std::f32::INFINITY));
+                let x = select!($ty, $ity, valid, self, s(1.0));
+                let (m, e) = $ty::frexp(x);
+                let y = $ty::log_poly(m);
+                // log2(e) - 1, so that the leading terms are added exactly.
+                let log2ea = s(0.44269504088896340735992);
+                let r = y * log2ea + m * log2ea + y + m + e;
+                $ty::log_special(self, r)
+            }
+
+            #[inline]
+            fn sin(self) -> $ty {
+                self.sincos().0
+            }
+
+            #[inline]
+            fn cos(self) -> $ty {
+                self.sincos().1
+            }
+
+            #[inline]
+            fn sincos(self) -> ($ty, $ty) {
+                let s = $ty::splat;
+                let i = $ity::splat;
+                let bits: $ity = unsafe { transmute(self) };
+                let ax = self.abs();
+                let ok = ax.le(s(8192.0));
+                let ax = select!($ty, $ity, ok, ax, s(0.0));
+
+                // Reduce to `[-pi/4, pi/4]`, and remember the octant in `j`.
+                let j = (ax * s(1.27323954473516)).$to_int();
+                let j = (j + i(1)) & i(!1);
+                let y = j.$to_float();
+                let z = ((ax - y * s(0.78515625))
+                    - y * s(2.4187564849853515625e-4))
+                    - y * s(3.77489497744594108e-8);
+                let zz = z * z;
+                let ps = ((s(-1.9515295891E-4) * zz
+                    + s(8.3321608736E-3)) * zz
+                    - s(1.6666654611E-1)) * zz * z + z;
+                let pc = ((s(2.443315711809948E-005) * zz
+                    - s(1.388731625493765E-003)) * zz
+                    + s(4.166664568298827E-002)) * zz * zz
+                    - s(0.5) * zz + s(1.0);
+
+                let swap = (j & i(2)).ne(i(0));
+                let sin = select!($ty, $ity, swap, pc, ps);
+                let cos = select!($ty, $ity, swap, ps, pc);
+                let sin_sign = (bits & i(::std::i32::MIN))
+                    ^ ((j & i(4)) << 29u32);
+                let cos_sign = ((j + i(2)) & i(4)) << 29u32;
+                let (sin, cos): ($ty, $ty) = unsafe {
+                    let sin: $ity = transmute(sin);
+                    let cos: $ity = transmute(cos);
+                    (transmute(sin ^ sin_sign), transmute(cos ^ cos_sign))
+                };
+                (
+                    scalar_fallback!($ty, f32, ok, self, sin, f32::sin),
+                    scalar_fallback!($ty, f32, ok, self, cos, f32::cos),
+                )
+            }
+
+            #[inline]
+            fn tan(self) -> $ty {
+                let s = $ty::splat;
+                let i = $ity::splat;
+                let bits: $ity = unsafe { transmute(self) };
+                let ax = self.abs();
+                let ok = ax.le(s(8192.0));
+                let ax = select!($ty, $ity, ok, ax, s(0.0));
+
+                let j = (ax * s(1.27323954473516)).$to_int();
+                let j = (j + i(1)) & i(!1);
+                let y = j.$to_float();
+                let z = ((ax - y * s(0.78515625))
+                    - y * s(2.4187564849853515625e-4))
+                    - y * s(3.77489497744594108e-8);
+                let zz = z * z;
+                let r = ((((((s(9.38540185543E-3) * zz
+                    + s(3.11992232697E-3)) * zz
+                    + s(2.44301354525E-2)) * zz
+                    + s(5.34112807005E-2)) * zz
+                    + s(1.33387994085E-1)) * zz
+                    + s(3.33331568548E-1)) * zz * z) + z;
+                let cot = (j & i(2)).ne(i(0));
+                let r = select!($ty, $ity, cot, s(-1.0) / r, r);
+                let r: $ty = unsafe {
+                    let r: $ity = transmute(r);
+                    transmute(r ^ (bits & i(::std::i32::MIN)))
+                };
+                scalar_fallback!($ty, f32, ok, self, r, f32::tan)
+            }
+
+            #[inline]
+            fn atan(self) -> $ty {
+                let s = $ty::splat;
+                let ax = self.abs();
+                let big = ax.gt(s(2.414213562373095));
+                let mid = !big & ax.gt(s(0.4142135623730950));
+                let x = select!($ty, $ity, big, s(-1.0) / ax,
+                    select!($ty, $ity, mid, (ax - s(1.0)) / (ax + s(1.0)),
+                            ax));
+                let y0 = select!($ty, $ity, big, s(::std::f32::consts::FRAC_PI_2),
+                    select!($ty, $ity, mid, s(::std::f32::consts::FRAC_PI_4),
+                            s(0.0)));
+                let z = x * x;
+                let r = y0 + ((((s(8.05374449538e-2) * z
+                    - s(1.38776856032E-1)) * z
+                    + s(1.99777106478E-1)) * z
+                    - s(3.33329491539E-1)) * z * x + x);
+                r.copysign(self)
+            }
+
+            #[inline]
+            fn atan2(self, x: $ty) -> $ty {
+                use std::f32::consts::{PI, FRAC_PI_4};
+
+                let s = $ty::splat;
+                let y = self;
+                // Use the sign bit, so that `-0.0` counts as negative.
+                let x_neg = s(1.0).copysign(x).lt(s(0.0));
+                let r = (y / x).atan();
+                let r = select!($ty, $ity, x_neg, r + s(PI).copysign(y), r);
+
+                let zero = y.eq(s(0.0)) & x.eq(s(0.0));
+                let r = select!($ty, $ity, zero,
+                    select!($ty, $ity, x_neg, s(PI), s(0.0)).copysign(y), r);
+                let inf = y.abs().eq(s(::std::f32::INFINITY))
+                    & x.abs().eq(s(::std::f32::INFINITY));
+                select!($ty, $ity, inf,
+                    select!($ty, $ity, x_neg, s(3.0 * FRAC_PI_4), s(FRAC_PI_4))
+                        .copysign(y), r)
+            }
+
+            #[inline]
+            fn pow(self, n: $ty) -> $ty {
+                $pow(self, n)
+            }
+        }
+    }
+}
+
+macro_rules! impl_f64 {
+    ($ty:ident, $ity:ident, $to_int:ident, $to_float:ident) => {
+        impl $ty {
+            /// Compute `y * 2^n` for `n` in `[-2044, 2046]`, rounding once
+            /// when the result is denormal.
+            #[inline(always)]
+            fn scale(y: $ty, n: $ity) -> $ty {
+                let n1 = n >> 1u32;
+                let n2 = n - n1;
+                let bias = $ity::splat(1023);
+                unsafe {
+                    let p1: $ty = transmute((n1 + bias) << 52u32);
+                    let p2: $ty = transmute((n2 + bias) << 52u32);
+                    y * p1 * p2
+                }
+            }
+
+            /// Split `x` into a mantissa in `[sqrt(0.5) - 1, sqrt(2) - 1)`
+            /// (shifted down by one) and an exponent. `x` must be positive
+            /// and finite.
+            #[inline(always)]
+            fn frexp(x: $ty) -> ($ty, $ty) {
+                let s = $ty::splat;
+                let denormal = x.lt(s(::std::f64::MIN_POSITIVE));
+                let x = select!($ty, $ity, denormal,
+                                x * s(4503599627370496.0), x);
+                let adjust = $ity::from(denormal) & $ity::splat(52);
+                let bits: $ity = unsafe { transmute(x) };
+                let e = ((bits >> 52u32) & $ity::splat(0x7ff))
+                    - $ity::splat(1022) - adjust;
+                let m: $ty = unsafe {
+                    transmute((bits & $ity::splat(!0x7ff0000000000000))
+                              | $ity::splat(0x3fe0000000000000))
+                };
+                let small = m.lt(s(0.70710678118654752440));
+                let e = e + $ity::from(small);
+                let m = m - s(1.0) + select!($ty, $ity, small, m, s(0.0));
+                (m, e.$to_float())
+            }
+
+            /// Evaluate the core of the logarithm for a mantissa `m` from
+            /// `frexp`, returning `ln(1 + m) - m`.
+            #[inline(always)]
+            fn log_poly(m: $ty) -> $ty {
+                let s = $ty::splat;
+                let z = m * m;
+                let p = ((((s(1.01875663804580931796E-4) * m
+                    + s(4.97494994976747001425E-1)) * m
+                    + s(4.70579119878881725854E0)) * m
+                    + s(1.44989225341610930846E1)) * m
+                    + s(1.79368678507819816313E1)) * m
+                    + s(7.70838733755885391666E0);
+                let q = ((((m
+                    + s(1.12873587189167450590E1)) * m
+                    + s(4.52279145837532221105E1)) * m
+                    + s(8.29875266912776603211E1)) * m
+                    + s(7.11544750618563894466E1)) * m
+                    + s(2.31251620126765340583E1);
+                m * (z * p / q) - s(0.5) * z
+            }
+
+            /// Apply the results of the special cases shared by `ln` and
+            /// `log2` to `r`.
+            #[inline(always)]
+            fn log_special(x: $ty, r: $ty) -> $ty {
+                let s = $ty::splat;
+                let r = select!($ty, $ity, x.eq(s(0.0)),
+                                s(::std::f64::NEG_INFINITY), r);
+                let r = select!($ty, $ity, x.eq(s(::std::f64::INFINITY)),
+                                x, r);
+                select!($ty, $ity, x.lt(s(0.0)) | x.ne(x),
+                        s(::std::f64::NAN), r)
+            }
+        }
+
+        impl Transcendental for $ty {
+            #[inline]
+            fn exp(self) -> $ty {
+                let s = $ty::splat;
+                let nan = self.ne(self);
+                let x = select!($ty, $ity, nan, s(0.0), self);
+                // Beyond these bounds, the result is `0` or `INFINITY`.
+                let x = select!($ty, $ity, x.lt(s(-746.0)), s(-746.0), x);
+                let x = select!($ty, $ity, x.gt(s(710.0)), s(710.0), x);
+
+                let fx = x * s(::std::f64::consts::LOG2_E);
+                let n = (fx + s(0.5).copysign(fx)).$to_int();
+                let nf = n.$to_float();
+                let r = x - nf * s(6.93145751953125E-1)
+                    - nf * s(1.42860682030941723212E-6);
+                let rr = r * r;
+                let p = r * ((s(1.26177193074810590878E-4) * rr
+                    + s(3.02994407707441961300E-2)) * rr
+                    + s(9.99999999999999999910E-1));
+                let q = ((s(3.00198505138664455042E-6) * rr
+                    + s(2.52448340349684104192E-3)) * rr
+                    + s(2.27265548208155028766E-1)) * rr
+                    + s(2.00000000000000000009E0);
+                let y = s(1.0) + s(2.0) * (p / (q - p));
+                select!($ty, $ity, nan, self, $ty::scale(y, n))
+            }
+
+            #[inline]
+            fn exp2(self) -> $ty {
+                let s = $ty::splat;
+                let nan = self.ne(self);
+                let x = select!($ty, $ity, nan, s(0.0), self);
+                let x = select!($ty, $ity, x.lt(s(-1076.0)), s(-1076.0), x);
+                let x = select!($ty, $ity, x.gt(s(1025.0)), s(1025.0), x);
+
+                let n = (x + s(0.5).copysign(x)).$to_int();
+                let f = x - n.$to_float();
+                let ff = f * f;
+                let p = f * ((s(2.30933477057345225087E-2) * ff
+                    + s(2.02020656693165307700E1)) * ff
+                    + s(1.51390680115615096133E3));
+                let q = (ff + s(2.33184211722314911771E2)) * ff
+                    + s(4.36821166879210612817E3);
+                let y = s(1.0) + s(2.0) * (p / (q - p));
+                select!($ty, $ity, nan, self, $ty::scale(y, n))
+            }
+
+            #[inline]
+            fn ln(self) -> $ty {
+                let s = $ty::splat;
+                let valid = self.gt(s(0.0))
+                    & self.lt(s(::std::f64::INFINITY));
+                let x = select!($ty, $ity, valid, self, s(1.0));
+                let (m, e) = $ty::frexp(x);
+                let y = $ty::log_poly(m)
+                    - e * s(2.121944400546905827679e-4);
+                let r = m + y + e * s(0.693359375);
+                $ty::log_special(self, r)
+            }
+
+            #[inline]
+            fn log2(self) -> $ty {
+                let s = $ty::splat;
+                let valid = self.gt(s(0.0))
+                    & self.lt(s(::std::f64::INFINITY));
+                let x = select!($ty, $ity, valid, self, s(1.0));
+                let (m, e) = $ty::frexp(x);
+                let y = $ty::log_poly(m);
+                // log2(e) - 1, so that the leading terms are added exactly.
+                let log2ea = s(4.4269504088896340735992e-1);
+                let r = y * log2ea + m * log2ea + y + m + e;
+                $ty::log_special(self, r)
+            }
+
+            #[inline]
+            fn sin(self) -> $ty {
+                self.sincos().0
+            }
+
+            #[inline]
+            fn cos(self) -> $ty {
+                self.sincos().1
+            }
+
+            #[inline]
+            fn sincos(self) -> ($ty, $ty) {
+                let s = $ty::splat;
+                let i = $ity::splat;
+                let bits: $ity = unsafe { transmute(self) };
+                let ax = self.abs();
+                let ok = ax.le(s(1.073741824e9));
+                let ax = select!($ty, $ity, ok, ax, s(0.0));
+
+                // Reduce to `[-pi/4, pi/4]`, and remember the octant in `j`.
+                let j = (ax * s(1.27323954473516268615)).$to_int();
+                let j = (j + i(1)) & i(!1);
+                let y = j.$to_float();
+                let z = ((ax - y * s(7.85398125648498535156E-1))
+                    - y * s(3.77489470793079817668E-8))
+                    - y * s(2.69515142907905952645E-15);
+                let zz = z * z;
+                let ps = z + z * zz * ((((((s(1.58962301576546568060E-10) * zz
+                    - s(2.50507477628578072866E-8)) * zz
+                    + s(2.75573136213857245213E-6)) * zz
+                    - s(1.98412698295895385996E-4)) * zz
+                    + s(8.33333333332211858878E-3)) * zz
+                    - s(1.66666666666666307295E-1)));
+                let pc = s(1.0) - s(0.5) * zz
+                    + zz * zz * ((((((s(-1.13585365213876817300E-11) * zz
+                    + s(2.08757008419747316778E-9)) * zz
+                    - s(2.75573141792967388112E-7)) * zz
+                    + s(2.48015872888517045348E-5)) * zz
+                    - s(1.38888888888730564116E-3)) * zz
+                    + s(4.16666666666665929218E-2)));
+
+                let swap = (j & i(2)).ne(i(0));
+                let sin = select!($ty, $ity, swap, pc, ps);
+                let cos = select!($ty, $ity, swap, ps, pc);
+                let sin_sign = (bits & i(::std::i64::MIN))
+                    ^ ((j & i(4)) << 61u32);
+                let cos_sign = ((j + i(2)) & i(4)) << 61u32;
+                let (sin, cos): ($ty, $ty) = unsafe {
+                    let sin: $ity = transmute(sin);
+                    let cos: $ity = transmute(cos);
+                    (transmute(sin ^ sin_sign), transmute(cos ^ cos_sign))
+                };
+                (
+                    scalar_fallback!($ty, f64, ok, self, sin, f64::sin),
+                    scalar_fallback!($ty, f64, ok, self, cos, f64::cos),
+                )
+            }
+
+            #[inline]
+            fn tan(self) -> $ty {
+                let s = $ty::splat;
+                let i = $ity::splat;
+                let bits: $ity = unsafe { transmute(self) };
+                let ax = self.abs();
+                let ok = ax.le(s(1.073741824e9));
+                let ax = select!($ty, $ity, ok, ax, s(0.0));
+
+                let j = (ax * s(1.27323954473516268615)).$to_int();
+                let j = (j + i(1)) & i(!1);
+                let y = j.$to_float();
+                let z = ((ax - y * s(7.853981554508209228515625E-1))
+                    - y * s(7.94662735614792836714E-9))
+                    - y * s(3.06161699786838294307E-17);
+                let zz = z * z;
+                let p = (s(-1.30936939181383777646E4) * zz
+                    + s(1.15351664838587416140E6)) * zz
+                    - s(1.79565251976484877988E7);
+                let q = (((zz
+                    + s(1.36812963470692954678E4)) * zz
+                    - s(1.32089234440210967447E6)) * zz
+                    + s(2.50083801823357915839E7)) * zz
+                    - s(5.38695755929454629881E7);
+                let r = z + z * (zz * p / q);
+                let cot = (j & i(2)).ne(i(0));
+                let r = select!($ty, $ity, cot, s(-1.0) / r, r);
+                let r: $ty = unsafe {
+                    let r: $ity = transmute(r);
+                    transmute(r ^ (bits & i(::std::i64::MIN)))
+                };
+                scalar_fallback!($ty, f64, ok, self, r, f64::tan)
+            }
+
+            #[inline]
+            fn atan(self) -> $ty {
+                let s = $ty::splat;
+                // Low bits of pi/2 that don't fit in `FRAC_PI_2`.
+                let morebits = 6.123233995736765886130E-17;
+                let ax = self.abs();
+                let big = ax.gt(s(2.41421356237309504880));
+                let mid = !big & ax.gt(s(0.66));
+                let x = select!($ty, $ity, big, s(-1.0) / ax,
+                    select!($ty, $ity, mid, (ax - s(1.0)) / (ax + s(1.0)),
+                            ax));
+                let y0 = select!($ty, $ity, big, s(::std::f64::consts::FRAC_PI_2),
+                    select!($ty, $ity, mid, s(::std::f64::consts::FRAC_PI_4),
+                            s(0.0)));
+                let extra = select!($ty, $ity, big, s(morebits),
+                    select!($ty, $ity, mid, s(0.5 * morebits), s(0.0)));
+                let z = x * x;
+                let p = (((s(-8.750608600031904122785E-1) * z
+                    - s(1.615753718733365076637E1)) * z
+                    - s(7.500855792314704667340E1)) * z
+                    - s(1.228866684490136173410E2)) * z
+                    - s(6.485021904942025371773E1);
+                let q = ((((z
+                    + s(2.485846490142306297962E1)) * z
+                    + s(1.650270098316988542046E2)) * z
+                    + s(4.328810604912902668951E2)) * z
+                    + s(4.853903996359136964868E2)) * z
+                    + s(1.945506571482613964425E2);
+                let z = z * p / q;
+                let r = y0 + ((x * z + x) + extra);
+                r.copysign(self)
+            }
+
+            #[inline]
+            fn atan2(self, x: $ty) -> $ty {
+                use std::f64::consts::{PI, FRAC_PI_4};
+
+                let s = $ty::splat;
+                let y = self;
+                // Use the sign bit, so that `-0.0` counts as negative.
+                let x_neg = s(1.0).copysign(x).lt(s(0.0));
+                let r = (y / x).atan();
+                let r = select!($ty, $ity, x_neg, r + s(PI).copysign(y), r);
+
+                let zero = y.eq(s(0.0)) & x.eq(s(0.0));
+                let r = select!($ty, $ity, zero,
+                    select!($ty, $ity, x_neg, s(PI), s(0.0)).copysign(y), r);
+                let inf = y.abs().eq(s(::std::f64::INFINITY))
+                    & x.abs().eq(s(::std::f64::INFINITY));
+                select!($ty, $ity, inf,
+                    select!($ty, $ity, x_neg, s(3.0 * FRAC_PI_4), s(FRAC_PI_4))
+                        .copysign(y), r)
+            }
+
+            #[inline]
+            fn pow(self, n: $ty) -> $ty {
+                let s = $ty::splat;
+                let x = self;
+                let r = (n * x.abs().log2()).exp2();
+
+                // A negative base is only defined for integer exponents, and
+                // the sign of the result depends on whether it is odd.
+                let int = n.trunc().eq(n);
+                let half = n * s(0.5);
+                let odd = int & half.trunc().ne(half);
+                let x_neg = s(1.0).copysign(x).lt(s(0.0));
+                let r = select!($ty, $ity, x_neg & odd, r * s(-1.0), r);
+                let finite_neg = x.lt(s(0.0))
+                    & x.gt(s(::std::f64::NEG_INFINITY));
+                let r = select!($ty, $ity, finite_neg & !int,
+                                s(::std::f64::NAN), r);
+
+                // `0 * INFINITY` is `NaN` above, but these are all `1`.
+                let one = n.eq(s(0.0)) | x.eq(s(1.0))
+                    | (x.eq(s(-1.0)) & n.abs().eq(s(::std::f64::INFINITY)));
+                select!($ty, $ity, one, s(1.0), r)
+            }
+        }
+    }
+}
+
+impl_f32!(f32x4, i32x4, as_i32x4, as_f32x4, pow_f32x4);
+impl_f32!(f32x8, i32x8, as_i32x8, as_f32x8, pow_f32x8);
+impl_f32!(f32x16, i32x16, as_i32x16, as_f32x16, pow_f32x16);
+impl_f64!(f64x2, i64x2, as_i64x2, as_f64x2);
+impl_f64!(f64x4, i64x4, as_i64x4, as_f64x4);
+impl_f64!(f64x8, i64x8, as_i64x8, as_f64x8);
+
+// `pow` on `f32` lanes is computed in double precision, since the error of
+// `exp2(n * log2(x))` grows with the magnitude of its result.
+
+#[inline]
+fn pow_f32x4(x: f32x4, n: f32x4) -> f32x4 {
+    let (x, n): (f64x4, f64x4) = unsafe { (simd_cast(x), simd_cast(n)) };
+    unsafe { simd_cast(x.pow(n)) }
+}
+
+#[inline]
+fn pow_f32x8(x: f32x8, n: f32x8) -> f32x8 {
+    let (x, n): (f64x8, f64x8) = unsafe { (simd_cast(x), simd_cast(n)) };
+    unsafe { simd_cast(x.pow(n)) }
+}
+
+#[inline]
+fn pow_f32x16(x: f32x16, n: f32x16) -> f32x16 {
+    unsafe {
+        let xlo: f32x8 = simd_shuffle8(x, x, [0, 1, 2, 3, 4, 5, 6, 7]);
+        let xhi: f32x8 = simd_shuffle8(x, x, [8, 9, 10, 11, 12, 13, 14, 15]);
+        let nlo: f32x8 = simd_shuffle8(n, n, [0, 1, 2, 3, 4, 5, 6, 7]);
+        let nhi: f32x8 = simd_shuffle8(n, n, [8, 9, 10, 11, 12, 13, 14, 15]);
+        let rlo = pow_f32x8(xlo, nlo);
+        let rhi = pow_f32x8(xhi, nhi);
+        simd_shuffle16(rlo, rhi, [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32, f64};
+
+    use simd::*;
+    use super::Transcendental;
+
+    /// Return the distance between `a` and `b` in units in the last place.
+    fn ulp32(a: f32, b: f32) -> u32 {
+        if a.is_nan() && b.is_nan() || a == b {
+            return 0;
+        }
+        // Map the sign-magnitude representation to a monotonic one.
+        fn key(x: f32) -> u32 {
+            let bits = x.to_bits();
+            if bits >> 31 == 1 { !bits } else { bits | (1 << 31) }
+        }
+        let (a, b) = (key(a), key(b));
+        if a > b { a - b } else { b - a }
+    }
+
+    fn ulp64(a: f64, b: f64) -> u64 {
+        if a.is_nan() && b.is_nan() || a == b {
+            return 0;
+        }
+        fn key(x: f64) -> u64 {
+            let bits = x.to_bits();
+            if bits >> 63 == 1 { !bits } else { bits | (1 << 63) }
+        }
+        let (a, b) = (key(a), key(b));
+        if a > b { a - b } else { b - a }
+    }
+
+    fn inputs32() -> Vec<f32> {
+        let mut xs = vec![
+            0.0, -0.0, 1.0, -1.0, 0.5, 2.0, 3.0, -3.0, 10.0, -10.0,
+            f32::consts::PI, -f32::consts::FRAC_PI_2, 88.0, -87.0, -100.0,
+            1e-10, 1e-40, -1e-40, 1000.0, 8000.0, 1e20, -1e20,
+            f32::INFINITY, f32::NEG_INFINITY, f32::NAN,
+        ];
+        let mut x = 0.001f32;
+        while x < 100.0 {
+            xs.push(x);
+            xs.push(-x);
+            x *= 1.37;
+        }
+        // A dense linear sweep, to catch errors in the range reduction
+        // between the points of the geometric one.
+        for i in -4000..4001 {
+            xs.push(i as f32 * 0.025);
+        }
+        xs
+    }
+
+    fn inputs64() -> Vec<f64> {
+        let mut xs = vec![
+            0.0, -0.0, 1.0, -1.0, 0.5, 2.0, 3.0, -3.0, 10.0, -10.0,
+            f64::consts::PI, -f64::consts::FRAC_PI_2, 700.0, -700.0, -740.0,
+            1e-10, 1e-310, -1e-310, 1000.0, 1e8, 1e20, -1e20,
+            f64::INFINITY, f64::NEG_INFINITY, f64::NAN,
+        ];
+        let mut x = 0.001f64;
+        while x < 100.0 {
+            xs.push(x);
+            xs.push(-x);
+            x *= 1.37;
+        }
+        // A dense linear sweep, to catch errors in the range reduction
+        // between the points of the geometric one.
+        for i in -4000..4001 {
+            xs.push(i as f64 * 0.025);
+        }
+        xs
+    }
+
+    fn check32<F, G>(name: &str, max: u32, f: F, g: G)
+        where F: Fn(f32x4) -> f32x4, G: Fn(f32) -> f32
+    {
+        // Every lane gets a different input, to catch lanes that mix.
+        for chunk in inputs32().chunks(4) {
+            let mut xs = [chunk[0]; 4];
+            xs[..chunk.len()].copy_from_slice(chunk);
+            let r = f(f32x4::load(&xs, 0));
+            for (i, &x) in xs.iter().enumerate() {
+                let (r, e) = (r.extract(i as u32), g(x));
+                assert!(ulp32(r, e) <= max,
+                        "{}({:e}) = {:e}, expected {:e}", name, x, r, e);
+            }
+        }
+    }
+
+    fn check64<F, G>(name: &str, max: u64, f: F, g: G)
+        where F: Fn(f64x2) -> f64x2, G: Fn(f64) -> f64
+    {
+        // Every lane gets a different input, to catch lanes that mix.
+        for chunk in inputs64().chunks(2) {
+            let mut xs = [chunk[0]; 2];
+            xs[..chunk.len()].copy_from_slice(chunk);
+            let r = f(f64x2::load(&xs, 0));
+            for (i, &x) in xs.iter().enumerate() {
+                let (r, e) = (r.extract(i as u32), g(x));
+                assert!(ulp64(r, e) <= max,
+                        "{}({:e}) = {:e}, expected {:e}", name, x, r, e);
+            }
+        }
+    }
+
+    #[test]
+    fn exp() {
+        check32("exp", 2, |x| x.exp(), |x: f32| x.exp());
+        check64("exp", 2, |x| x.exp(), |x: f64| x.exp());
+    }
+
+    #[test]
+    fn exp2() {
+        check32("exp2", 2, |x| x.exp2(), |x: f32| x.exp2());
+        check64("exp2", 2, |x| x.exp2(), |x: f64| x.exp2());
+    }
+
+    #[test]
+    fn ln() {
+        check32("ln", 2, |x| x.ln(), |x: f32| x.ln());
+        check64("ln", 2, |x| x.ln(), |x: f64| x.ln());
+    }
+
+    #[test]
+    fn log2() {
+        check32("log2", 2, |x| x.log2(), |x: f32| x.log2());
+        check64("log2", 2, |x| x.log2(), |x: f64| x.log2());
+    }
+
+    #[test]
+    fn sin() {
+        check32("sin", 2, |x| x.sin(), |x: f32| x.sin());
+        check64("sin", 2, |x| x.sin(), |x: f64| x.sin());
+    }
+
+    #[test]
+    fn cos() {
+        check32("cos", 2, |x| x.cos(), |x: f32| x.cos());
+        check64("cos", 2, |x| x.cos(), |x: f64| x.cos());
+    }
+
+    #[test]
+    fn sincos() {
+        let x = f32x8::new(0.0, 1.0, -2.0, 3.0, 1e4, -0.5, 100.0, -7.0);
+        let (s, c) = x.sincos();
+        assert_eq!(s, x.sin());
+        assert_eq!(c, x.cos());
+    }
+
+    #[test]
+    fn tan() {
+        check32("tan", 3, |x| x.tan(), |x: f32| x.tan());
+        check64("tan", 2, |x| x.tan(), |x: f64| x.tan());
+    }
+
+    #[test]
+    fn atan() {
+        check32("atan", 2, |x| x.atan(), |x: f32| x.atan());
+        check64("atan", 2, |x| x.atan(), |x: f64| x.atan());
+    }
+
+    #[test]
+    fn atan2() {
+        for &y in &inputs32() {
+            for &x in &[0.0, -0.0, 1.0, -1.0, 5.0, -0.25,
+                        f32::INFINITY, f32::NEG_INFINITY, f32::NAN] {
+                let r = f32x4::splat(y).atan2(f32x4::splat(x)).extract(0);
+                let e = y.atan2(x);
+                assert!(ulp32(r, e) <= 3,
+                        "atan2({:e}, {:e}) = {:e}, expected {:e}", y, x, r, e);
+            }
+        }
+        for &y in &inputs64() {
+            for &x in &[0.0, -0.0, 1.0, -1.0, 5.0, -0.25,
+                        f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+                let r = f64x2::splat(y).atan2(f64x2::splat(x)).extract(0);
+                let e = y.atan2(x);
+                assert!(ulp64(r, e) <= 3,
+                        "atan2({:e}, {:e}) = {:e}, expected {:e}", y, x, r, e);
+            }
+        }
+    }
+
+    #[test]
+    fn pow() {
+        for &x in &inputs32() {
+            for &n in &[0.0, 1.0, -1.0, 2.0, 3.0, 0.5, -2.5, 10.0,
+                        f32::INFINITY, f32::NAN] {
+                let r = f32x16::splat(x).pow(f32x16::splat(n)).extract(9);
+                let e = x.powf(n);
+                assert!(ulp32(r, e) <= 1,
+                        "pow({:e}, {:e}) = {:e}, expected {:e}", x, n, r, e);
+            }
+        }
+        for &x in &inputs64() {
+            for &n in &[0.0, 1.0, -1.0, 2.0, 3.0, 0.5, -2.5,
+                        f64::INFINITY, f64::NAN] {
+                let r = f64x4::splat(x).pow(f64x4::splat(n)).extract(3);
+                let e = x.powf(n);
+                let max = 2 + (n * x.abs().log2()).abs() as u64;
+                assert!(ulp64(r, e) <= max,
+                        "pow({:e}, {:e}) = {:e}, expected {:e}", x, n, r, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Substring search on byte slices.
+//!
+//! On x86 CPUs supporting SSE4.2 the haystack is scanned 16 bytes at a time
+//! with `pcmpestrm` in "equal ordered" mode. Each scan returns a bit mask of
+//! the positions where the first (up to) 16 bytes of the needle start,
+//! including needles that only partially fit at the end of the block. Every
+//! candidate is then verified against the whole needle. Everywhere else a
+//! scalar search is used.
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// An empty needle matches at index `0`.
+///
+/// ```rust
+/// use stdsimd::search::find;
+///
+/// assert_eq!(find(b"Hello! Good-Bye!", b"Bye"), Some(12));
+/// assert_eq!(find(b"Hello! Good-Bye!", b"bye"), None);
+/// ```
+pub fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if ::x86::cpu_features().sse42() {
+            return unsafe { find_sse42(haystack, needle) };
+        }
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`.
+///
+/// An empty needle matches at index `haystack.len()`.
+///
+/// ```rust
+/// use stdsimd::search::rfind;
+///
+/// assert_eq!(rfind(b"abcabc", b"bc"), Some(4));
+/// ```
+pub fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(haystack.len());
+    }
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if ::x86::cpu_features().sse42() {
+            return unsafe { rfind_sse42(haystack, needle) };
+        }
+    }
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+/// Returns an iterator over the indices of the non-overlapping occurrences
+/// of `needle` in `haystack`, from left to right.
+///
+/// Like `str::match_indices`, an empty needle matches at every index from
+/// `0` to `haystack.len()`.
+///
+/// ```rust
+/// use stdsimd::search::find_iter;
+///
+/// let found: Vec<usize> = find_iter(b"aaaa", b"aa").collect();
+/// assert_eq!(found, [0, 2]);
+/// ```
+pub fn find_iter<'a>(haystack: &'a [u8], needle: &'a [u8]) -> FindIter<'a> {
+    FindIter { haystack: haystack, needle: needle, pos: 0 }
+}
+
+/// An iterator over the occurrences of a needle in a haystack.
+///
+/// This struct is created by the `find_iter` function.
+#[derive(Clone, Debug)]
+pub struct FindIter<'a> {
+    haystack: &'a [u8],
+    needle: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Iterator for FindIter<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.pos > self.haystack.len() {
+            return None;
+        }
+        match find(&self.haystack[self.pos..], self.needle) {
+            Some(i) => {
+                let found = self.pos + i;
+                self.pos = found + ::std::cmp::max(self.needle.len(), 1);
+                Some(found)
+            }
+            None => {
+                self.pos = self.haystack.len() + 1;
+                None
+            }
+        }
+    }
+}
+
+/// Returns the bit mask of the positions in the 16 bytes of `haystack`
+/// starting at `offset` where the needle prefix `vneedle` of length `la`
+/// starts, or partially starts at the end of a full block.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+unsafe fn candidates(
+    vneedle: ::x86::__m128i,
+    la: usize,
+    haystack: &[u8],
+    offset: usize,
+) -> u32 {
+    use v128::u8x16;
+    use x86::{__m128i, _mm_cmpestrm, _SIDD_CMP_EQUAL_ORDERED};
+
+    let lb = ::std::cmp::min(16, haystack.len() - offset);
+    let vhaystack = __m128i::from(u8x16::load_partial(haystack, offset));
+    let mask = _mm_cmpestrm(
+        vneedle, la as i32, vhaystack, lb as i32, _SIDD_CMP_EQUAL_ORDERED);
+    ::v128::u16x8::from(mask).extract(0) as u32
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature = "+sse4.2"]
+unsafe fn find_sse42(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    use v128::u8x16;
+    use x86::__m128i;
+
+    let la = ::std::cmp::min(16, needle.len());
+    let vneedle = __m128i::from(u8x16::load_partial(needle, 0));
+    let mut offset = 0;
+    while offset + needle.len() <= haystack.len() {
+        let mut mask = candidates(vneedle, la, haystack, offset);
+        while mask != 0 {
+            let i = offset + mask.trailing_zeros() as usize;
+            if i + needle.len() > haystack.len() {
+                return None;
+            }
+            if &haystack[i..i + needle.len()] == needle {
+                return Some(i);
+            }
+            mask &= mask - 1;
+        }
+        offset += 16;
+    }
+    None
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature = "+sse4.2"]
+unsafe fn rfind_sse42(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    use v128::u8x16;
+    use x86::__m128i;
+
+    if needle.len() > haystack.len() {
+        return None;
+    }
+    let last = haystack.len() - needle.len();
+    let la = ::std::cmp::min(16, needle.len());
+    let vneedle = __m128i::from(u8x16::load_partial(needle, 0));
+    let mut offset = last - last % 16;
+    loop {
+        let mut mask = candidates(vneedle, la, haystack, offset);
+        while mask != 0 {
+            let i = offset + 31 - mask.leading_zeros() as usize;
+            if i <= last && &haystack[i..i + needle.len()] == needle {
+                return Some(i);
+            }
+            mask &= !(1 << (i - offset));
+        }
+        if offset == 0 {
+            return None;
+        }
+        offset -= 16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, find_iter, rfind};
+
+    fn naive_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        haystack.windows(needle.len()).position(|w| w == needle)
+    }
+
+    fn naive_rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(haystack.len());
+        }
+        haystack.windows(needle.len()).rposition(|w| w == needle)
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(find(b"foobar", b"bar"), Some(3));
+        assert_eq!(find(b"foobar", b"baz"), None);
+        assert_eq!(find(b"foo", b"foobar"), None);
+        assert_eq!(find(b"", b"a"), None);
+        assert_eq!(find(b"", b""), Some(0));
+        assert_eq!(rfind(b"foobarbar", b"bar"), Some(6));
+        assert_eq!(rfind(b"foobar", b""), Some(6));
+        assert_eq!(rfind(b"foo", b"foobar"), None);
+    }
+
+    #[test]
+    fn across_blocks() {
+        // Matches straddling the 16-byte blocks, and needles longer than
+        // a block.
+        let haystack = b"0123456789abcdef0123456789ABCDEF needle xneedlex";
+        assert_eq!(find(haystack, b"ef0123"), Some(14));
+        assert_eq!(find(haystack, b"0123456789ABCDEF needle"), Some(16));
+        assert_eq!(rfind(haystack, b"0123"), Some(16));
+        assert_eq!(rfind(haystack, b"needle"), Some(41));
+        assert_eq!(find(haystack, b"needlex"), Some(41));
+        assert_eq!(find(haystack, b"needlexx"), None);
+    }
+
+    #[test]
+    fn exhaustive() {
+        // Small alphabets produce many partial matches.
+        let haystack: Vec<u8> =
+            (0..200u32).map(|i| b"ab"[(i * i / 7 % 2) as usize]).collect();
+        for start in 0..40 {
+            for len in 0..40 {
+                let needle = &haystack[start..start + len];
+                for end in 150..haystack.len() + 1 {
+                    let h = &haystack[..end];
+                    assert_eq!(find(h, needle), naive_find(h, needle));
+                    assert_eq!(rfind(h, needle), naive_rfind(h, needle));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iter() {
+        let found: Vec<usize> = find_iter(b"abcabcab", b"abc").collect();
+        assert_eq!(found, [0, 3]);
+        let found: Vec<usize> = find_iter(b"aaaaa", b"aa").collect();
+        assert_eq!(found, [0, 2]);
+        let found: Vec<usize> = find_iter(b"ab", b"").collect();
+        assert_eq!(found, [0, 1, 2]);
+        assert_eq!(find_iter(b"ab", b"c").next(), None);
+    }
+}
@@ -0,0 +1,172 @@
+/// Compiles a function body several times with different target features
+/// and dispatches to the best version for the running CPU.
+///
+/// The `#[versions(...)]` attribute lists the target feature sets to compile
+/// the body for, in order of preference, spelled like the argument of
+/// `#[target_feature]` (e.g. `"+avx2"` or `"+sse4.2,+popcnt"`). The body is
+/// also compiled once without any extra features, which is used when the CPU
+/// supports none of the listed sets.
+///
+/// The first call detects the features of the running CPU, picks the first
+/// version whose features are all supported and caches a pointer to it, so
+/// later calls only pay for an indirect call.
+///
+/// Generic functions and methods are not supported.
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(target_feature)]
+///
+/// #[macro_use]
+/// extern crate stdsimd;
+///
+/// multiversion! {
+///     #[versions("+avx2", "+sse4.2")]
+///     /// Returns the sum of the bytes in `xs`.
+///     pub fn sum(xs: &[u8]) -> u32 {
+///         xs.iter().map(|&x| x as u32).sum()
+///     }
+/// }
+///
+/// # fn main() {
+/// assert_eq!(sum(&[1, 2, 3]), 6);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! multiversion {
+    (
+        #[versions($($feat:expr),+ $(,)*)]
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $argty:ty),* $(,)*) -> $ret:ty
+        $body:block
+    ) => {
+        multiversion!(
+            @impl [pub] [$($feat),+] [$(#[$attr])*]
+            $name [$($arg: $argty),*] $ret $body);
+    };
+    (
+        #[versions($($feat:expr),+ $(,)*)]
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $argty:ty),* $(,)*) $body:block
+    ) => {
+        multiversion!(
+            @impl [pub] [$($feat),+] [$(#[$attr])*]
+            $name [$($arg: $argty),*] () $body);
+    };
+    (
+        #[versions($($feat:expr),+ $(,)*)]
+        $(#[$attr:meta])*
+        fn $name:ident($($arg:ident: $argty:ty),* $(,)*) -> $ret:ty
+        $body:block
+    ) => {
+        multiversion!(
+            @impl [] [$($feat),+] [$(#[$attr])*]
+            $name [$($arg: $argty),*] $ret $body);
+    };
+    (
+        #[versions($($feat:expr),+ $(,)*)]
+        $(#[$attr:meta])*
+        fn $name:ident($($arg:ident: $argty:ty),* $(,)*) $body:block
+    ) => {
+        multiversion!(
+            @impl [] [$($feat),+] [$(#[$attr])*]
+            $name [$($arg: $argty),*] () $body);
+    };
+    (
+        @impl [$($vis:tt)*] [$($feat:expr),+] [$($attr:tt)*]
+        $name:ident [$($arg:ident: $argty:ty),*] $ret:ty $body:block
+    ) => {
+        $($attr)*
+        $($vis)* fn $name($($arg: $argty),*) -> $ret {
+            use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT};
+
+            static DISPATCH: AtomicUsize = ATOMIC_USIZE_INIT;
+
+            fn select() -> usize {
+                let versions = [$({
+                    #[target_feature = $feat]
+                    fn version($($arg: $argty),*) -> $ret $body
+
+                    version as usize
+                }),+];
+                fn fallback($($arg: $argty),*) -> $ret $body
+
+                match $crate::__multiversion::first_supported(&[$($feat),+]) {
+                    Some(i) => versions[i],
+                    None => fallback as usize,
+                }
+            }
+
+            let f = $crate::__multiversion::dispatch(&DISPATCH, select);
+            let f: fn($($argty),*) -> $ret = unsafe {
+                ::std::mem::transmute(f)
+            };
+            f($($arg),*)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+    use __multiversion::{dispatch, first_supported, supports};
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn supports_known_features() {
+        assert!(supports("+sse2"));
+        assert!(supports("+sse, +sse2"));
+        assert!(supports("-avx2"));
+    }
+
+    #[test]
+    fn supports_unknown_features() {
+        // None of these are in the detection table.
+        assert!(!supports("+avx512f"));
+        assert!(!supports("+sse2,+sse4a"));
+        assert!(!supports("+xsave"));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn preference_order() {
+        assert_eq!(first_supported(&["+avx512f", "+sse2", "+sse"]), Some(1));
+        assert_eq!(first_supported(&["+sse", "+sse2"]), Some(0));
+    }
+
+    #[test]
+    fn fallback() {
+        assert_eq!(first_supported(&["+avx512f", "+sse4a"]), None);
+    }
+
+    #[test]
+    fn dispatch_is_cached() {
+        static CALLS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+        fn select() -> usize {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            42
+        }
+
+        let cache = AtomicUsize::new(0);
+        assert_eq!(dispatch(&cache, select), 42);
+        assert_eq!(dispatch(&cache, select), 42);
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    multiversion! {
+        #[versions("+avx512f", "+avx2", "+sse4.2,+popcnt")]
+        fn count_ones(xs: &[u64]) -> u32 {
+            xs.iter().map(|x| x.count_ones()).sum()
+        }
+    }
+
+    #[test]
+    fn multiversion() {
+        let xs = [0, 1, 3, !0];
+        assert_eq!(count_ones(&xs), 67);
+        assert_eq!(count_ones(&xs[..2]), 1);
+    }
+}
@@ -0,0 +1,211 @@
+/// Shuffle the lanes of one or two vectors using a constant list of indices.
+///
+/// `shuffle!(a, b, [i0, i1, ...])` returns a vector whose `k`th lane is the
+/// `ik`th lane of the concatenation of `a` and `b`. That is, if `a` and `b`
+/// have `n` lanes each, then the indices `0..n` refer to the lanes of `a` and
+/// the indices `n..2n` refer to the lanes of `b`.
+///
+/// `shuffle!(a, [i0, i1, ...])` is equivalent to
+/// `shuffle!(a, a, [i0, i1, ...])`.
+///
+/// The number of indices (2, 4, 8, 16, 32 or 64) determines the number of
+/// lanes in the result, which may be different from the number of lanes in
+/// the inputs. The type of the result must be known from context, and its
+/// lanes must have the same type as the lanes of the inputs.
+///
+/// Every index must be a constant expression. Indices that are out of range
+/// for the inputs are rejected at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate stdsimd;
+///
+/// use stdsimd::simd::{u32x4, u32x8};
+///
+/// # fn main() {
+/// let a = u32x4::new(0, 1, 2, 3);
+/// let b = u32x4::new(4, 5, 6, 7);
+///
+/// let r: u32x4 = shuffle!(a, [3, 2, 1, 0]);
+/// assert_eq!(r, u32x4::new(3, 2, 1, 0));
+///
+/// let r: u32x8 = shuffle!(a, b, [0, 4, 1, 5, 2, 6, 3, 7]);
+/// assert_eq!(r, u32x8::new(0, 4, 1, 5, 2, 6, 3, 7));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! shuffle {
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle2(a, b, [
+                $i0, $i1
+            ])
+        }
+    }};
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr, $i2:expr, $i3:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle4(a, b, [
+                $i0, $i1, $i2, $i3
+            ])
+        }
+    }};
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr, $i2:expr, $i3:expr, $i4:expr, $i5:expr,
+        $i6:expr, $i7:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle8(a, b, [
+                $i0, $i1, $i2, $i3, $i4, $i5, $i6, $i7
+            ])
+        }
+    }};
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr, $i2:expr, $i3:expr, $i4:expr, $i5:expr,
+        $i6:expr, $i7:expr, $i8:expr, $i9:expr, $i10:expr, $i11:expr,
+        $i12:expr, $i13:expr, $i14:expr, $i15:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle16(a, b, [
+                $i0, $i1, $i2, $i3, $i4, $i5, $i6, $i7,
+                $i8, $i9, $i10, $i11, $i12, $i13, $i14, $i15
+            ])
+        }
+    }};
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr, $i2:expr, $i3:expr, $i4:expr, $i5:expr,
+        $i6:expr, $i7:expr, $i8:expr, $i9:expr, $i10:expr, $i11:expr,
+        $i12:expr, $i13:expr, $i14:expr, $i15:expr, $i16:expr, $i17:expr,
+        $i18:expr, $i19:expr, $i20:expr, $i21:expr, $i22:expr, $i23:expr,
+        $i24:expr, $i25:expr, $i26:expr, $i27:expr, $i28:expr, $i29:expr,
+        $i30:expr, $i31:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle32(a, b, [
+                $i0, $i1, $i2, $i3, $i4, $i5, $i6, $i7,
+                $i8, $i9, $i10, $i11, $i12, $i13, $i14, $i15,
+                $i16, $i17, $i18, $i19, $i20, $i21, $i22, $i23,
+                $i24, $i25, $i26, $i27, $i28, $i29, $i30, $i31
+            ])
+        }
+    }};
+    ($a:expr, $b:expr, [
+        $i0:expr, $i1:expr, $i2:expr, $i3:expr, $i4:expr, $i5:expr,
+        $i6:expr, $i7:expr, $i8:expr, $i9:expr, $i10:expr, $i11:expr,
+        $i12:expr, $i13:expr, $i14:expr, $i15:expr, $i16:expr, $i17:expr,
+        $i18:expr, $i19:expr, $i20:expr, $i21:expr, $i22:expr, $i23:expr,
+        $i24:expr, $i25:expr, $i26:expr, $i27:expr, $i28:expr, $i29:expr,
+        $i30:expr, $i31:expr, $i32:expr, $i33:expr, $i34:expr, $i35:expr,
+        $i36:expr, $i37:expr, $i38:expr, $i39:expr, $i40:expr, $i41:expr,
+        $i42:expr, $i43:expr, $i44:expr, $i45:expr, $i46:expr, $i47:expr,
+        $i48:expr, $i49:expr, $i50:expr, $i51:expr, $i52:expr, $i53:expr,
+        $i54:expr, $i55:expr, $i56:expr, $i57:expr, $i58:expr, $i59:expr,
+        $i60:expr, $i61:expr, $i62:expr, $i63:expr $(,)*
+    ]) => {{
+        let (a, b) = ($a, $b);
+        unsafe {
+            $crate::__shuffle::simd_shuffle64(a, b, [
+                $i0, $i1, $i2, $i3, $i4, $i5, $i6, $i7,
+                $i8, $i9, $i10, $i11, $i12, $i13, $i14, $i15,
+                $i16, $i17, $i18, $i19, $i20, $i21, $i22, $i23,
+                $i24, $i25, $i26, $i27, $i28, $i29, $i30, $i31,
+                $i32, $i33, $i34, $i35, $i36, $i37, $i38, $i39,
+                $i40, $i41, $i42, $i43, $i44, $i45, $i46, $i47,
+                $i48, $i49, $i50, $i51, $i52, $i53, $i54, $i55,
+                $i56, $i57, $i58, $i59, $i60, $i61, $i62, $i63
+            ])
+        }
+    }};
+    ($a:expr, [$($i:expr),+ $(,)*]) => {{
+        let a = $a;
+        shuffle!(a, a, [$($i),+])
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use v256::*;
+    use v512::*;
+
+    #[test]
+    fn shuffle2() {
+        let a = f64x2::new(0.0, 1.0);
+        let b = f64x2::new(2.0, 3.0);
+        let r: f64x2 = shuffle!(a, b, [3, 0]);
+        assert_eq!(r, f64x2::new(3.0, 0.0));
+        let r: f64x2 = shuffle!(a, [1, 1]);
+        assert_eq!(r, f64x2::splat(1.0));
+    }
+
+    #[test]
+    fn shuffle32_from_u8x16() {
+        let a = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7,
+                           8, 9, 10, 11, 12, 13, 14, 15);
+        let b = u8x16::new(16, 17, 18, 19, 20, 21, 22, 23,
+                           24, 25, 26, 27, 28, 29, 30, 31);
+        let r: u8x32 = shuffle!(a, b, [
+            0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23, 8, 24, 9,
+            25, 10, 26, 11, 27, 12, 28, 13, 29, 14, 30, 15, 31
+        ]);
+        for i in 0..16 {
+            assert_eq!(r.extract(2 * i), i as u8);
+            assert_eq!(r.extract(2 * i + 1), 16 + i as u8);
+        }
+    }
+
+    #[test]
+    fn shuffle32() {
+        let mut xs = [0u8; 64];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let (a, b) = (u8x32::load(&xs, 0), u8x32::load(&xs, 32));
+
+        let r: u8x32 = shuffle!(a, [
+            31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0
+        ]);
+        for i in 0..32 {
+            assert_eq!(r.extract(i), 31 - i as u8);
+        }
+
+        // Lanes 8..40 of the concatenation straddle `a` and `b`.
+        let r: u8x32 = shuffle!(a, b, [
+            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39
+        ]);
+        assert_eq!(r, u8x32::load(&xs, 8));
+    }
+
+    #[test]
+    fn shuffle64() {
+        let mut xs = [0u8; 128];
+        for (i, x) in xs.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let (a, b) = (u8x64::load(&xs, 0), u8x64::load(&xs, 64));
+
+        // The odd lanes of both inputs.
+        let r: u8x64 = shuffle!(a, b, [
+            1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, 31, 33, 35,
+            37, 39, 41, 43, 45, 47, 49, 51, 53, 55, 57, 59, 61, 63, 65, 67,
+            69, 71, 73, 75, 77, 79, 81, 83, 85, 87, 89, 91, 93, 95, 97, 99,
+            101, 103, 105, 107, 109, 111, 113, 115, 117, 119, 121, 123, 125,
+            127
+        ]);
+        for i in 0..64 {
+            assert_eq!(r.extract(i), 2 * i as u8 + 1);
+        }
+    }
+}
@@ -10,6 +10,8 @@ extern "platform-intrinsic" {
     pub fn simd_shuffle4<T, U>(x: T, y: T, idx: [u32; 4]) -> U;
     pub fn simd_shuffle8<T, U>(x: T, y: T, idx: [u32; 8]) -> U;
     pub fn simd_shuffle16<T, U>(x: T, y: T, idx: [u32; 16]) -> U;
+    pub fn simd_shuffle32<T, U>(x: T, y: T, idx: [u32; 32]) -> U;
+    pub fn simd_shuffle64<T, U>(x: T, y: T, idx: [u32; 64]) -> U;
 
     pub fn simd_insert<T, U>(x: T, idx: u32, val: U) -> T;
     pub fn simd_extract<T, U>(x: T, idx: u32) -> U;
@@ -1,34 +1,34 @@
 use simd_llvm::*;
 
 define_ty! { f64x2, f64, f64 }
-define_impl! { f64x2, f64, 2, i64x2, x0, x1 }
+define_impl! { f64x2, f64, 2, m64x2, x0, x1 }
 
 define_ty! { f32x4, f32, f32, f32, f32 }
-define_impl! { f32x4, f32, 4, i32x4, x0, x1, x2, x3 }
+define_impl! { f32x4, f32, 4, m32x4, x0, x1, x2, x3 }
 
 define_ty! { u64x2, u64, u64 }
-define_impl! { u64x2, u64, 2, i64x2, x0, x1 }
+define_impl! { u64x2, u64, 2, m64x2, x0, x1 }
 
 define_ty! { i64x2, i64, i64 }
-define_impl! { i64x2, i64, 2, i64x2, x0, x1 }
+define_impl! { i64x2, i64, 2, m64x2, x0, x1 }
 
 define_ty! { u32x4, u32, u32, u32, u32 }
-define_impl! { u32x4, u32, 4, i32x4, x0, x1, x2, x3 }
+define_impl! { u32x4, u32, 4, m32x4, x0, x1, x2, x3 }
 
 define_ty! { i32x4, i32, i32, i32, i32 }
-define_impl! { i32x4, i32, 4, i32x4, x0, x1, x2, x3 }
+define_impl! { i32x4, i32, 4, m32x4, x0, x1, x2, x3 }
 
 define_ty! { u16x8, u16, u16, u16, u16, u16, u16, u16, u16 }
-define_impl! { u16x8, u16, 8, i16x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { u16x8, u16, 8, m16x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! { i16x8, i16, i16, i16, i16, i16, i16, i16, i16 }
-define_impl! { i16x8, i16, 8, i16x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { i16x8, i16, 8, m16x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! {
     u8x16, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
 }
 define_impl! {
-    u8x16, u8, 16, i8x16,
+    u8x16, u8, 16, m8x16,
     x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
 }
 
@@ -36,10 +36,47 @@ define_ty! {
     i8x16, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
 }
 define_impl! {
-    i8x16, i8, 16, i8x16,
+    i8x16, i8, 16, m8x16,
     x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
 }
 
+define_ty_doc! {
+    m64x2, i64, i64 |
+    /// A 128-bit boolean mask with 2 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! { m64x2, i64, 2, i64x2, x0, x1 }
+
+define_ty_doc! {
+    m32x4, i32, i32, i32, i32 |
+    /// A 128-bit boolean mask with 4 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! { m32x4, i32, 4, i32x4, x0, x1, x2, x3 }
+
+define_ty_doc! {
+    m16x8,
+    i16, i16, i16, i16, i16, i16, i16, i16 |
+    /// A 128-bit boolean mask with 8 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m16x8, i16, 8, i16x8,
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+
+define_ty_doc! {
+    m8x16,
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8 |
+    /// A 128-bit boolean mask with 16 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m8x16, i8, 16, i8x16,
+    x0, x1, x2, x3, x4, x5, x6, x7,
+    x8, x9, x10, x11, x12, x13, x14, x15
+}
+
 define_from!(u64x2, i64x2, u32x4, i32x4, u16x8, i16x8, u8x16, i8x16);
 define_from!(i64x2, u64x2, u32x4, i32x4, u16x8, i16x8, u8x16, i8x16);
 define_from!(u32x4, u64x2, i64x2, i32x4, u16x8, i16x8, u8x16, i8x16);
@@ -48,10 +85,30 @@ define_from!(u16x8, u64x2, i64x2, u32x4, i32x4, i16x8, u8x16, i8x16);
 define_from!(i16x8, u64x2, i64x2, u32x4, i32x4, u16x8, u8x16, i8x16);
 define_from!(u8x16, u64x2, i64x2, u32x4, i32x4, u16x8, i16x8, i8x16);
 define_from!(i8x16, u64x2, i64x2, u32x4, i32x4, u16x8, i16x8, u8x16);
+define_from!(i64x2, m64x2);
+define_from!(u64x2, m64x2);
+define_from!(i32x4, m32x4);
+define_from!(u32x4, m32x4);
+define_from!(i16x8, m16x8);
+define_from!(u16x8, m16x8);
+define_from!(i8x16, m8x16);
+define_from!(u8x16, m8x16);
 
 define_common_ops!(
     f64x2, f32x4, u64x2, i64x2, u32x4, i32x4, u16x8, i16x8, u8x16, i8x16);
-define_float_ops!(f64x2, f32x4);
+define_float_ops!((f64x2, f64), (f32x4, f32));
+define_float_math! {
+    f64x2, i64x2,
+    "llvm.sqrt.v2f64", "llvm.fabs.v2f64", "llvm.fma.v2f64",
+    "llvm.floor.v2f64", "llvm.ceil.v2f64", "llvm.round.v2f64",
+    "llvm.trunc.v2f64", "llvm.copysign.v2f64"
+}
+define_float_math! {
+    f32x4, i32x4,
+    "llvm.sqrt.v4f32", "llvm.fabs.v4f32", "llvm.fma.v4f32",
+    "llvm.floor.v4f32", "llvm.ceil.v4f32", "llvm.round.v4f32",
+    "llvm.trunc.v4f32", "llvm.copysign.v4f32"
+}
 define_integer_ops!(
     (u64x2, u64),
     (i64x2, i64),
@@ -80,3 +137,261 @@ define_casts!(
     (i16x8, u16x8, as_u16x8),
     (u8x16, i8x16, as_i8x16),
     (i8x16, u8x16, as_u8x16));
+define_gather_scatter! {
+    f64x2, f64, 2, u32x2, m64x2, _mm_mask_i32gather_pd
+}
+define_gather_scatter! {
+    u64x2, u64, 2, u32x2, m64x2, _mm_mask_i32gather_epi64
+}
+define_gather_scatter! {
+    i64x2, i64, 2, u32x2, m64x2, _mm_mask_i32gather_epi64
+}
+define_gather_scatter! {
+    f32x4, f32, 4, u32x4, m32x4, _mm_mask_i32gather_ps
+}
+define_gather_scatter! {
+    u32x4, u32, 4, u32x4, m32x4, _mm_mask_i32gather_epi32
+}
+define_gather_scatter! {
+    i32x4, i32, 4, u32x4, m32x4, _mm_mask_i32gather_epi32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32, f64};
+
+    use v128::*;
+    use v64::u32x2;
+
+    macro_rules! check_mask {
+        ($mask:ident, $bits:ident, $n:expr) => {{
+            let all = $mask::splat(true);
+            let none = $mask::splat(false);
+            let mixed = none.replace(0, true).replace($n - 1, true);
+            assert!(all.any() && all.all() && !all.none());
+            assert!(!none.any() && !none.all() && none.none());
+            assert!(mixed.any() && !mixed.all() && !mixed.none());
+            assert_eq!(!all, none);
+            assert_eq!(mixed & all, mixed);
+            assert_eq!(mixed | none, mixed);
+            assert_eq!(mixed ^ mixed, none);
+
+            assert_eq!($mask::from_bits($bits::splat(-1)), Some(all));
+            assert_eq!($mask::from_bits($bits::splat(0)), Some(none));
+            assert_eq!($mask::from_bits($bits::from(mixed)), Some(mixed));
+            assert_eq!($mask::from_bits($bits::splat(0).replace(1, 1)), None);
+            assert_eq!($mask::from_bits($bits::splat(-1).replace(0, 2)), None);
+        }}
+    }
+
+    #[test]
+    fn masks() {
+        check_mask!(m8x16, i8x16, 16);
+        check_mask!(m16x8, i16x8, 8);
+        check_mask!(m32x4, i32x4, 4);
+        check_mask!(m64x2, i64x2, 2);
+    }
+
+    #[test]
+    fn comparison_masks() {
+        let a = f32x4::new(1.0, 2.0, f32::NAN, 4.0);
+        let b = f32x4::new(1.0, 3.0, f32::NAN, 0.0);
+        assert_eq!(a.eq(b), m32x4::new(true, false, false, false));
+        assert_eq!(a.lt(b), m32x4::new(false, true, false, false));
+        assert!(a.ne(b).any());
+        assert!(u8x16::splat(7).eq(u8x16::splat(7)).all());
+    }
+
+    #[test]
+    fn float_reductions() {
+        let v = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.sum(), 10.0);
+        assert_eq!(v.product(), 24.0);
+        assert_eq!(v.min_element(), 1.0);
+        assert_eq!(v.max_element(), 4.0);
+
+        // Lanes are added in order: pairwise, this would sum to zero.
+        let v = f32x4::new(1e8, 1.0, -1e8, 1.0);
+        assert_eq!(v.sum(), 1.0);
+
+        let v = f64x2::new(f64::NAN, 2.0);
+        assert_eq!(v.min_element(), 2.0);
+        assert_eq!(v.max_element(), 2.0);
+        assert!(f64x2::splat(f64::NAN).min_element().is_nan());
+    }
+
+    #[test]
+    fn integer_reductions() {
+        let v = i32x4::new(-3, 7, 2, -8);
+        assert_eq!(v.sum(), -2);
+        assert_eq!(v.product(), 336);
+        assert_eq!(v.min_element(), -8);
+        assert_eq!(v.max_element(), 7);
+
+        // Sums and products wrap.
+        assert_eq!(u8x16::splat(200).sum(), 128);
+        assert_eq!(i8x16::splat(2).product(), 0);
+        assert_eq!(u64x2::splat(!0).sum(), !0 - 1);
+
+        let v = u32x4::new(0b1110, 0b0111, 0b1111, 0b0110);
+        assert_eq!(v.and(), 0b0110);
+        assert_eq!(v.or(), 0b1111);
+        assert_eq!(v.xor(), 0b0000);
+        assert_eq!(i16x8::new(1, 2, 4, 8, 16, 32, 64, -128).or(), -1);
+    }
+
+    #[test]
+    fn float_math() {
+        let v = f32x4::new(-2.5, -0.5, 0.5, 2.5);
+        assert_eq!(v.abs(), f32x4::new(2.5, 0.5, 0.5, 2.5));
+        assert_eq!(v.floor(), f32x4::new(-3.0, -1.0, 0.0, 2.0));
+        assert_eq!(v.ceil(), f32x4::new(-2.0, -0.0, 1.0, 3.0));
+        assert_eq!(v.round(), f32x4::new(-3.0, -1.0, 1.0, 3.0));
+        assert_eq!(v.trunc(), f32x4::new(-2.0, -0.0, 0.0, 2.0));
+        assert_eq!(v.recip(), f32x4::new(-0.4, -2.0, 2.0, 0.4));
+
+        let v = f64x2::new(16.0, 2.0);
+        assert_eq!(v.sqrt(), f64x2::new(4.0, f64::consts::SQRT_2));
+        assert!(f64x2::splat(-1.0).sqrt().extract(0).is_nan());
+        assert_eq!(f64x2::splat(-0.0).abs().extract(0).to_bits(), 0);
+
+        // Fused: (1 + e) * (1 - e) - 1 is -e^2, which a separate multiply
+        // would round away.
+        let e = 1.0 / (1u64 << 30) as f64;
+        let r = f64x2::splat(1.0 + e)
+            .mul_add(f64x2::splat(1.0 - e), f64x2::splat(-1.0));
+        assert_eq!(r, f64x2::splat(-e * e));
+
+        let v = f32x4::new(1.0, -2.0, 3.0, -4.0);
+        let sign = f32x4::new(-0.0, 0.0, -f32::INFINITY, 1.0);
+        assert_eq!(v.copysign(sign), f32x4::new(-1.0, 2.0, -3.0, 4.0));
+
+        let v = f32x4::new(0.0, -0.0, f32::NEG_INFINITY, 7.0);
+        assert_eq!(v.signum(), f32x4::new(1.0, -1.0, -1.0, 1.0));
+        assert!(f32x4::splat(f32::NAN).signum().extract(0).is_nan());
+    }
+
+    #[test]
+    fn load_partial() {
+        let xs = [1, 2, 3, 4, 5, 6];
+        let d = i32x4::splat(-1);
+
+        // A full vector, ending exactly at the end of the slice.
+        assert_eq!(i32x4::load_or(&xs, 0, d), i32x4::new(1, 2, 3, 4));
+        assert_eq!(i32x4::load_or(&xs, 2, d), i32x4::new(3, 4, 5, 6));
+        // A short tail, and no lanes at all at the end of the slice.
+        assert_eq!(i32x4::load_or(&xs, 4, d), i32x4::new(5, 6, -1, -1));
+        assert_eq!(i32x4::load_or(&xs, 6, d), d);
+        assert_eq!(i32x4::load_or(&[], 0, d), d);
+
+        assert_eq!(i32x4::load_partial(&xs, 2), i32x4::new(3, 4, 5, 6));
+        assert_eq!(i32x4::load_partial(&xs, 5), i32x4::new(6, 0, 0, 0));
+        assert_eq!(i32x4::load_partial(&xs, 6), i32x4::splat(0));
+
+        let ys = [0.5, 1.5, 2.5];
+        let r = f64x2::load_partial(&ys, 2);
+        assert_eq!(r, f64x2::new(2.5, 0.0));
+        assert_eq!(f64x2::load_partial(&ys, 1), f64x2::new(1.5, 2.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn load_partial_out_of_bounds() {
+        i32x4::load_partial(&[1, 2, 3], 4);
+    }
+
+    #[test]
+    fn store_partial() {
+        let v = i32x4::new(1, 2, 3, 4);
+
+        // A full vector, ending exactly at the end of the slice.
+        let mut xs = [0; 6];
+        v.store_partial(&mut xs, 2);
+        assert_eq!(xs, [0, 0, 1, 2, 3, 4]);
+
+        // A short tail leaves everything before `offset` untouched.
+        let mut xs = [0; 6];
+        v.store_partial(&mut xs, 4);
+        assert_eq!(xs, [0, 0, 0, 0, 1, 2]);
+
+        // No lanes fit at the end of the slice.
+        let mut xs = [0; 6];
+        v.store_partial(&mut xs, 6);
+        assert_eq!(xs, [0; 6]);
+        v.store_partial(&mut [], 0);
+
+        // A full vector in the middle leaves the elements after it alone.
+        let mut xs = [9; 8];
+        v.store_partial(&mut xs, 1);
+        assert_eq!(xs, [9, 1, 2, 3, 4, 9, 9, 9]);
+
+        // Round trip through a tail.
+        let mut ys = [0u8; 20];
+        u8x16::splat(7).store_partial(&mut ys, 10);
+        assert_eq!(u8x16::load_partial(&ys, 10), u8x16::new(
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 0, 0));
+        assert_eq!(&ys[..10], &[0; 10]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn store_partial_out_of_bounds() {
+        i32x4::splat(1).store_partial(&mut [0; 3], 4);
+    }
+
+    #[test]
+    fn gather() {
+        let data: Vec<f32> = (0..100).map(|i| i as f32 * 0.5).collect();
+        let idx = u32x4::new(99, 0, 42, 7);
+        let r = f32x4::gather(&data, idx);
+        assert_eq!(r, f32x4::new(49.5, 0.0, 21.0, 3.5));
+        let mask = m32x4::new(true, false, true, false);
+        let r = f32x4::gather_masked(
+            &data, idx.replace(1, !0), mask, f32x4::splat(-1.0));
+        assert_eq!(r, f32x4::new(49.5, -1.0, 21.0, -1.0));
+
+        let data: Vec<f64> = (0..100).map(|i| i as f64 * 0.5).collect();
+        let r = f64x2::gather(&data, u32x2::new(3, 98));
+        assert_eq!(r, f64x2::new(1.5, 49.0));
+        let mask = m64x2::new(false, true);
+        let r = f64x2::gather_masked(
+            &data, u32x2::new(!0, 98), mask, f64x2::splat(-1.0));
+        assert_eq!(r, f64x2::new(-1.0, 49.0));
+
+        let data: Vec<i64> = (0..10).map(|i| -i).collect();
+        let r = i64x2::gather(&data, u32x2::new(9, 0));
+        assert_eq!(r, i64x2::new(-9, 0));
+        let data: Vec<u64> = (0..10).map(|i| i << 40).collect();
+        let r = u64x2::gather(&data, u32x2::new(1, 1));
+        assert_eq!(r, u64x2::splat(1 << 40));
+    }
+
+    #[test]
+    fn select() {
+        let a = i32x4::new(1, 2, 3, 4);
+        let b = i32x4::new(-1, -2, -3, -4);
+        let mask = m32x4::new(true, false, false, true);
+        assert_eq!(i32x4::select(mask, a, b), i32x4::new(1, -2, -3, 4));
+        assert_eq!(i32x4::select(m32x4::splat(true), a, b), a);
+        assert_eq!(i32x4::select(m32x4::splat(false), a, b), b);
+
+        // Branch-free clamping, keeping the bits of `-0.0` and `NaN`.
+        let x = f32x4::new(-5.0, -0.0, 7.0, f32::NAN);
+        let lo = f32x4::splat(-1.0);
+        let x = f32x4::select(x.lt(lo), lo, x);
+        let r = f32x4::select(x.gt(f32x4::splat(1.0)), f32x4::splat(1.0), x);
+        assert_eq!(r.extract(0), -1.0);
+        assert_eq!(r.extract(1).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(r.extract(2), 1.0);
+        assert!(r.extract(3).is_nan());
+
+        let a = u8x16::splat(0xAA);
+        let lanes = u8x16::new(0, 1, 2, 3, 4, 5, 6, 7,
+                               8, 9, 10, 11, 12, 13, 14, 15);
+        let mask = lanes.lt(u8x16::splat(8));
+        let r = u8x16::select(mask, a, u8x16::splat(0x55));
+        assert_eq!(r.extract(7), 0xAA);
+        assert_eq!(r.extract(8), 0x55);
+        assert_eq!(r.sum(), (8 * 0xAA + 8 * 0x55) as u8);
+    }
+}
@@ -1,22 +1,22 @@
 use simd_llvm::*;
 
 define_ty! { f64x4, f64, f64, f64, f64 }
-define_impl! { f64x4, f64, 4, i64x4, x0, x1, x2, x3 }
+define_impl! { f64x4, f64, 4, m64x4, x0, x1, x2, x3 }
 
 define_ty! { f32x8, f32, f32, f32, f32, f32, f32, f32, f32 }
-define_impl! { f32x8, f32, 8, i32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { f32x8, f32, 8, m32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! { u64x4, u64, u64, u64, u64 }
-define_impl! { u64x4, u64, 4, i64x4, x0, x1, x2, x3 }
+define_impl! { u64x4, u64, 4, m64x4, x0, x1, x2, x3 }
 
 define_ty! { i64x4, i64, i64, i64, i64 }
-define_impl! { i64x4, i64, 4, i64x4, x0, x1, x2, x3 }
+define_impl! { i64x4, i64, 4, m64x4, x0, x1, x2, x3 }
 
 define_ty! { u32x8, u32, u32, u32, u32, u32, u32, u32, u32 }
-define_impl! { u32x8, u32, 8, i32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { u32x8, u32, 8, m32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! { i32x8, i32, i32, i32, i32, i32, i32, i32, i32 }
-define_impl! { i32x8, i32, 8, i32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { i32x8, i32, 8, m32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! {
     u16x16,
@@ -24,7 +24,7 @@ define_ty! {
     u16, u16, u16, u16, u16, u16, u16, u16
 }
 define_impl! {
-    u16x16, u16, 16, i16x16,
+    u16x16, u16, 16, m16x16,
     x0, x1, x2, x3, x4, x5, x6, x7,
     x8, x9, x10, x11, x12, x13, x14, x15
 }
@@ -35,7 +35,7 @@ define_ty! {
     i16, i16, i16, i16, i16, i16, i16, i16
 }
 define_impl! {
-    i16x16, i16, 16, i16x16,
+    i16x16, i16, 16, m16x16,
     x0, x1, x2, x3, x4, x5, x6, x7,
     x8, x9, x10, x11, x12, x13, x14, x15
 }
@@ -46,7 +46,7 @@ define_ty! {
     u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8
 }
 define_impl! {
-    u8x32, u8, 32, i8x32,
+    u8x32, u8, 32, m8x32,
     x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15,
     x16, x17, x18, x19, x20, x21, x22, x23,
     x24, x25, x26, x27, x28, x29, x30, x31
@@ -58,12 +58,58 @@ define_ty! {
     i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8
 }
 define_impl! {
-    i8x32, i8, 32, i8x32,
+    i8x32, i8, 32, m8x32,
     x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15,
     x16, x17, x18, x19, x20, x21, x22, x23,
     x24, x25, x26, x27, x28, x29, x30, x31
 }
 
+define_ty_doc! {
+    m64x4, i64, i64, i64, i64 |
+    /// A 256-bit boolean mask with 4 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! { m64x4, i64, 4, i64x4, x0, x1, x2, x3 }
+
+define_ty_doc! {
+    m32x8,
+    i32, i32, i32, i32, i32, i32, i32, i32 |
+    /// A 256-bit boolean mask with 8 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m32x8, i32, 8, i32x8,
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
+
+define_ty_doc! {
+    m16x16,
+    i16, i16, i16, i16, i16, i16, i16, i16,
+    i16, i16, i16, i16, i16, i16, i16, i16 |
+    /// A 256-bit boolean mask with 16 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m16x16, i16, 16, i16x16,
+    x0, x1, x2, x3, x4, x5, x6, x7,
+    x8, x9, x10, x11, x12, x13, x14, x15
+}
+
+define_ty_doc! {
+    m8x32,
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8,
+    i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8, i8 |
+    /// A 256-bit boolean mask with 32 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m8x32, i8, 32, i8x32,
+    x0, x1, x2, x3, x4, x5, x6, x7,
+    x8, x9, x10, x11, x12, x13, x14, x15,
+    x16, x17, x18, x19, x20, x21, x22, x23,
+    x24, x25, x26, x27, x28, x29, x30, x31
+}
+
 define_from!(u64x4, i64x4, u32x8, i32x8, u16x16, i16x16, u8x32, i8x32);
 define_from!(i64x4, u64x4, u32x8, i32x8, u16x16, i16x16, u8x32, i8x32);
 define_from!(u32x8, u64x4, i64x4, i32x8, u16x16, i16x16, u8x32, i8x32);
@@ -72,10 +118,30 @@ define_from!(u16x16, u64x4, i64x4, u32x8, i32x8, i16x16, u8x32, i8x32);
 define_from!(i16x16, u64x4, i64x4, u32x8, i32x8, u16x16, u8x32, i8x32);
 define_from!(u8x32, u64x4, i64x4, u32x8, i32x8, u16x16, i16x16, i8x32);
 define_from!(i8x32, u64x4, i64x4, u32x8, i32x8, u16x16, i16x16, u8x32);
+define_from!(i64x4, m64x4);
+define_from!(u64x4, m64x4);
+define_from!(i32x8, m32x8);
+define_from!(u32x8, m32x8);
+define_from!(i16x16, m16x16);
+define_from!(u16x16, m16x16);
+define_from!(i8x32, m8x32);
+define_from!(u8x32, m8x32);
 
 define_common_ops!(
     f64x4, f32x8, u64x4, i64x4, u32x8, i32x8, u16x16, i16x16, u8x32, i8x32);
-define_float_ops!(f64x4, f32x8);
+define_float_ops!((f64x4, f64), (f32x8, f32));
+define_float_math! {
+    f64x4, i64x4,
+    "llvm.sqrt.v4f64", "llvm.fabs.v4f64", "llvm.fma.v4f64",
+    "llvm.floor.v4f64", "llvm.ceil.v4f64", "llvm.round.v4f64",
+    "llvm.trunc.v4f64", "llvm.copysign.v4f64"
+}
+define_float_math! {
+    f32x8, i32x8,
+    "llvm.sqrt.v8f32", "llvm.fabs.v8f32", "llvm.fma.v8f32",
+    "llvm.floor.v8f32", "llvm.ceil.v8f32", "llvm.round.v8f32",
+    "llvm.trunc.v8f32", "llvm.copysign.v8f32"
+}
 define_integer_ops!(
     (u64x4, u64),
     (i64x4, i64),
@@ -103,3 +169,94 @@ define_casts!(
     (i16x16, u16x16, as_u16x16),
     (u8x32, i8x32, as_i8x32),
     (i8x32, u8x32, as_u8x32));
+define_gather_scatter! {
+    f64x4, f64, 4, u32x4, m64x4, _mm256_mask_i32gather_pd
+}
+define_gather_scatter! {
+    u64x4, u64, 4, u32x4, m64x4, _mm256_mask_i32gather_epi64
+}
+define_gather_scatter! {
+    i64x4, i64, 4, u32x4, m64x4, _mm256_mask_i32gather_epi64
+}
+define_gather_scatter! {
+    f32x8, f32, 8, u32x8, m32x8, _mm256_mask_i32gather_ps
+}
+define_gather_scatter! {
+    u32x8, u32, 8, u32x8, m32x8, _mm256_mask_i32gather_epi32
+}
+define_gather_scatter! {
+    i32x8, i32, 8, u32x8, m32x8, _mm256_mask_i32gather_epi32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::u32;
+
+    use v256::*;
+
+    // Compares `gather`, which takes the AVX2 path when compiled for it,
+    // with loads of the same elements one at a time.
+    macro_rules! check_gather {
+        ($ty:ident, $elem:ident, $idx:ident, $mask:ident, $n:expr) => {{
+            let data: Vec<$elem> =
+                (0..1000).map(|i| (i * 7 % 1000) as $elem).collect();
+            let mut idx = $idx::splat(0);
+            let mut mask = $mask::splat(false);
+            for i in 0..$n {
+                idx = idx.replace(i, (i * 131 + 17) % 1000);
+                mask = mask.replace(i, i % 3 != 1);
+            }
+            let default = $ty::splat(5 as $elem);
+
+            let all = $ty::gather(&data, idx);
+            let some = $ty::gather_masked(&data, idx, mask, default);
+            for i in 0..$n {
+                let e = data[idx.extract(i) as usize];
+                assert_eq!(all.extract(i), e);
+                let e = if mask.extract(i) { e } else { 5 as $elem };
+                assert_eq!(some.extract(i), e);
+            }
+
+            // The indices of lanes that are not set are never read.
+            let far = idx.replace(1, u32::MAX);
+            let mask = mask.replace(1, false);
+            let r = $ty::gather_masked(&data, far, mask, default);
+            assert_eq!(r, some);
+        }}
+    }
+
+    #[test]
+    fn gather() {
+        check_gather!(f32x8, f32, u32x8, m32x8, 8);
+        check_gather!(u32x8, u32, u32x8, m32x8, 8);
+        check_gather!(i32x8, i32, u32x8, m32x8, 8);
+        check_gather!(f64x4, f64, u32x4, m64x4, 4);
+        check_gather!(u64x4, u64, u32x4, m64x4, 4);
+        check_gather!(i64x4, i64, u32x4, m64x4, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "gather index out of bounds")]
+    fn gather_out_of_bounds() {
+        let data = [1.0f64; 10];
+        f64x4::gather(&data, u32x4::new(0, 1, 10, 2));
+    }
+
+    #[test]
+    fn scatter() {
+        let mut data = [0i32; 10];
+        let v = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let idx = u32x8::new(9, 0, 3, 3, 5, u32::MAX, 7, 1);
+        let mask = m32x8::new(true, true, true, true, true, false, true, true);
+        v.scatter_masked(&mut data, idx, mask);
+        // Of the lanes with index 3, the highest wins.
+        assert_eq!(data, [2, 8, 0, 4, 0, 5, 0, 7, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "scatter index out of bounds")]
+    fn scatter_out_of_bounds() {
+        let mut data = [0u64; 4];
+        u64x4::splat(1).scatter(&mut data, u32x4::new(0, 1, 2, 4));
+    }
+}
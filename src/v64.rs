@@ -4,28 +4,53 @@ define_ty_doc! {
     f32x2, f32, f32 |
     /// A 64-bit vector with 2 `f32` lanes.
 }
-define_impl! { f32x2, f32, 2, i32x2, x0, x1 }
+define_impl! { f32x2, f32, 2, m32x2, x0, x1 }
 
 define_ty_doc! {
     u32x2, u32, u32 |
     /// A 64-bit vector with 2 `u32` lanes.
 }
-define_impl! { u32x2, u32, 2, i32x2, x0, x1 }
+define_impl! { u32x2, u32, 2, m32x2, x0, x1 }
 
 define_ty! { i32x2, i32, i32 }
-define_impl! { i32x2, i32, 2, i32x2, x0, x1 }
+define_impl! { i32x2, i32, 2, m32x2, x0, x1 }
 
 define_ty! { u16x4, u16, u16, u16, u16 }
-define_impl! { u16x4, u16, 4, i16x4, x0, x1, x2, x3 }
+define_impl! { u16x4, u16, 4, m16x4, x0, x1, x2, x3 }
 
 define_ty! { i16x4, i16, i16, i16, i16 }
-define_impl! { i16x4, i16, 4, i16x4, x0, x1, x2, x3 }
+define_impl! { i16x4, i16, 4, m16x4, x0, x1, x2, x3 }
 
 define_ty! { u8x8, u8, u8, u8, u8, u8, u8, u8, u8 }
-define_impl! { u8x8, u8, 8, i8x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { u8x8, u8, 8, m8x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
 define_ty! { i8x8, i8, i8, i8, i8, i8, i8, i8, i8 }
-define_impl! { i8x8, i8, 8, i8x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+define_impl! { i8x8, i8, 8, m8x8, x0, x1, x2, x3, x4, x5, x6, x7 }
+
+define_ty_doc! {
+    m32x2, i32, i32 |
+    /// A 64-bit boolean mask with 2 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! { m32x2, i32, 2, i32x2, x0, x1 }
+
+define_ty_doc! {
+    m16x4, i16, i16, i16, i16 |
+    /// A 64-bit boolean mask with 4 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! { m16x4, i16, 4, i16x4, x0, x1, x2, x3 }
+
+define_ty_doc! {
+    m8x8,
+    i8, i8, i8, i8, i8, i8, i8, i8 |
+    /// A 64-bit boolean mask with 8 lanes, each of which is either all
+    /// zeros or all ones.
+}
+define_mask! {
+    m8x8, i8, 8, i8x8,
+    x0, x1, x2, x3, x4, x5, x6, x7
+}
 
 define_from!(u32x2, i32x2, u16x4, i16x4, u8x8, i8x8);
 define_from!(i32x2, u32x2, u16x4, i16x4, u8x8, i8x8);
@@ -33,9 +58,21 @@ define_from!(u16x4, u32x2, i32x2, i16x4, u8x8, i8x8);
 define_from!(i16x4, u32x2, i32x2, u16x4, u8x8, i8x8);
 define_from!(u8x8, u32x2, i32x2, u16x4, i16x4, i8x8);
 define_from!(i8x8, u32x2, i32x2, u16x4, i16x4, u8x8);
+define_from!(i32x2, m32x2);
+define_from!(u32x2, m32x2);
+define_from!(i16x4, m16x4);
+define_from!(u16x4, m16x4);
+define_from!(i8x8, m8x8);
+define_from!(u8x8, m8x8);
 
 define_common_ops!(f32x2, u32x2, i32x2, u16x4, i16x4, u8x8, i8x8);
-define_float_ops!(f32x2);
+define_float_ops!((f32x2, f32));
+define_float_math! {
+    f32x2, i32x2,
+    "llvm.sqrt.v2f32", "llvm.fabs.v2f32", "llvm.fma.v2f32",
+    "llvm.floor.v2f32", "llvm.ceil.v2f32", "llvm.round.v2f32",
+    "llvm.trunc.v2f32", "llvm.copysign.v2f32"
+}
 define_integer_ops!(
     (u32x2, u32),
     (i32x2, i32),
@@ -55,3 +92,6 @@ define_casts!(
     (i16x4, u16x4, as_u16x4),
     (u8x8, i8x8, as_i8x8),
     (i8x8, u8x8, as_u8x8));
+define_gather_scatter! { f32x2, f32, 2, u32x2, m32x2 }
+define_gather_scatter! { u32x2, u32, 2, u32x2, m32x2 }
+define_gather_scatter! { i32x2, i32, 2, u32x2, m32x2 }
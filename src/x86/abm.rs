@@ -0,0 +1,76 @@
+//! Advanced Bit Manipulation (ABM) instructions: `LZCNT` and `POPCNT`.
+//!
+//! Each has its own CPUID feature bit, so they are detected separately.
+
+/// Count the leading zero bits of `x`.
+///
+/// Returns 32 if `x` is zero.
+#[inline(always)]
+#[target_feature = "+lzcnt"]
+pub fn _lzcnt_u32(x: u32) -> u32 {
+    x.leading_zeros()
+}
+
+/// Count the leading zero bits of `x`.
+///
+/// Returns 64 if `x` is zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+lzcnt"]
+pub fn _lzcnt_u64(x: u64) -> u64 {
+    x.leading_zeros() as u64
+}
+
+/// Count the bits of `x` that are set.
+#[inline(always)]
+#[target_feature = "+popcnt"]
+pub fn _popcnt32(x: i32) -> i32 {
+    x.count_ones() as i32
+}
+
+/// Count the bits of `x` that are set.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+popcnt"]
+pub fn _popcnt64(x: i64) -> i32 {
+    x.count_ones() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use x86::abm;
+
+    #[test]
+    #[target_feature = "+lzcnt"]
+    fn _lzcnt_u32() {
+        assert_eq!(abm::_lzcnt_u32(0b0101_1010), 25);
+        assert_eq!(abm::_lzcnt_u32(0x8000_0000), 0);
+        assert_eq!(abm::_lzcnt_u32(0), 32);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+lzcnt"]
+    fn _lzcnt_u64() {
+        assert_eq!(abm::_lzcnt_u64(0b0101_1010), 57);
+        assert_eq!(abm::_lzcnt_u64(1 << 63), 0);
+        assert_eq!(abm::_lzcnt_u64(0), 64);
+    }
+
+    #[test]
+    #[target_feature = "+popcnt"]
+    fn _popcnt32() {
+        assert_eq!(abm::_popcnt32(0b0101_1010), 4);
+        assert_eq!(abm::_popcnt32(0), 0);
+        assert_eq!(abm::_popcnt32(-1), 32);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+popcnt"]
+    fn _popcnt64() {
+        assert_eq!(abm::_popcnt64(0b0101_1010), 4);
+        assert_eq!(abm::_popcnt64(0), 0);
+        assert_eq!(abm::_popcnt64(-1), 64);
+    }
+}
@@ -0,0 +1,158 @@
+//! AES New Instructions (AES-NI).
+//!
+//! The state and round keys are 128-bit values holding the bytes of the AES
+//! state in column order, i.e. the order in which FIPS-197 lists them.
+
+use v128::*;
+use x86::__m128i;
+
+/// Perform one round of an AES decryption flow on data (state) in `a`.
+///
+/// The round applies `InvShiftRows`, `InvSubBytes` and `InvMixColumns` to
+/// `a`, then xors the result with `round_key`.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aesdec_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    unsafe { __m128i::from(aesdec(a.into(), round_key.into())) }
+}
+
+/// Perform the last round of an AES decryption flow on data (state) in `a`.
+///
+/// The round applies `InvShiftRows` and `InvSubBytes` to `a`, then xors the
+/// result with `round_key`.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aesdeclast_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    unsafe { __m128i::from(aesdeclast(a.into(), round_key.into())) }
+}
+
+/// Perform one round of an AES encryption flow on data (state) in `a`.
+///
+/// The round applies `ShiftRows`, `SubBytes` and `MixColumns` to `a`, then
+/// xors the result with `round_key`.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aesenc_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    unsafe { __m128i::from(aesenc(a.into(), round_key.into())) }
+}
+
+/// Perform the last round of an AES encryption flow on data (state) in `a`.
+///
+/// The round applies `ShiftRows` and `SubBytes` to `a`, then xors the result
+/// with `round_key`.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aesenclast_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    unsafe { __m128i::from(aesenclast(a.into(), round_key.into())) }
+}
+
+/// Perform the `InvMixColumns` transformation on `a`.
+///
+/// This turns an encryption round key into the round key used by
+/// `_mm_aesdec_si128` in the equivalent inverse cipher.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aesimc_si128(a: __m128i) -> __m128i {
+    unsafe { __m128i::from(aesimc(a.into())) }
+}
+
+/// Assist in expanding the AES cipher key.
+///
+/// Apply `SubWord` to the 32-bit words 1 and 3 of `a`, and store them in
+/// words 0 and 2 of the result. Words 1 and 3 of the result are the same
+/// values rotated right by 8 bits and xored with the round constant `imm8`.
+#[inline(always)]
+#[target_feature = "+aes"]
+pub fn _mm_aeskeygenassist_si128(a: __m128i, imm8: i32) -> __m128i {
+    let a = i64x2::from(a);
+    macro_rules! call {
+        ($imm8:expr) => {
+            __m128i::from(unsafe { aeskeygenassist(a, $imm8) })
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.aesni.aesdec"]
+    fn aesdec(a: i64x2, round_key: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.aesni.aesdeclast"]
+    fn aesdeclast(a: i64x2, round_key: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.aesni.aesenc"]
+    fn aesenc(a: i64x2, round_key: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.aesni.aesenclast"]
+    fn aesenclast(a: i64x2, round_key: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.aesni.aesimc"]
+    fn aesimc(a: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.aesni.aeskeygenassist"]
+    fn aeskeygenassist(a: i64x2, imm8: u8) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use x86::{__m128i, aes};
+
+    // The state and round key of the tests, as two little-endian halves:
+    // the bytes 00 11 .. ff and 00 01 .. 0f.
+    fn state() -> __m128i {
+        __m128i::from(u64x2::new(0x7766554433221100, 0xffeeddccbbaa9988))
+    }
+
+    fn round_key() -> __m128i {
+        __m128i::from(u64x2::new(0x0706050403020100, 0x0f0e0d0c0b0a0908))
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aesdec_si128() {
+        let r = aes::_mm_aesdec_si128(state(), round_key());
+        let e = u64x2::new(0x6f3f7426c202e6dd, 0xbf4ff46fa83c0700);
+        assert_eq!(r, __m128i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aesdeclast_si128() {
+        let r = aes::_mm_aesdeclast_si128(state(), round_key());
+        let e = u64x2::new(0xf99fe6820160c852, 0x69ddf42b769ee49f);
+        assert_eq!(r, __m128i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aesenc_si128() {
+        let r = aes::_mm_aesenc_si128(state(), round_key());
+        let e = u64x2::new(0x71fd62f0dae47863, 0xac84e6deff360fa5);
+        assert_eq!(r, __m128i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aesenclast_si128() {
+        let r = aes::_mm_aesenclast_si128(state(), round_key());
+        let e = u64x2::new(0xc42eeb1f15aefd63, 0xe53d8f47fe99c8cc);
+        assert_eq!(r, __m128i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aesimc_si128() {
+        let r = aes::_mm_aesimc_si128(state());
+        let e = u64x2::new(0x99ccbbeedd88ffaa, 0x1144336655007722);
+        assert_eq!(r, __m128i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+aes"]
+    fn _mm_aeskeygenassist_si128() {
+        let r = aes::_mm_aeskeygenassist_si128(state(), 0x01);
+        let e = u64x2::new(0x1bf533fdf533fc1b, 0x4b1628c01628c14b);
+        assert_eq!(r, __m128i::from(e));
+
+        let r = aes::_mm_aeskeygenassist_si128(state(), 0x36);
+        let e = u64x2::new(0x1bf533caf533fc1b, 0x4b1628f71628c14b);
+        assert_eq!(r, __m128i::from(e));
+    }
+}
@@ -1,17 +1,92 @@
+use std::intrinsics;
+use std::mem;
+use std::ptr;
+
+use simd_llvm::{simd_cast, simd_shuffle2, simd_shuffle4, simd_shuffle8};
+use v128::*;
 use v256::*;
+use x86::{__m128, __m128i, __m256, __m256d, __m256i};
+use x86::{_mm_loadu_ps, _mm_loadu_si128, _mm_setzero_ps};
+use x86::{_mm_storeu_ps, _mm_storeu_si128};
+use x86::{_MM_FROUND_CEIL, _MM_FROUND_FLOOR};
+
+/// Equal (ordered, non-signaling)
+pub const _CMP_EQ_OQ: i32 = 0x00;
+/// Less-than (ordered, signaling)
+pub const _CMP_LT_OS: i32 = 0x01;
+/// Less-than-or-equal (ordered, signaling)
+pub const _CMP_LE_OS: i32 = 0x02;
+/// Unordered (non-signaling)
+pub const _CMP_UNORD_Q: i32 = 0x03;
+/// Not-equal (unordered, non-signaling)
+pub const _CMP_NEQ_UQ: i32 = 0x04;
+/// Not-less-than (unordered, signaling)
+pub const _CMP_NLT_US: i32 = 0x05;
+/// Not-less-than-or-equal (unordered, signaling)
+pub const _CMP_NLE_US: i32 = 0x06;
+/// Ordered (non-signaling)
+pub const _CMP_ORD_Q: i32 = 0x07;
+/// Equal (unordered, non-signaling)
+pub const _CMP_EQ_UQ: i32 = 0x08;
+/// Not-greater-than-or-equal (unordered, signaling)
+pub const _CMP_NGE_US: i32 = 0x09;
+/// Not-greater-than (unordered, signaling)
+pub const _CMP_NGT_US: i32 = 0x0a;
+/// False (ordered, non-signaling)
+pub const _CMP_FALSE_OQ: i32 = 0x0b;
+/// Not-equal (ordered, non-signaling)
+pub const _CMP_NEQ_OQ: i32 = 0x0c;
+/// Greater-than-or-equal (ordered, signaling)
+pub const _CMP_GE_OS: i32 = 0x0d;
+/// Greater-than (ordered, signaling)
+pub const _CMP_GT_OS: i32 = 0x0e;
+/// True (unordered, non-signaling)
+pub const _CMP_TRUE_UQ: i32 = 0x0f;
+/// Equal (ordered, signaling)
+pub const _CMP_EQ_OS: i32 = 0x10;
+/// Less-than (ordered, non-signaling)
+pub const _CMP_LT_OQ: i32 = 0x11;
+/// Less-than-or-equal (ordered, non-signaling)
+pub const _CMP_LE_OQ: i32 = 0x12;
+/// Unordered (signaling)
+pub const _CMP_UNORD_S: i32 = 0x13;
+/// Not-equal (unordered, signaling)
+pub const _CMP_NEQ_US: i32 = 0x14;
+/// Not-less-than (unordered, non-signaling)
+pub const _CMP_NLT_UQ: i32 = 0x15;
+/// Not-less-than-or-equal (unordered, non-signaling)
+pub const _CMP_NLE_UQ: i32 = 0x16;
+/// Ordered (signaling)
+pub const _CMP_ORD_S: i32 = 0x17;
+/// Equal (unordered, signaling)
+pub const _CMP_EQ_US: i32 = 0x18;
+/// Not-greater-than-or-equal (unordered, non-signaling)
+pub const _CMP_NGE_UQ: i32 = 0x19;
+/// Not-greater-than (unordered, non-signaling)
+pub const _CMP_NGT_UQ: i32 = 0x1a;
+/// False (ordered, signaling)
+pub const _CMP_FALSE_OS: i32 = 0x1b;
+/// Not-equal (ordered, signaling)
+pub const _CMP_NEQ_OS: i32 = 0x1c;
+/// Greater-than-or-equal (ordered, non-signaling)
+pub const _CMP_GE_OQ: i32 = 0x1d;
+/// Greater-than (ordered, non-signaling)
+pub const _CMP_GT_OQ: i32 = 0x1e;
+/// True (unordered, signaling)
+pub const _CMP_TRUE_US: i32 = 0x1f;
 
 /// Add packed double-precision (64-bit) floating-point elements
 /// in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx"]
-pub fn _mm256_add_pd(a: f64x4, b: f64x4) -> f64x4 {
+pub fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d {
     a + b
 }
 
 /// Add packed single-precision (32-bit) floating-point elements in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx"]
-pub fn _mm256_add_ps(a: f32x8, b: f32x8) -> f32x8 {
+pub fn _mm256_add_ps(a: __m256, b: __m256) -> __m256 {
     a + b
 }
 
@@ -19,53 +94,3942 @@ pub fn _mm256_add_ps(a: f32x8, b: f32x8) -> f32x8 {
 /// floating-point elements in `a` to/from packed elements in `b`.
 #[inline(always)]
 #[target_feature = "+avx"]
-pub fn _mm256_addsub_pd(a: f64x4, b: f64x4) -> f64x4 {
+pub fn _mm256_addsub_pd(a: __m256d, b: __m256d) -> __m256d {
     unsafe { addsubpd256(a, b) }
 }
 
+/// Alternatively add and subtract packed single-precision (32-bit)
+/// floating-point elements in `a` to/from packed elements in `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_addsub_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { addsubps256(a, b) }
+}
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.x86.avx.addsub.pd.256"]
-    fn addsubpd256(a: f64x4, b:f64x4) -> f64x4;
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_and_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe {
+        let a: i64x4 = mem::transmute(a);
+        let b: i64x4 = mem::transmute(b);
+        mem::transmute(a & b)
+    }
 }
 
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_and_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe {
+        let a: i32x8 = mem::transmute(a);
+        let b: i32x8 = mem::transmute(b);
+        mem::transmute(a & b)
+    }
+}
 
-#[cfg(test)]
-mod tests {
-    use v256::*;
-    use x86::avx;
+/// Compute the bitwise NOT of `a` and then AND with `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_andnot_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe {
+        let a: i64x4 = mem::transmute(a);
+        let b: i64x4 = mem::transmute(b);
+        mem::transmute((!a) & b)
+    }
+}
 
-    #[test]
-    #[target_feature = "+avx"]
-    fn _mm256_add_pd() {
-        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
-        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
-        let r = avx::_mm256_add_pd(a, b);
-        let e = f64x4::new(6.0, 8.0, 10.0, 12.0);
-        assert_eq!(r, e);
+/// Compute the bitwise NOT of `a` and then AND with `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_andnot_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe {
+        let a: i32x8 = mem::transmute(a);
+        let b: i32x8 = mem::transmute(b);
+        mem::transmute((!a) & b)
     }
+}
 
-    #[test]
-    #[target_feature = "+avx"]
-    fn _mm256_add_ps() {
-        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
-        let b = f32x8::new(9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
-        let r = avx::_mm256_add_ps(a, b);
-        let e = f32x8::new(10.0, 12.0, 14.0, 16.0, 18.0, 20.0, 22.0, 24.0);
-        assert_eq!(r, e);
+/// Blend packed double-precision (64-bit) floating-point elements from `a`
+/// and `b` using control mask `imm8`.
+///
+/// If bit `i` of `imm8` is set, element `i` of `b` is selected, otherwise
+/// element `i` of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_blend_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d {
+    macro_rules! blend {
+        ($imm4:expr) => {
+            unsafe {
+                simd_shuffle4(a, b, [
+                    blend_index!($imm4, 0, 4), blend_index!($imm4, 1, 4),
+                    blend_index!($imm4, 2, 4), blend_index!($imm4, 3, 4),
+                ])
+            }
+        }
     }
+    constify_imm4!(imm8, blend)
+}
 
-    #[test]
-    #[target_feature = "+avx"]
-    fn _mm256_addsub_pd() {
-        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
-        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
-        let r = avx::_mm256_addsub_pd(a, b);
-        let e = f64x4::new(-4.0,8.0,-4.0,12.0);
-        assert_eq!(r, e);
+/// Blend packed single-precision (32-bit) floating-point elements from `a`
+/// and `b` using control mask `imm8`.
+///
+/// If bit `i` of `imm8` is set, element `i` of `b` is selected, otherwise
+/// element `i` of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_blend_ps(a: __m256, b: __m256, imm8: i32) -> __m256 {
+    macro_rules! blend {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, b, [
+                    blend_index!($imm8, 0, 8), blend_index!($imm8, 1, 8),
+                    blend_index!($imm8, 2, 8), blend_index!($imm8, 3, 8),
+                    blend_index!($imm8, 4, 8), blend_index!($imm8, 5, 8),
+                    blend_index!($imm8, 6, 8), blend_index!($imm8, 7, 8),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, blend)
+}
+
+/// Blend packed double-precision (64-bit) floating-point elements from `a`
+/// and `b` using `c` as a mask.
+///
+/// The element of `b` is selected where the sign bit of `c` is set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_blendv_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vblendvpd(a, b, c) }
+}
+
+/// Blend packed single-precision (32-bit) floating-point elements from `a`
+/// and `b` using `c` as a mask.
+///
+/// The element of `b` is selected where the sign bit of `c` is set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_blendv_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vblendvps(a, b, c) }
+}
+
+/// Divide packed double-precision (64-bit) floating-point elements in `a` by
+/// packed elements in `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_div_pd(a: __m256d, b: __m256d) -> __m256d {
+    a / b
+}
+
+/// Divide packed single-precision (32-bit) floating-point elements in `a` by
+/// packed elements in `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_div_ps(a: __m256, b: __m256) -> __m256 {
+    a / b
+}
+
+/// Conditionally multiply the packed single-precision (32-bit) floating-point
+/// elements in `a` and `b` within each 128-bit lane using the high 4 bits of
+/// `imm8`, sum the four products, and conditionally store the sum using the
+/// low 4 bits of `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_dp_ps(a: __m256, b: __m256, imm8: i32) -> __m256 {
+    macro_rules! call {
+        ($imm8:expr) => { unsafe { vdpps(a, b, $imm8) } }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Horizontally add adjacent pairs of double-precision (64-bit)
+/// floating-point elements in `a` and `b`.
+///
+/// In each 128-bit lane, the sum of the pair in `a` is stored in the low
+/// element and the sum of the pair in `b` in the high element.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_hadd_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { vhaddpd(a, b) }
+}
+
+/// Horizontally add adjacent pairs of single-precision (32-bit)
+/// floating-point elements in `a` and `b`.
+///
+/// In each 128-bit lane, the sums of the pairs in `a` are stored in the low
+/// two elements and the sums of the pairs in `b` in the high two elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_hadd_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { vhaddps(a, b) }
+}
+
+/// Horizontally subtract adjacent pairs of double-precision (64-bit)
+/// floating-point elements in `a` and `b`.
+///
+/// The results are ordered like in `_mm256_hadd_pd`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_hsub_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { vhsubpd(a, b) }
+}
+
+/// Horizontally subtract adjacent pairs of single-precision (32-bit)
+/// floating-point elements in `a` and `b`.
+///
+/// The results are ordered like in `_mm256_hadd_ps`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_hsub_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { vhsubps(a, b) }
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and return packed maximum values.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { maxpd256(a, b) }
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and return packed maximum values.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_max_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { maxps256(a, b) }
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and return packed minimum values.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { minpd256(a, b) }
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and return packed minimum values.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_min_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { minps256(a, b) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_mul_pd(a: __m256d, b: __m256d) -> __m256d {
+    a * b
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_mul_ps(a: __m256, b: __m256) -> __m256 {
+    a * b
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_or_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe {
+        let a: i64x4 = mem::transmute(a);
+        let b: i64x4 = mem::transmute(b);
+        mem::transmute(a | b)
+    }
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_or_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe {
+        let a: i32x8 = mem::transmute(a);
+        let b: i32x8 = mem::transmute(b);
+        mem::transmute(a | b)
+    }
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements within 128-bit
+/// lanes using the control in `imm8`.
+///
+/// In each lane, bit `2 * i` of `imm8` selects the element of `a` stored in
+/// the low element, and bit `2 * i + 1` the element of `b` stored in the high
+/// element.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_shuffle_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d {
+    macro_rules! shuffle {
+        ($imm4:expr) => {
+            unsafe {
+                simd_shuffle4(a, b, [
+                    $imm4 & 1,
+                    4 + (($imm4 >> 1) & 1),
+                    2 + (($imm4 >> 2) & 1),
+                    6 + (($imm4 >> 3) & 1),
+                ])
+            }
+        }
+    }
+    constify_imm4!(imm8, shuffle)
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements within 128-bit
+/// lanes using the control in `imm8`.
+///
+/// In each lane, the low two elements are taken from `a` and the high two
+/// from `b`, each selected by a 2-bit field of `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_shuffle_ps(a: __m256, b: __m256, imm8: i32) -> __m256 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, b, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    8 + (($imm8 >> 4) & 0b11),
+                    8 + (($imm8 >> 6) & 0b11),
+                    4 + ($imm8 & 0b11),
+                    4 + (($imm8 >> 2) & 0b11),
+                    12 + (($imm8 >> 4) & 0b11),
+                    12 + (($imm8 >> 6) & 0b11),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Subtract packed double-precision (64-bit) floating-point elements in `b`
+/// from packed elements in `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d {
+    a - b
+}
+
+/// Subtract packed single-precision (32-bit) floating-point elements in `b`
+/// from packed elements in `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256 {
+    a - b
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_xor_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe {
+        let a: i64x4 = mem::transmute(a);
+        let b: i64x4 = mem::transmute(b);
+        mem::transmute(a ^ b)
+    }
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_xor_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe {
+        let a: i32x8 = mem::transmute(a);
+        let b: i32x8 = mem::transmute(b);
+        mem::transmute(a ^ b)
+    }
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, one of the
+/// `_CMP_*` constants.
+///
+/// Each element of the result is all ones where the comparison is true and
+/// all zeros otherwise.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_cmp_pd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmppd(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, one of the
+/// `_CMP_*` constants.
+///
+/// Each element of the result is all ones where the comparison is true and
+/// all zeros otherwise.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cmp_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmppd256(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, one of the
+/// `_CMP_*` constants.
+///
+/// Each element of the result is all ones where the comparison is true and
+/// all zeros otherwise.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_cmp_ps(a: __m128, b: __m128, imm8: i32) -> __m128 {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmpps(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, one of the
+/// `_CMP_*` constants.
+///
+/// Each element of the result is all ones where the comparison is true and
+/// all zeros otherwise.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cmp_ps(a: __m256, b: __m256, imm8: i32) -> __m256 {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmpps256(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Compare the low double-precision (64-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, store the
+/// result in the low element of the return vector, and copy the upper
+/// element from `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_cmp_sd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmpsd(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Compare the low single-precision (32-bit) floating-point elements in `a`
+/// and `b` based on the comparison operand specified by `imm8`, store the
+/// result in the low element of the return vector, and copy the upper three
+/// elements from `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_cmp_ss(a: __m128, b: __m128, imm8: i32) -> __m128 {
+    macro_rules! call {
+        ($imm5:expr) => { unsafe { vcmpss(a, b, $imm5) } }
+    }
+    constify_imm5!(imm8, call)
+}
+
+/// Convert packed 32-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtepi32_pd(a: i32x4) -> __m256d {
+    unsafe { simd_cast(a) }
+}
+
+/// Convert packed 32-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtepi32_ps(a: i32x8) -> __m256 {
+    unsafe { simd_cast(a) }
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a`
+/// to packed single-precision (32-bit) floating-point elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtpd_ps(a: __m256d) -> __m128 {
+    unsafe { vcvtpd2ps(a) }
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 32-bit integers, rounding according to `MXCSR.RC`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtps_epi32(a: __m256) -> i32x8 {
+    unsafe { vcvtps2dq(a) }
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed double-precision (64-bit) floating-point elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtps_pd(a: __m128) -> __m256d {
+    unsafe { simd_cast(a) }
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a`
+/// to packed 32-bit integers with truncation.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvttpd_epi32(a: __m256d) -> i32x4 {
+    unsafe { vcvttpd2dq(a) }
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a`
+/// to packed 32-bit integers, rounding according to `MXCSR.RC`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvtpd_epi32(a: __m256d) -> i32x4 {
+    unsafe { vcvtpd2dq(a) }
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 32-bit integers with truncation.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_cvttps_epi32(a: __m256) -> i32x8 {
+    unsafe { vcvttps2dq(a) }
+}
+
+/// Extract 128 bits (composed of 4 packed single-precision (32-bit)
+/// floating-point elements) from `a`, selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extractf128_ps(a: __m256, imm8: i32) -> __m128 {
+    match imm8 & 1 {
+        0 => unsafe { simd_shuffle4(a, a, [0, 1, 2, 3]) },
+        _ => unsafe { simd_shuffle4(a, a, [4, 5, 6, 7]) },
+    }
+}
+
+/// Extract 128 bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from `a`, selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extractf128_pd(a: __m256d, imm8: i32) -> f64x2 {
+    match imm8 & 1 {
+        0 => unsafe { simd_shuffle2(a, a, [0, 1]) },
+        _ => unsafe { simd_shuffle2(a, a, [2, 3]) },
     }
+}
+
+/// Extract 128 bits (composed of integer data) from `a`, selected with
+/// `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extractf128_si256(a: __m256i, imm8: i32) -> __m128i {
+    let a = i64x4::from(a);
+    let r: i64x2 = match imm8 & 1 {
+        0 => unsafe { simd_shuffle2(a, a, [0, 1]) },
+        _ => unsafe { simd_shuffle2(a, a, [2, 3]) },
+    };
+    __m128i::from(r)
+}
+
+/// Extract an 8-bit integer from `a`, selected with `imm8`, and return it
+/// zero-extended.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extract_epi8(a: i8x32, imm8: i32) -> i32 {
+    a.extract(imm8 as u32 & 0b1_1111) as u8 as i32
+}
+
+/// Extract a 16-bit integer from `a`, selected with `imm8`, and return it
+/// zero-extended.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extract_epi16(a: i16x16, imm8: i32) -> i32 {
+    a.extract(imm8 as u32 & 0b1111) as u16 as i32
+}
+
+/// Extract a 32-bit integer from `a`, selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extract_epi32(a: i32x8, imm8: i32) -> i32 {
+    a.extract(imm8 as u32 & 0b111)
+}
+
+/// Extract a 64-bit integer from `a`, selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_extract_epi64(a: i64x4, imm8: i32) -> i64 {
+    a.extract(imm8 as u32 & 0b11)
+}
 
+/// Zero the contents of all XMM or YMM registers.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_zeroall() {
+    unsafe { vzeroall() }
+}
 
+/// Zero the upper 128 bits of all YMM registers; the lower 128 bits of the
+/// registers are unmodified.
+///
+/// This avoids the penalty of transitioning from AVX to legacy SSE code.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_zeroupper() {
+    unsafe { vzeroupper() }
+}
 
-}
\ No newline at end of file
+/// Shuffle single-precision (32-bit) floating-point elements in `a` within
+/// 128-bit lanes using the control in `b`.
+///
+/// The low 2 bits of each element of `b` select the element of `a` from the
+/// same lane.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permutevar_ps(a: __m256, b: i32x8) -> __m256 {
+    unsafe { vpermilps256(a, b) }
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements in `a` using
+/// the control in `b`.
+///
+/// The low 2 bits of each element of `b` select the element of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_permutevar_ps(a: __m128, b: i32x4) -> __m128 {
+    unsafe { vpermilps(a, b) }
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements in `a` within
+/// 128-bit lanes using the control in `imm8`.
+///
+/// Each 2-bit field of `imm8` selects the element of `a` for the
+/// corresponding element of both lanes.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permute_ps(a: __m256, imm8: i32) -> __m256 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                    4 + ($imm8 & 0b11),
+                    4 + (($imm8 >> 2) & 0b11),
+                    4 + (($imm8 >> 4) & 0b11),
+                    4 + (($imm8 >> 6) & 0b11),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements in `a` using
+/// the control in `imm8`.
+///
+/// Each 2-bit field of `imm8` selects the element of `a` for the
+/// corresponding element of the result.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_permute_ps(a: __m128, imm8: i32) -> __m128 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle4(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements in `a` within
+/// 128-bit lanes using the control in `b`.
+///
+/// Bit 1 of each element of `b` selects the element of `a` from the same
+/// lane.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permutevar_pd(a: __m256d, b: i64x4) -> __m256d {
+    unsafe { vpermilpd256(a, b) }
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements in `a` using
+/// the control in `b`.
+///
+/// Bit 1 of each element of `b` selects the element of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_permutevar_pd(a: f64x2, b: i64x2) -> f64x2 {
+    unsafe { vpermilpd(a, b) }
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements in `a` within
+/// 128-bit lanes using the control in `imm8`.
+///
+/// Bit `i` of `imm8` selects the element of `a` for element `i` of the
+/// result from the same lane.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permute_pd(a: __m256d, imm8: i32) -> __m256d {
+    macro_rules! shuffle {
+        ($imm4:expr) => {
+            unsafe {
+                simd_shuffle4(a, a, [
+                    $imm4 & 1,
+                    ($imm4 >> 1) & 1,
+                    2 + (($imm4 >> 2) & 1),
+                    2 + (($imm4 >> 3) & 1),
+                ])
+            }
+        }
+    }
+    constify_imm4!(imm8, shuffle)
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements in `a` using
+/// the control in `imm8`.
+///
+/// Bit `i` of `imm8` selects the element of `a` for element `i` of the
+/// result.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_permute_pd(a: f64x2, imm8: i32) -> f64x2 {
+    macro_rules! shuffle {
+        ($imm2:expr) => {
+            unsafe { simd_shuffle2(a, a, [$imm2 & 1, ($imm2 >> 1) & 1]) }
+        }
+    }
+    constify_imm2!(imm8, shuffle)
+}
+
+/// Shuffle 128 bits (composed of 4 packed single-precision (32-bit)
+/// floating-point elements) selected by `imm8` from `a` and `b`.
+///
+/// Bits `[1:0]` and `[5:4]` of `imm8` select the low and the high half of
+/// the result among the halves of `a` and `b`; bits 3 and 7 zero them.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permute2f128_ps(a: __m256, b: __m256, imm8: i32) -> __m256 {
+    macro_rules! call {
+        ($imm8:expr) => { unsafe { vperm2f128ps256(a, b, $imm8) } }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Shuffle 128 bits (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) selected by `imm8` from `a` and `b`.
+///
+/// See `_mm256_permute2f128_ps` for the meaning of `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permute2f128_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d {
+    macro_rules! call {
+        ($imm8:expr) => { unsafe { vperm2f128pd256(a, b, $imm8) } }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Shuffle 128 bits (composed of integer data) selected by `imm8` from `a`
+/// and `b`.
+///
+/// See `_mm256_permute2f128_ps` for the meaning of `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_permute2f128_si256(
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let a = i32x8::from(a);
+    let b = i32x8::from(b);
+    macro_rules! call {
+        ($imm8:expr) => {
+            __m256i::from(unsafe { vperm2f128si256(a, b, $imm8) })
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Broadcast a single-precision (32-bit) floating-point element from memory
+/// to all elements of the returned vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_broadcast_ss(f: &f32) -> __m256 {
+    __m256::splat(*f)
+}
+
+/// Broadcast a single-precision (32-bit) floating-point element from memory
+/// to all elements of the returned vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_broadcast_ss(f: &f32) -> __m128 {
+    __m128::splat(*f)
+}
+
+/// Broadcast a double-precision (64-bit) floating-point element from memory
+/// to all elements of the returned vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_broadcast_sd(f: &f64) -> __m256d {
+    __m256d::splat(*f)
+}
+
+/// Broadcast 128 bits from memory (composed of 4 packed single-precision
+/// (32-bit) floating-point elements) to all elements of the returned vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_broadcast_ps(a: &__m128) -> __m256 {
+    unsafe { simd_shuffle8(*a, *a, [0, 1, 2, 3, 0, 1, 2, 3]) }
+}
+
+/// Broadcast 128 bits from memory (composed of 2 packed double-precision
+/// (64-bit) floating-point elements) to all elements of the returned vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_broadcast_pd(a: &f64x2) -> __m256d {
+    unsafe { simd_shuffle4(*a, *a, [0, 1, 0, 1]) }
+}
+
+/// Copy `a`, then insert 128 bits (composed of 4 packed single-precision
+/// (32-bit) floating-point elements) from `b` at the location specified by
+/// `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insertf128_ps(a: __m256, b: __m128, imm8: i32) -> __m256 {
+    let b = _mm256_castps128_ps256(b);
+    match imm8 & 1 {
+        0 => unsafe { simd_shuffle8(a, b, [8, 9, 10, 11, 4, 5, 6, 7]) },
+        _ => unsafe { simd_shuffle8(a, b, [0, 1, 2, 3, 8, 9, 10, 11]) },
+    }
+}
+
+/// Copy `a`, then insert 128 bits (composed of 2 packed double-precision
+/// (64-bit) floating-point elements) from `b` at the location specified by
+/// `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insertf128_pd(a: __m256d, b: f64x2, imm8: i32) -> __m256d {
+    let b = _mm256_castpd128_pd256(b);
+    match imm8 & 1 {
+        0 => unsafe { simd_shuffle4(a, b, [4, 5, 2, 3]) },
+        _ => unsafe { simd_shuffle4(a, b, [0, 1, 4, 5]) },
+    }
+}
+
+/// Copy `a`, then insert 128 bits of integer data from `b` at the location
+/// specified by `imm8`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insertf128_si256(
+    a: __m256i,
+    b: __m128i,
+    imm8: i32,
+) -> __m256i {
+    let a = i64x4::from(a);
+    let b = i64x4::from(_mm256_castsi128_si256(b));
+    let r: i64x4 = match imm8 & 1 {
+        0 => unsafe { simd_shuffle4(a, b, [4, 5, 2, 3]) },
+        _ => unsafe { simd_shuffle4(a, b, [0, 1, 4, 5]) },
+    };
+    __m256i::from(r)
+}
+
+/// Copy `a`, and insert the 8-bit integer `i` at the location specified by
+/// `index`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insert_epi8(a: i8x32, i: i8, index: i32) -> i8x32 {
+    a.replace(index as u32 & 0b1_1111, i)
+}
+
+/// Copy `a`, and insert the 16-bit integer `i` at the location specified by
+/// `index`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insert_epi16(a: i16x16, i: i16, index: i32) -> i16x16 {
+    a.replace(index as u32 & 0b1111, i)
+}
+
+/// Copy `a`, and insert the 32-bit integer `i` at the location specified by
+/// `index`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insert_epi32(a: i32x8, i: i32, index: i32) -> i32x8 {
+    a.replace(index as u32 & 0b111, i)
+}
+
+/// Copy `a`, and insert the 64-bit integer `i` at the location specified by
+/// `index`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_insert_epi64(a: i64x4, i: i64, index: i32) -> i64x4 {
+    a.replace(index as u32 & 0b11, i)
+}
+
+/// Load 256 bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from memory into the returned vector.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_load_pd(mem_addr: *const f64) -> __m256d {
+    *(mem_addr as *const __m256d)
+}
+
+/// Store 256 bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from `a` into memory.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_store_pd(mem_addr: *mut f64, a: __m256d) {
+    *(mem_addr as *mut __m256d) = a;
+}
+
+/// Load 256 bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from memory into the returned vector.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_load_ps(mem_addr: *const f32) -> __m256 {
+    *(mem_addr as *const __m256)
+}
+
+/// Store 256 bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from `a` into memory.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_store_ps(mem_addr: *mut f32, a: __m256) {
+    *(mem_addr as *mut __m256) = a;
+}
+
+/// Load 256 bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from memory into the returned vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu_pd(mem_addr: *const f64) -> __m256d {
+    let mut dst = mem::uninitialized();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m256d as *mut u8,
+        mem::size_of::<__m256d>());
+    dst
+}
+
+/// Store 256 bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu_pd(mem_addr: *mut f64, a: __m256d) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m256d as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m256d>());
+}
+
+/// Load 256 bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from memory into the returned vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu_ps(mem_addr: *const f32) -> __m256 {
+    let mut dst = mem::uninitialized();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m256 as *mut u8,
+        mem::size_of::<__m256>());
+    dst
+}
+
+/// Store 256 bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu_ps(mem_addr: *mut f32, a: __m256) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m256 as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m256>());
+}
+
+/// Load 256 bits of integer data from memory into the returned vector.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_load_si256(mem_addr: *const __m256i) -> __m256i {
+    *mem_addr
+}
+
+/// Store 256 bits of integer data from `a` into memory.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_store_si256(mem_addr: *mut __m256i, a: __m256i) {
+    *mem_addr = a;
+}
+
+/// Load 256 bits of integer data from memory into the returned vector.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu_si256(mem_addr: *const __m256i) -> __m256i {
+    let mut dst = mem::uninitialized();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m256i as *mut u8,
+        mem::size_of::<__m256i>());
+    dst
+}
+
+/// Store 256 bits of integer data from `a` into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu_si256(mem_addr: *mut __m256i, a: __m256i) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m256i as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m256i>());
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_maskload_pd(
+    mem_addr: *const f64,
+    mask: i64x4,
+) -> __m256d {
+    maskloadpd256(mem_addr as *const i8, mask)
+}
+
+/// Store packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_maskstore_pd(
+    mem_addr: *mut f64,
+    mask: i64x4,
+    a: __m256d,
+) {
+    maskstorepd256(mem_addr as *mut i8, mask, a);
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm_maskload_pd(mem_addr: *const f64, mask: i64x2) -> f64x2 {
+    maskloadpd(mem_addr as *const i8, mask)
+}
+
+/// Store packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm_maskstore_pd(mem_addr: *mut f64, mask: i64x2, a: f64x2) {
+    maskstorepd(mem_addr as *mut i8, mask, a);
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_maskload_ps(
+    mem_addr: *const f32,
+    mask: i32x8,
+) -> __m256 {
+    maskloadps256(mem_addr as *const i8, mask)
+}
+
+/// Store packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_maskstore_ps(
+    mem_addr: *mut f32,
+    mask: i32x8,
+    a: __m256,
+) {
+    maskstoreps256(mem_addr as *mut i8, mask, a);
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm_maskload_ps(mem_addr: *const f32, mask: i32x4) -> __m128 {
+    maskloadps(mem_addr as *const i8, mask)
+}
+
+/// Store packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm_maskstore_ps(mem_addr: *mut f32, mask: i32x4, a: __m128) {
+    maskstoreps(mem_addr as *mut i8, mask, a);
+}
+
+/// Duplicate odd-indexed single-precision (32-bit) floating-point elements
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_movehdup_ps(a: __m256) -> __m256 {
+    unsafe { simd_shuffle8(a, a, [1, 1, 3, 3, 5, 5, 7, 7]) }
+}
+
+/// Duplicate even-indexed single-precision (32-bit) floating-point elements
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_moveldup_ps(a: __m256) -> __m256 {
+    unsafe { simd_shuffle8(a, a, [0, 0, 2, 2, 4, 4, 6, 6]) }
+}
+
+/// Duplicate even-indexed double-precision (64-bit) floating-point elements
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_movedup_pd(a: __m256d) -> __m256d {
+    unsafe { simd_shuffle4(a, a, [0, 0, 2, 2]) }
+}
+
+/// Load 256 bits of integer data from unaligned memory.
+///
+/// This intrinsic may perform better than `_mm256_loadu_si256` when the data
+/// crosses a cache line boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_lddqu_si256(mem_addr: *const __m256i) -> __m256i {
+    vlddqu(mem_addr as *const i8)
+}
+
+/// Store 256 bits of integer data from `a` into memory using a non-temporal
+/// memory hint.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_stream_si256(mem_addr: *mut __m256i, a: __m256i) {
+    intrinsics::nontemporal_store(mem_addr, a);
+}
+
+/// Store 256 bits (composed of 4 packed double-precision (64-bit)
+/// floating-point elements) from `a` into memory using a non-temporal memory
+/// hint.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_stream_pd(mem_addr: *mut f64, a: __m256d) {
+    intrinsics::nontemporal_store(mem_addr as *mut __m256d, a);
+}
+
+/// Store 256 bits (composed of 8 packed single-precision (32-bit)
+/// floating-point elements) from `a` into memory using a non-temporal memory
+/// hint.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_stream_ps(mem_addr: *mut f32, a: __m256) {
+    intrinsics::nontemporal_store(mem_addr as *mut __m256, a);
+}
+
+/// Compute the approximate reciprocal of packed single-precision (32-bit)
+/// floating-point elements in `a`.
+///
+/// The maximum relative error for this approximation is less than
+/// 1.5*2^-12.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_rcp_ps(a: __m256) -> __m256 {
+    unsafe { vrcpps(a) }
+}
+
+/// Compute the approximate reciprocal square root of packed single-precision
+/// (32-bit) floating-point elements in `a`.
+///
+/// The maximum relative error for this approximation is less than
+/// 1.5*2^-12.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_rsqrt_ps(a: __m256) -> __m256 {
+    unsafe { vrsqrtps(a) }
+}
+
+/// Return the square root of packed double-precision (64-bit) floating-point
+/// elements in `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_sqrt_pd(a: __m256d) -> __m256d {
+    unsafe { sqrtpd256(a) }
+}
+
+/// Return the square root of packed single-precision (32-bit) floating-point
+/// elements in `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_sqrt_ps(a: __m256) -> __m256 {
+    unsafe { sqrtps256(a) }
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// using the `rounding` parameter, one of the `_MM_FROUND_*` constants.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_round_pd(a: __m256d, rounding: i32) -> __m256d {
+    macro_rules! call {
+        ($imm4:expr) => { unsafe { roundpd256(a, $imm4) } }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// using the `rounding` parameter, one of the `_MM_FROUND_*` constants.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_round_ps(a: __m256, rounding: i32) -> __m256 {
+    macro_rules! call {
+        ($imm4:expr) => { unsafe { roundps256(a, $imm4) } }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// toward positive infinity.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_ceil_pd(a: __m256d) -> __m256d {
+    unsafe { roundpd256(a, _MM_FROUND_CEIL) }
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// toward negative infinity.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_floor_pd(a: __m256d) -> __m256d {
+    unsafe { roundpd256(a, _MM_FROUND_FLOOR) }
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// toward positive infinity.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_ceil_ps(a: __m256) -> __m256 {
+    unsafe { roundps256(a, _MM_FROUND_CEIL) }
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// toward negative infinity.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_floor_ps(a: __m256) -> __m256 {
+    unsafe { roundps256(a, _MM_FROUND_FLOOR) }
+}
+
+/// Unpack and interleave double-precision (64-bit) floating-point elements
+/// from the high half of each 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_unpackhi_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { simd_shuffle4(a, b, [1, 5, 3, 7]) }
+}
+
+/// Unpack and interleave single-precision (32-bit) floating-point elements
+/// from the high half of each 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_unpackhi_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { simd_shuffle8(a, b, [2, 10, 3, 11, 6, 14, 7, 15]) }
+}
+
+/// Unpack and interleave double-precision (64-bit) floating-point elements
+/// from the low half of each 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_unpacklo_pd(a: __m256d, b: __m256d) -> __m256d {
+    unsafe { simd_shuffle4(a, b, [0, 4, 2, 6]) }
+}
+
+/// Unpack and interleave single-precision (32-bit) floating-point elements
+/// from the low half of each 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_unpacklo_ps(a: __m256, b: __m256) -> __m256 {
+    unsafe { simd_shuffle8(a, b, [0, 8, 1, 9, 4, 12, 5, 13]) }
+}
+
+/// Compute the bitwise AND of 256 bits (representing integer data) in `a`
+/// and `b`, and return 1 if the result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testz_si256(a: __m256i, b: __m256i) -> i32 {
+    unsafe { ptestz256(i64x4::from(a), i64x4::from(b)) }
+}
+
+/// Compute the bitwise NOT of `a` and then AND with `b`, and return 1 if the
+/// result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testc_si256(a: __m256i, b: __m256i) -> i32 {
+    unsafe { ptestc256(i64x4::from(a), i64x4::from(b)) }
+}
+
+/// Return 1 if neither the bitwise AND of `a` and `b`, nor the bitwise AND
+/// of the NOT of `a` and `b` is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testnzc_si256(a: __m256i, b: __m256i) -> i32 {
+    unsafe { ptestnzc256(i64x4::from(a), i64x4::from(b)) }
+}
+
+/// Compute the bitwise AND of the sign bits of the packed double-precision
+/// (64-bit) floating-point elements in `a` and `b`, and return 1 if the
+/// result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testz_pd(a: __m256d, b: __m256d) -> i32 {
+    unsafe { vtestzpd256(a, b) }
+}
+
+/// Compute the bitwise NOT of the sign bits of the packed double-precision
+/// (64-bit) floating-point elements in `a` and then AND with the sign bits
+/// in `b`, and return 1 if the result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testc_pd(a: __m256d, b: __m256d) -> i32 {
+    unsafe { vtestcpd256(a, b) }
+}
+
+/// Return 1 if neither `_mm256_testz_pd` nor `_mm256_testc_pd` would return
+/// 1 for `a` and `b`, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testnzc_pd(a: __m256d, b: __m256d) -> i32 {
+    unsafe { vtestnzcpd256(a, b) }
+}
+
+/// Compute the bitwise AND of the sign bits of the packed double-precision
+/// (64-bit) floating-point elements in `a` and `b`, and return 1 if the
+/// result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testz_pd(a: f64x2, b: f64x2) -> i32 {
+    unsafe { vtestzpd(a, b) }
+}
+
+/// Compute the bitwise NOT of the sign bits of the packed double-precision
+/// (64-bit) floating-point elements in `a` and then AND with the sign bits
+/// in `b`, and return 1 if the result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testc_pd(a: f64x2, b: f64x2) -> i32 {
+    unsafe { vtestcpd(a, b) }
+}
+
+/// Return 1 if neither `_mm_testz_pd` nor `_mm_testc_pd` would return 1 for
+/// `a` and `b`, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testnzc_pd(a: f64x2, b: f64x2) -> i32 {
+    unsafe { vtestnzcpd(a, b) }
+}
+
+/// Compute the bitwise AND of the sign bits of the packed single-precision
+/// (32-bit) floating-point elements in `a` and `b`, and return 1 if the
+/// result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testz_ps(a: __m256, b: __m256) -> i32 {
+    unsafe { vtestzps256(a, b) }
+}
+
+/// Compute the bitwise NOT of the sign bits of the packed single-precision
+/// (32-bit) floating-point elements in `a` and then AND with the sign bits
+/// in `b`, and return 1 if the result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testc_ps(a: __m256, b: __m256) -> i32 {
+    unsafe { vtestcps256(a, b) }
+}
+
+/// Return 1 if neither `_mm256_testz_ps` nor `_mm256_testc_ps` would return
+/// 1 for `a` and `b`, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_testnzc_ps(a: __m256, b: __m256) -> i32 {
+    unsafe { vtestnzcps256(a, b) }
+}
+
+/// Compute the bitwise AND of the sign bits of the packed single-precision
+/// (32-bit) floating-point elements in `a` and `b`, and return 1 if the
+/// result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testz_ps(a: __m128, b: __m128) -> i32 {
+    unsafe { vtestzps(a, b) }
+}
+
+/// Compute the bitwise NOT of the sign bits of the packed single-precision
+/// (32-bit) floating-point elements in `a` and then AND with the sign bits
+/// in `b`, and return 1 if the result is zero, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testc_ps(a: __m128, b: __m128) -> i32 {
+    unsafe { vtestcps(a, b) }
+}
+
+/// Return 1 if neither `_mm_testz_ps` nor `_mm_testc_ps` would return 1 for
+/// `a` and `b`, otherwise 0.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm_testnzc_ps(a: __m128, b: __m128) -> i32 {
+    unsafe { vtestnzcps(a, b) }
+}
+
+/// Return a mask of the most significant bit of each element in `a`.
+///
+/// The mask is stored in the 4 least significant bits of the return value.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_movemask_pd(a: __m256d) -> i32 {
+    unsafe { movmskpd256(a) }
+}
+
+/// Return a mask of the most significant bit of each element in `a`.
+///
+/// The mask is stored in the 8 least significant bits of the return value.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_movemask_ps(a: __m256) -> i32 {
+    unsafe { movmskps256(a) }
+}
+
+/// Return a `__m256d` with all elements set to zero.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setzero_pd() -> __m256d {
+    __m256d::splat(0.0)
+}
+
+/// Return a `__m256` with all elements set to zero.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setzero_ps() -> __m256 {
+    __m256::splat(0.0)
+}
+
+/// Return a `__m256i` with all elements set to zero.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setzero_si256() -> __m256i {
+    __m256i::splat(0)
+}
+
+/// Set packed double-precision (64-bit) floating-point elements with the
+/// supplied values, from the highest to the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_pd(e3: f64, e2: f64, e1: f64, e0: f64) -> __m256d {
+    __m256d::new(e0, e1, e2, e3)
+}
+
+/// Set packed single-precision (32-bit) floating-point elements with the
+/// supplied values, from the highest to the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_ps(
+    e7: f32, e6: f32, e5: f32, e4: f32, e3: f32, e2: f32, e1: f32, e0: f32,
+) -> __m256 {
+    __m256::new(e0, e1, e2, e3, e4, e5, e6, e7)
+}
+
+/// Set packed 8-bit integers with the supplied values, from the highest to
+/// the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_epi8(
+    e31: i8, e30: i8, e29: i8, e28: i8, e27: i8, e26: i8, e25: i8, e24: i8,
+    e23: i8, e22: i8, e21: i8, e20: i8, e19: i8, e18: i8, e17: i8, e16: i8,
+    e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+    e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+) -> i8x32 {
+    i8x32::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15,
+        e16, e17, e18, e19, e20, e21, e22, e23,
+        e24, e25, e26, e27, e28, e29, e30, e31,
+    )
+}
+
+/// Set packed 16-bit integers with the supplied values, from the highest to
+/// the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_epi16(
+    e15: i16, e14: i16, e13: i16, e12: i16,
+    e11: i16, e10: i16, e9: i16, e8: i16,
+    e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16,
+) -> i16x16 {
+    i16x16::new(
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15,
+    )
+}
+
+/// Set packed 32-bit integers with the supplied values, from the highest to
+/// the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_epi32(
+    e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32,
+) -> i32x8 {
+    i32x8::new(e0, e1, e2, e3, e4, e5, e6, e7)
+}
+
+/// Set packed 64-bit integers with the supplied values, from the highest to
+/// the lowest.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_epi64x(e3: i64, e2: i64, e1: i64, e0: i64) -> i64x4 {
+    i64x4::new(e0, e1, e2, e3)
+}
+
+/// Set packed double-precision (64-bit) floating-point elements with the
+/// supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_pd(e3: f64, e2: f64, e1: f64, e0: f64) -> __m256d {
+    __m256d::new(e3, e2, e1, e0)
+}
+
+/// Set packed single-precision (32-bit) floating-point elements with the
+/// supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_ps(
+    e7: f32, e6: f32, e5: f32, e4: f32, e3: f32, e2: f32, e1: f32, e0: f32,
+) -> __m256 {
+    __m256::new(e7, e6, e5, e4, e3, e2, e1, e0)
+}
+
+/// Set packed 8-bit integers with the supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_epi8(
+    e31: i8, e30: i8, e29: i8, e28: i8, e27: i8, e26: i8, e25: i8, e24: i8,
+    e23: i8, e22: i8, e21: i8, e20: i8, e19: i8, e18: i8, e17: i8, e16: i8,
+    e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+    e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+) -> i8x32 {
+    i8x32::new(
+        e31, e30, e29, e28, e27, e26, e25, e24,
+        e23, e22, e21, e20, e19, e18, e17, e16,
+        e15, e14, e13, e12, e11, e10, e9, e8, e7, e6, e5, e4, e3, e2, e1, e0,
+    )
+}
+
+/// Set packed 16-bit integers with the supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_epi16(
+    e15: i16, e14: i16, e13: i16, e12: i16,
+    e11: i16, e10: i16, e9: i16, e8: i16,
+    e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16,
+) -> i16x16 {
+    i16x16::new(
+        e15, e14, e13, e12, e11, e10, e9, e8, e7, e6, e5, e4, e3, e2, e1, e0,
+    )
+}
+
+/// Set packed 32-bit integers with the supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_epi32(
+    e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32,
+) -> i32x8 {
+    i32x8::new(e7, e6, e5, e4, e3, e2, e1, e0)
+}
+
+/// Set packed 64-bit integers with the supplied values in reverse order.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_epi64x(e3: i64, e2: i64, e1: i64, e0: i64) -> i64x4 {
+    i64x4::new(e3, e2, e1, e0)
+}
+
+/// Broadcast double-precision (64-bit) floating-point value `a` to all
+/// elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_pd(a: f64) -> __m256d {
+    __m256d::splat(a)
+}
+
+/// Broadcast single-precision (32-bit) floating-point value `a` to all
+/// elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_ps(a: f32) -> __m256 {
+    __m256::splat(a)
+}
+
+/// Broadcast 8-bit integer `a` to all elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_epi8(a: i8) -> i8x32 {
+    i8x32::splat(a)
+}
+
+/// Broadcast 16-bit integer `a` to all elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_epi16(a: i16) -> i16x16 {
+    i16x16::splat(a)
+}
+
+/// Broadcast 32-bit integer `a` to all elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_epi32(a: i32) -> i32x8 {
+    i32x8::splat(a)
+}
+
+/// Broadcast 64-bit integer `a` to all elements.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set1_epi64x(a: i64) -> i64x4 {
+    i64x4::splat(a)
+}
+
+/// Cast vector of type `__m256d` to type `__m256`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castpd_ps(a: __m256d) -> __m256 {
+    unsafe { mem::transmute(a) }
+}
+
+/// Cast vector of type `__m256` to type `__m256d`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castps_pd(a: __m256) -> __m256d {
+    unsafe { mem::transmute(a) }
+}
+
+/// Cast vector of type `__m256` to type `__m256i`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castps_si256(a: __m256) -> __m256i {
+    unsafe { mem::transmute(a) }
+}
+
+/// Cast vector of type `__m256d` to type `__m256i`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castpd_si256(a: __m256d) -> __m256i {
+    unsafe { mem::transmute(a) }
+}
+
+/// Cast vector of type `__m256i` to type `__m256`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castsi256_ps(a: __m256i) -> __m256 {
+    unsafe { mem::transmute(a) }
+}
+
+/// Cast vector of type `__m256i` to type `__m256d`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castsi256_pd(a: __m256i) -> __m256d {
+    unsafe { mem::transmute(a) }
+}
+
+/// Return the lower 128 bits of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castps256_ps128(a: __m256) -> __m128 {
+    unsafe { simd_shuffle4(a, a, [0, 1, 2, 3]) }
+}
+
+/// Return the lower 128 bits of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castpd256_pd128(a: __m256d) -> f64x2 {
+    unsafe { simd_shuffle2(a, a, [0, 1]) }
+}
+
+/// Return the lower 128 bits of `a`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castsi256_si128(a: __m256i) -> __m128i {
+    let a = i64x4::from(a);
+    let r: i64x2 = unsafe { simd_shuffle2(a, a, [0, 1]) };
+    __m128i::from(r)
+}
+
+/// Return a `__m256` with the lower 128 bits set to `a`.
+///
+/// The upper 128 bits are undefined; this implementation copies `a` into
+/// them.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castps128_ps256(a: __m128) -> __m256 {
+    unsafe { simd_shuffle8(a, a, [0, 1, 2, 3, 0, 1, 2, 3]) }
+}
+
+/// Return a `__m256d` with the lower 128 bits set to `a`.
+///
+/// The upper 128 bits are undefined; this implementation copies `a` into
+/// them.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castpd128_pd256(a: f64x2) -> __m256d {
+    unsafe { simd_shuffle4(a, a, [0, 1, 0, 1]) }
+}
+
+/// Return a `__m256i` with the lower 128 bits set to `a`.
+///
+/// The upper 128 bits are undefined; this implementation copies `a` into
+/// them.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_castsi128_si256(a: __m128i) -> __m256i {
+    let a = i64x2::from(a);
+    let r: i64x4 = unsafe { simd_shuffle4(a, a, [0, 1, 0, 1]) };
+    __m256i::from(r)
+}
+
+/// Return a `__m256` with the lower 128 bits set to `a` and the upper 128
+/// bits zeroed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_zextps128_ps256(a: __m128) -> __m256 {
+    let zero = _mm_setzero_ps();
+    unsafe { simd_shuffle8(a, zero, [0, 1, 2, 3, 4, 5, 6, 7]) }
+}
+
+/// Return a `__m256d` with the lower 128 bits set to `a` and the upper 128
+/// bits zeroed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_zextpd128_pd256(a: f64x2) -> __m256d {
+    unsafe { simd_shuffle4(a, f64x2::splat(0.0), [0, 1, 2, 3]) }
+}
+
+/// Return a `__m256i` with the lower 128 bits set to `a` and the upper 128
+/// bits zeroed.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_zextsi128_si256(a: __m128i) -> __m256i {
+    let a = i64x2::from(a);
+    let zero = i64x2::splat(0);
+    let r: i64x4 = unsafe { simd_shuffle4(a, zero, [0, 1, 2, 3]) };
+    __m256i::from(r)
+}
+
+/// Return a `__m256` with undefined elements.
+///
+/// This implementation returns a zeroed vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_undefined_ps() -> __m256 {
+    _mm256_setzero_ps()
+}
+
+/// Return a `__m256d` with undefined elements.
+///
+/// This implementation returns a zeroed vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_undefined_pd() -> __m256d {
+    _mm256_setzero_pd()
+}
+
+/// Return a `__m256i` with undefined elements.
+///
+/// This implementation returns a zeroed vector.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_undefined_si256() -> __m256i {
+    _mm256_setzero_si256()
+}
+
+/// Set the upper and the lower 128 bits of the returned vector to `hi` and
+/// `lo`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_m128(hi: __m128, lo: __m128) -> __m256 {
+    unsafe { simd_shuffle8(lo, hi, [0, 1, 2, 3, 4, 5, 6, 7]) }
+}
+
+/// Set the upper and the lower 128 bits of the returned vector to `hi` and
+/// `lo`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_m128d(hi: f64x2, lo: f64x2) -> __m256d {
+    unsafe { simd_shuffle4(lo, hi, [0, 1, 2, 3]) }
+}
+
+/// Set the upper and the lower 128 bits of the returned vector to `hi` and
+/// `lo`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_set_m128i(hi: __m128i, lo: __m128i) -> __m256i {
+    let hi = i64x2::from(hi);
+    let lo = i64x2::from(lo);
+    let r: i64x4 = unsafe { simd_shuffle4(lo, hi, [0, 1, 2, 3]) };
+    __m256i::from(r)
+}
+
+/// Set the lower and the upper 128 bits of the returned vector to `lo` and
+/// `hi`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_m128(lo: __m128, hi: __m128) -> __m256 {
+    _mm256_set_m128(hi, lo)
+}
+
+/// Set the lower and the upper 128 bits of the returned vector to `lo` and
+/// `hi`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_m128d(lo: f64x2, hi: f64x2) -> __m256d {
+    _mm256_set_m128d(hi, lo)
+}
+
+/// Set the lower and the upper 128 bits of the returned vector to `lo` and
+/// `hi`.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub fn _mm256_setr_m128i(lo: __m128i, hi: __m128i) -> __m256i {
+    _mm256_set_m128i(hi, lo)
+}
+
+/// Load two 128-bit values (composed of 4 packed single-precision (32-bit)
+/// floating-point elements) from memory, and combine them into a 256-bit
+/// value.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu2_m128(
+    hiaddr: *const f32,
+    loaddr: *const f32,
+) -> __m256 {
+    _mm256_set_m128(_mm_loadu_ps(hiaddr), _mm_loadu_ps(loaddr))
+}
+
+/// Load two 128-bit values (composed of 2 packed double-precision (64-bit)
+/// floating-point elements) from memory, and combine them into a 256-bit
+/// value.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu2_m128d(
+    hiaddr: *const f64,
+    loaddr: *const f64,
+) -> __m256d {
+    let r = _mm256_loadu2_m128(hiaddr as *const f32, loaddr as *const f32);
+    _mm256_castps_pd(r)
+}
+
+/// Load two 128-bit values (composed of integer data) from memory, and
+/// combine them into a 256-bit value.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_loadu2_m128i(
+    hiaddr: *const __m128i,
+    loaddr: *const __m128i,
+) -> __m256i {
+    _mm256_set_m128i(_mm_loadu_si128(hiaddr), _mm_loadu_si128(loaddr))
+}
+
+/// Store the high and the low 128-bit halves (each composed of 4 packed
+/// single-precision (32-bit) floating-point elements) from `a` into two
+/// different memory locations.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu2_m128(
+    hiaddr: *mut f32,
+    loaddr: *mut f32,
+    a: __m256,
+) {
+    _mm_storeu_ps(loaddr, _mm256_castps256_ps128(a));
+    _mm_storeu_ps(hiaddr, _mm256_extractf128_ps(a, 1));
+}
+
+/// Store the high and the low 128-bit halves (each composed of 2 packed
+/// double-precision (64-bit) floating-point elements) from `a` into two
+/// different memory locations.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu2_m128d(
+    hiaddr: *mut f64,
+    loaddr: *mut f64,
+    a: __m256d,
+) {
+    let a = _mm256_castpd_ps(a);
+    _mm256_storeu2_m128(hiaddr as *mut f32, loaddr as *mut f32, a);
+}
+
+/// Store the high and the low 128-bit halves (each composed of integer data)
+/// from `a` into two different memory locations.
+///
+/// `hiaddr` and `loaddr` do not need to be aligned on any particular
+/// boundary.
+#[inline(always)]
+#[target_feature = "+avx"]
+pub unsafe fn _mm256_storeu2_m128i(
+    hiaddr: *mut __m128i,
+    loaddr: *mut __m128i,
+    a: __m256i,
+) {
+    _mm_storeu_si128(loaddr, _mm256_castsi256_si128(a));
+    _mm_storeu_si128(hiaddr, _mm256_extractf128_si256(a, 1));
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.avx.addsub.pd.256"]
+    fn addsubpd256(a: __m256d, b: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.addsub.ps.256"]
+    fn addsubps256(a: __m256, b: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.blendv.pd.256"]
+    fn vblendvpd(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.blendv.ps.256"]
+    fn vblendvps(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.dp.ps.256"]
+    fn vdpps(a: __m256, b: __m256, imm8: u8) -> __m256;
+    #[link_name = "llvm.x86.avx.hadd.pd.256"]
+    fn vhaddpd(a: __m256d, b: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.hadd.ps.256"]
+    fn vhaddps(a: __m256, b: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.hsub.pd.256"]
+    fn vhsubpd(a: __m256d, b: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.hsub.ps.256"]
+    fn vhsubps(a: __m256, b: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.max.pd.256"]
+    fn maxpd256(a: __m256d, b: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.max.ps.256"]
+    fn maxps256(a: __m256, b: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.min.pd.256"]
+    fn minpd256(a: __m256d, b: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.min.ps.256"]
+    fn minps256(a: __m256, b: __m256) -> __m256;
+    #[link_name = "llvm.x86.sse2.cmp.pd"]
+    fn vcmppd(a: f64x2, b: f64x2, imm8: u8) -> f64x2;
+    #[link_name = "llvm.x86.avx.cmp.pd.256"]
+    fn vcmppd256(a: __m256d, b: __m256d, imm8: u8) -> __m256d;
+    #[link_name = "llvm.x86.sse.cmp.ps"]
+    fn vcmpps(a: __m128, b: __m128, imm8: u8) -> __m128;
+    #[link_name = "llvm.x86.avx.cmp.ps.256"]
+    fn vcmpps256(a: __m256, b: __m256, imm8: u8) -> __m256;
+    #[link_name = "llvm.x86.sse2.cmp.sd"]
+    fn vcmpsd(a: f64x2, b: f64x2, imm8: u8) -> f64x2;
+    #[link_name = "llvm.x86.sse.cmp.ss"]
+    fn vcmpss(a: __m128, b: __m128, imm8: u8) -> __m128;
+    #[link_name = "llvm.x86.avx.cvt.pd2.ps.256"]
+    fn vcvtpd2ps(a: __m256d) -> __m128;
+    #[link_name = "llvm.x86.avx.cvt.ps2dq.256"]
+    fn vcvtps2dq(a: __m256) -> i32x8;
+    #[link_name = "llvm.x86.avx.cvtt.pd2dq.256"]
+    fn vcvttpd2dq(a: __m256d) -> i32x4;
+    #[link_name = "llvm.x86.avx.cvt.pd2dq.256"]
+    fn vcvtpd2dq(a: __m256d) -> i32x4;
+    #[link_name = "llvm.x86.avx.cvtt.ps2dq.256"]
+    fn vcvttps2dq(a: __m256) -> i32x8;
+    #[link_name = "llvm.x86.avx.vzeroall"]
+    fn vzeroall();
+    #[link_name = "llvm.x86.avx.vzeroupper"]
+    fn vzeroupper();
+    #[link_name = "llvm.x86.avx.vpermilvar.ps.256"]
+    fn vpermilps256(a: __m256, b: i32x8) -> __m256;
+    #[link_name = "llvm.x86.avx.vpermilvar.ps"]
+    fn vpermilps(a: __m128, b: i32x4) -> __m128;
+    #[link_name = "llvm.x86.avx.vpermilvar.pd.256"]
+    fn vpermilpd256(a: __m256d, b: i64x4) -> __m256d;
+    #[link_name = "llvm.x86.avx.vpermilvar.pd"]
+    fn vpermilpd(a: f64x2, b: i64x2) -> f64x2;
+    #[link_name = "llvm.x86.avx.vperm2f128.ps.256"]
+    fn vperm2f128ps256(a: __m256, b: __m256, imm8: u8) -> __m256;
+    #[link_name = "llvm.x86.avx.vperm2f128.pd.256"]
+    fn vperm2f128pd256(a: __m256d, b: __m256d, imm8: u8) -> __m256d;
+    #[link_name = "llvm.x86.avx.vperm2f128.si.256"]
+    fn vperm2f128si256(a: i32x8, b: i32x8, imm8: u8) -> i32x8;
+    #[link_name = "llvm.x86.avx.maskload.pd.256"]
+    fn maskloadpd256(mem_addr: *const i8, mask: i64x4) -> __m256d;
+    #[link_name = "llvm.x86.avx.maskstore.pd.256"]
+    fn maskstorepd256(mem_addr: *mut i8, mask: i64x4, a: __m256d);
+    #[link_name = "llvm.x86.avx.maskload.pd"]
+    fn maskloadpd(mem_addr: *const i8, mask: i64x2) -> f64x2;
+    #[link_name = "llvm.x86.avx.maskstore.pd"]
+    fn maskstorepd(mem_addr: *mut i8, mask: i64x2, a: f64x2);
+    #[link_name = "llvm.x86.avx.maskload.ps.256"]
+    fn maskloadps256(mem_addr: *const i8, mask: i32x8) -> __m256;
+    #[link_name = "llvm.x86.avx.maskstore.ps.256"]
+    fn maskstoreps256(mem_addr: *mut i8, mask: i32x8, a: __m256);
+    #[link_name = "llvm.x86.avx.maskload.ps"]
+    fn maskloadps(mem_addr: *const i8, mask: i32x4) -> __m128;
+    #[link_name = "llvm.x86.avx.maskstore.ps"]
+    fn maskstoreps(mem_addr: *mut i8, mask: i32x4, a: __m128);
+    #[link_name = "llvm.x86.avx.ldu.dq.256"]
+    fn vlddqu(mem_addr: *const i8) -> __m256i;
+    #[link_name = "llvm.x86.avx.rcp.ps.256"]
+    fn vrcpps(a: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.rsqrt.ps.256"]
+    fn vrsqrtps(a: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.sqrt.pd.256"]
+    fn sqrtpd256(a: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.avx.sqrt.ps.256"]
+    fn sqrtps256(a: __m256) -> __m256;
+    #[link_name = "llvm.x86.avx.round.pd.256"]
+    fn roundpd256(a: __m256d, rounding: i32) -> __m256d;
+    #[link_name = "llvm.x86.avx.round.ps.256"]
+    fn roundps256(a: __m256, rounding: i32) -> __m256;
+    #[link_name = "llvm.x86.avx.ptestz.256"]
+    fn ptestz256(a: i64x4, b: i64x4) -> i32;
+    #[link_name = "llvm.x86.avx.ptestc.256"]
+    fn ptestc256(a: i64x4, b: i64x4) -> i32;
+    #[link_name = "llvm.x86.avx.ptestnzc.256"]
+    fn ptestnzc256(a: i64x4, b: i64x4) -> i32;
+    #[link_name = "llvm.x86.avx.vtestz.pd.256"]
+    fn vtestzpd256(a: __m256d, b: __m256d) -> i32;
+    #[link_name = "llvm.x86.avx.vtestc.pd.256"]
+    fn vtestcpd256(a: __m256d, b: __m256d) -> i32;
+    #[link_name = "llvm.x86.avx.vtestnzc.pd.256"]
+    fn vtestnzcpd256(a: __m256d, b: __m256d) -> i32;
+    #[link_name = "llvm.x86.avx.vtestz.pd"]
+    fn vtestzpd(a: f64x2, b: f64x2) -> i32;
+    #[link_name = "llvm.x86.avx.vtestc.pd"]
+    fn vtestcpd(a: f64x2, b: f64x2) -> i32;
+    #[link_name = "llvm.x86.avx.vtestnzc.pd"]
+    fn vtestnzcpd(a: f64x2, b: f64x2) -> i32;
+    #[link_name = "llvm.x86.avx.vtestz.ps.256"]
+    fn vtestzps256(a: __m256, b: __m256) -> i32;
+    #[link_name = "llvm.x86.avx.vtestc.ps.256"]
+    fn vtestcps256(a: __m256, b: __m256) -> i32;
+    #[link_name = "llvm.x86.avx.vtestnzc.ps.256"]
+    fn vtestnzcps256(a: __m256, b: __m256) -> i32;
+    #[link_name = "llvm.x86.avx.vtestz.ps"]
+    fn vtestzps(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.avx.vtestc.ps"]
+    fn vtestcps(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.avx.vtestnzc.ps"]
+    fn vtestnzcps(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.avx.movmsk.pd.256"]
+    fn movmskpd256(a: __m256d) -> i32;
+    #[link_name = "llvm.x86.avx.movmsk.ps.256"]
+    fn movmskps256(a: __m256) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::transmute;
+
+    use v128::*;
+    use v256::*;
+    use x86::{__m128i, __m256, __m256d, __m256i};
+    use x86::avx;
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_add_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_add_pd(a, b);
+        let e = f64x4::new(6.0, 8.0, 10.0, 12.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_add_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let r = avx::_mm256_add_ps(a, b);
+        let e = f32x8::new(10.0, 12.0, 14.0, 16.0, 18.0, 20.0, 22.0, 24.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_addsub_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_addsub_pd(a, b);
+        let e = f64x4::new(-4.0,8.0,-4.0,12.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_addsub_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0);
+        let b = f32x8::new(5.0, 6.0, 7.0, 8.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_addsub_ps(a, b);
+        let e = f32x8::new(-4.0, 8.0, -4.0, 12.0, -4.0, 8.0, -4.0, 12.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_and_pd() {
+        let a = f64x4::splat(1.0);
+        let b = f64x4::splat(0.6);
+        let r = avx::_mm256_and_pd(a, b);
+        let e = f64x4::splat(0.5);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_and_ps() {
+        let a = f32x8::splat(1.0);
+        let b = f32x8::splat(0.6);
+        let r = avx::_mm256_and_ps(a, b);
+        let e = f32x8::splat(0.5);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_andnot_pd() {
+        let a = f64x4::splat(-0.0);
+        let b = f64x4::new(-1.0, 2.0, -3.0, 4.0);
+        let r = avx::_mm256_andnot_pd(a, b);
+        let e = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_andnot_ps() {
+        let a = f32x8::splat(-0.0);
+        let b = f32x8::new(-1.0, 2.0, -3.0, 4.0, -5.0, 6.0, -7.0, 8.0);
+        let r = avx::_mm256_andnot_ps(a, b);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_blend_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_blend_pd(a, b, 0x0);
+        assert_eq!(r, f64x4::new(4.0, 9.0, 16.0, 25.0));
+        let r = avx::_mm256_blend_pd(a, b, 0x3);
+        assert_eq!(r, f64x4::new(4.0, 3.0, 16.0, 25.0));
+        let r = avx::_mm256_blend_pd(a, b, 0xF);
+        assert_eq!(r, f64x4::new(4.0, 3.0, 2.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_blend_ps() {
+        let a = f32x8::new(1.0, 4.0, 5.0, 8.0, 9.0, 12.0, 13.0, 16.0);
+        let b = f32x8::new(2.0, 3.0, 6.0, 7.0, 10.0, 11.0, 14.0, 15.0);
+        let r = avx::_mm256_blend_ps(a, b, 0x0);
+        assert_eq!(r, a);
+        let r = avx::_mm256_blend_ps(a, b, 0x3);
+        let e = f32x8::new(2.0, 3.0, 5.0, 8.0, 9.0, 12.0, 13.0, 16.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_blend_ps(a, b, 0xF0);
+        let e = f32x8::new(1.0, 4.0, 5.0, 8.0, 10.0, 11.0, 14.0, 15.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_blendv_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let c = f64x4::new(0.0, 0.0, -1.0, -0.0);
+        let r = avx::_mm256_blendv_pd(a, b, c);
+        let e = f64x4::new(4.0, 9.0, 2.0, 5.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_blendv_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let c = f32x8::new(0.0, 0.0, 0.0, 0.0, -1.0, -1.0, -1.0, -1.0);
+        let r = avx::_mm256_blendv_ps(a, b, c);
+        let e = f32x8::new(4.0, 9.0, 16.0, 25.0, 8.0, 9.0, 64.0, 50.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_div_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_div_pd(a, b);
+        let e = f64x4::new(1.0, 3.0, 8.0, 5.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_div_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_div_ps(a, b);
+        let e = f32x8::new(1.0, 3.0, 8.0, 5.0, 0.5, 1.0, 0.25, 0.5);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_dp_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_dp_ps(a, b, 0xFF);
+        let e = f32x8::new(
+            200.0, 200.0, 200.0, 200.0, 2387.0, 2387.0, 2387.0, 2387.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_dp_ps(a, b, 0x31);
+        let e = f32x8::new(43.0, 0.0, 0.0, 0.0, 113.0, 0.0, 0.0, 0.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_hadd_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_hadd_pd(a, b);
+        let e = f64x4::new(13.0, 7.0, 41.0, 7.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_hadd_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_hadd_ps(a, b);
+        let e = f32x8::new(13.0, 41.0, 7.0, 7.0, 13.0, 41.0, 17.0, 114.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_hsub_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_hsub_pd(a, b);
+        let e = f64x4::new(-5.0, 1.0, -9.0, -3.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_hsub_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_hsub_ps(a, b);
+        let e = f32x8::new(-5.0, -9.0, 1.0, -3.0, -5.0, -9.0, -1.0, 14.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_max_pd() {
+        let a = f64x4::new(1.0, 4.0, 5.0, 8.0);
+        let b = f64x4::new(2.0, 3.0, 6.0, 7.0);
+        let r = avx::_mm256_max_pd(a, b);
+        let e = f64x4::new(2.0, 4.0, 6.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_max_ps() {
+        let a = f32x8::new(1.0, 4.0, 5.0, 8.0, 9.0, 12.0, 13.0, 16.0);
+        let b = f32x8::new(2.0, 3.0, 6.0, 7.0, 10.0, 11.0, 14.0, 15.0);
+        let r = avx::_mm256_max_ps(a, b);
+        let e = f32x8::new(2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0, 16.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_min_pd() {
+        let a = f64x4::new(1.0, 4.0, 5.0, 8.0);
+        let b = f64x4::new(2.0, 3.0, 6.0, 7.0);
+        let r = avx::_mm256_min_pd(a, b);
+        let e = f64x4::new(1.0, 3.0, 5.0, 7.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_min_ps() {
+        let a = f32x8::new(1.0, 4.0, 5.0, 8.0, 9.0, 12.0, 13.0, 16.0);
+        let b = f32x8::new(2.0, 3.0, 6.0, 7.0, 10.0, 11.0, 14.0, 15.0);
+        let r = avx::_mm256_min_ps(a, b);
+        let e = f32x8::new(1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_mul_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_mul_pd(a, b);
+        let e = f64x4::new(5.0, 12.0, 21.0, 32.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_mul_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let r = avx::_mm256_mul_ps(a, b);
+        let e = f32x8::new(9.0, 20.0, 33.0, 48.0, 65.0, 84.0, 105.0, 128.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_or_pd() {
+        let a = f64x4::splat(1.0);
+        let b = f64x4::splat(-0.0);
+        let r = avx::_mm256_or_pd(a, b);
+        let e = f64x4::splat(-1.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_or_ps() {
+        let a = f32x8::splat(1.0);
+        let b = f32x8::splat(-0.0);
+        let r = avx::_mm256_or_ps(a, b);
+        let e = f32x8::splat(-1.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_shuffle_pd() {
+        let a = f64x4::new(1.0, 4.0, 5.0, 8.0);
+        let b = f64x4::new(2.0, 3.0, 6.0, 7.0);
+        let r = avx::_mm256_shuffle_pd(a, b, 0x0F);
+        let e = f64x4::new(4.0, 3.0, 8.0, 7.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_shuffle_pd(a, b, 0x06);
+        let e = f64x4::new(1.0, 3.0, 8.0, 6.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_shuffle_ps() {
+        let a = f32x8::new(1.0, 4.0, 5.0, 8.0, 9.0, 12.0, 13.0, 16.0);
+        let b = f32x8::new(2.0, 3.0, 6.0, 7.0, 10.0, 11.0, 14.0, 15.0);
+        let r = avx::_mm256_shuffle_ps(a, b, 0x0F);
+        let e = f32x8::new(8.0, 8.0, 2.0, 2.0, 16.0, 16.0, 10.0, 10.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_shuffle_ps(a, b, 0xE4);
+        let e = f32x8::new(1.0, 4.0, 6.0, 7.0, 9.0, 12.0, 14.0, 15.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_sub_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_sub_pd(a, b);
+        let e = f64x4::splat(-4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_sub_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, -1.0, -2.0, -3.0, -4.0);
+        let b = f32x8::new(5.0, 6.0, 7.0, 8.0, 3.0, 2.0, 1.0, 0.0);
+        let r = avx::_mm256_sub_ps(a, b);
+        let e = f32x8::new(-4.0, -4.0, -4.0, -4.0, -4.0, -4.0, -4.0, -4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_xor_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::splat(-0.0);
+        let r = avx::_mm256_xor_pd(a, b);
+        let e = f64x4::new(-4.0, -9.0, -16.0, -25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_xor_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let b = f32x8::splat(-0.0);
+        let r = avx::_mm256_xor_ps(a, b);
+        let e = f32x8::new(-4.0, -9.0, -16.0, -25.0, -4.0, -9.0, -16.0, -25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_cmp_pd() {
+        let a = f64x2::new(4.0, 9.0);
+        let b = f64x2::new(4.0, 3.0);
+        let r: u64x2 = unsafe {
+            transmute(avx::_mm_cmp_pd(a, b, avx::_CMP_GE_OS))
+        };
+        assert_eq!(r, u64x2::new(!0, !0));
+        let r: u64x2 = unsafe {
+            transmute(avx::_mm_cmp_pd(a, b, avx::_CMP_LT_OQ))
+        };
+        assert_eq!(r, u64x2::new(0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cmp_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let b = f64x4::new(4.0, 10.0, 2.0, 30.0);
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_LT_OQ))
+        };
+        assert_eq!(r, u64x4::new(0, !0, 0, !0));
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_LE_OS))
+        };
+        assert_eq!(r, u64x4::new(!0, !0, 0, !0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cmp_pd_unordered() {
+        let nan = ::std::f64::NAN;
+        let a = f64x4::new(nan, 1.0, nan, 2.0);
+        let b = f64x4::new(1.0, 1.0, nan, 3.0);
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_EQ_UQ))
+        };
+        assert_eq!(r, u64x4::new(!0, !0, !0, 0));
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_NEQ_OQ))
+        };
+        assert_eq!(r, u64x4::new(0, 0, 0, !0));
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_TRUE_UQ))
+        };
+        assert_eq!(r, u64x4::splat(!0));
+        let r: u64x4 = unsafe {
+            transmute(avx::_mm256_cmp_pd(a, b, avx::_CMP_FALSE_OS))
+        };
+        assert_eq!(r, u64x4::splat(0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_cmp_ps() {
+        let a = f32x4::new(4.0, 3.0, 2.0, 5.0);
+        let b = f32x4::new(4.0, 9.0, 16.0, 25.0);
+        let r: u32x4 = unsafe {
+            transmute(avx::_mm_cmp_ps(a, b, avx::_CMP_GE_OS))
+        };
+        assert_eq!(r, u32x4::new(!0, 0, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cmp_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let b = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let r: u32x8 = unsafe {
+            transmute(avx::_mm256_cmp_ps(a, b, avx::_CMP_GT_OQ))
+        };
+        assert_eq!(r, u32x8::new(0, 0, 0, 0, !0, 0, !0, !0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_cmp_sd() {
+        let a = f64x2::new(4.0, 9.0);
+        let b = f64x2::new(4.0, 3.0);
+        let r = avx::_mm_cmp_sd(a, b, avx::_CMP_EQ_OQ);
+        let m: u64x2 = unsafe { transmute(r) };
+        assert_eq!(m.extract(0), !0);
+        assert_eq!(r.extract(1), 9.0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_cmp_ss() {
+        let a = f32x4::new(4.0, 3.0, 2.0, 5.0);
+        let b = f32x4::new(4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm_cmp_ss(a, b, avx::_CMP_NEQ_UQ);
+        assert_eq!(r, f32x4::new(0.0, 3.0, 2.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtepi32_pd() {
+        let a = i32x4::new(4, 9, 16, 25);
+        let r = avx::_mm256_cvtepi32_pd(a);
+        let e = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtepi32_ps() {
+        let a = i32x8::new(4, 9, 16, 25, 4, 9, 16, 25);
+        let r = avx::_mm256_cvtepi32_ps(a);
+        let e = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtpd_ps() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm256_cvtpd_ps(a);
+        let e = f32x4::new(4.0, 9.0, 16.0, 25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtps_epi32() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 1.5, 2.5, -1.5, -2.7);
+        let r = avx::_mm256_cvtps_epi32(a);
+        let e = i32x8::new(4, 9, 16, 25, 2, 2, -2, -3);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtps_pd() {
+        let a = f32x4::new(4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm256_cvtps_pd(a);
+        let e = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvttpd_epi32() {
+        let a = f64x4::new(4.0, 9.5, -16.7, 25.9);
+        let r = avx::_mm256_cvttpd_epi32(a);
+        let e = i32x4::new(4, 9, -16, 25);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvtpd_epi32() {
+        let a = f64x4::new(4.0, 9.5, -16.7, 25.9);
+        let r = avx::_mm256_cvtpd_epi32(a);
+        let e = i32x4::new(4, 10, -17, 26);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_cvttps_epi32() {
+        let a = f32x8::new(4.0, 9.5, -16.7, 25.9, 1.5, 2.5, -1.5, -2.7);
+        let r = avx::_mm256_cvttps_epi32(a);
+        let e = i32x8::new(4, 9, -16, 25, 1, 2, -1, -2);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extractf128_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_extractf128_ps(a, 0);
+        assert_eq!(r, f32x4::new(4.0, 3.0, 2.0, 5.0));
+        let r = avx::_mm256_extractf128_ps(a, 1);
+        assert_eq!(r, f32x4::new(8.0, 9.0, 64.0, 50.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extractf128_pd() {
+        let a = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_extractf128_pd(a, 0);
+        assert_eq!(r, f64x2::new(4.0, 3.0));
+        let r = avx::_mm256_extractf128_pd(a, 1);
+        assert_eq!(r, f64x2::new(2.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extractf128_si256() {
+        let a = __m256i::from(i64x4::new(4, 3, 2, 5));
+        let r = avx::_mm256_extractf128_si256(a, 0);
+        assert_eq!(i64x2::from(r), i64x2::new(4, 3));
+        let r = avx::_mm256_extractf128_si256(a, 1);
+        assert_eq!(i64x2::from(r), i64x2::new(2, 5));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extract_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, -1);
+        assert_eq!(avx::_mm256_extract_epi8(a, 0), 0);
+        assert_eq!(avx::_mm256_extract_epi8(a, 20), 20);
+        assert_eq!(avx::_mm256_extract_epi8(a, 31), 0xFF);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extract_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, -1);
+        assert_eq!(avx::_mm256_extract_epi16(a, 0), 0);
+        assert_eq!(avx::_mm256_extract_epi16(a, 9), 9);
+        assert_eq!(avx::_mm256_extract_epi16(a, 15), 0xFFFF);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extract_epi32() {
+        let a = i32x8::new(0, 1, 2, 3, 4, 5, 6, -1);
+        assert_eq!(avx::_mm256_extract_epi32(a, 0), 0);
+        assert_eq!(avx::_mm256_extract_epi32(a, 5), 5);
+        assert_eq!(avx::_mm256_extract_epi32(a, 7), -1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_extract_epi64() {
+        let a = i64x4::new(0, 1, 2, -1);
+        assert_eq!(avx::_mm256_extract_epi64(a, 0), 0);
+        assert_eq!(avx::_mm256_extract_epi64(a, 3), -1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_zeroall() {
+        avx::_mm256_zeroall();
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_zeroupper() {
+        avx::_mm256_zeroupper();
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permutevar_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let b = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = avx::_mm256_permutevar_ps(a, b);
+        let e = f32x8::new(3.0, 2.0, 5.0, 4.0, 9.0, 64.0, 50.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_permutevar_ps() {
+        let a = f32x4::new(4.0, 3.0, 2.0, 5.0);
+        let b = i32x4::new(1, 2, 3, 4);
+        let r = avx::_mm_permutevar_ps(a, b);
+        let e = f32x4::new(3.0, 2.0, 5.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permute_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = avx::_mm256_permute_ps(a, 0x1b);
+        let e = f32x8::new(5.0, 2.0, 3.0, 4.0, 50.0, 64.0, 9.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_permute_ps() {
+        let a = f32x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm_permute_ps(a, 0x1b);
+        let e = f32x4::new(5.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permutevar_pd() {
+        let a = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let b = i64x4::new(1, 2, 3, 4);
+        let r = avx::_mm256_permutevar_pd(a, b);
+        let e = f64x4::new(4.0, 3.0, 5.0, 2.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_permutevar_pd() {
+        let a = f64x2::new(4.0, 3.0);
+        let b = i64x2::new(3, 0);
+        let r = avx::_mm_permutevar_pd(a, b);
+        let e = f64x2::new(3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permute_pd() {
+        let a = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_permute_pd(a, 5);
+        let e = f64x4::new(3.0, 4.0, 5.0, 2.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_permute_pd() {
+        let a = f64x2::new(4.0, 3.0);
+        let r = avx::_mm_permute_pd(a, 1);
+        let e = f64x2::new(3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permute2f128_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0);
+        let b = f32x8::new(5.0, 6.0, 7.0, 8.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_permute2f128_ps(a, b, 0x13);
+        let e = f32x8::new(5.0, 6.0, 7.0, 8.0, 1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_permute2f128_ps(a, b, 0x83);
+        let e = f32x8::new(5.0, 6.0, 7.0, 8.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permute2f128_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_permute2f128_pd(a, b, 0x31);
+        let e = f64x4::new(3.0, 4.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_permute2f128_si256() {
+        let a = __m256i::from(i32x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+        let b = __m256i::from(i32x8::new(9, 10, 11, 12, 13, 14, 15, 16));
+        let r = avx::_mm256_permute2f128_si256(a, b, 0x20);
+        let e = i32x8::new(1, 2, 3, 4, 9, 10, 11, 12);
+        assert_eq!(i32x8::from(r), e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_broadcast_ss() {
+        let r = avx::_mm256_broadcast_ss(&3.0);
+        assert_eq!(r, f32x8::splat(3.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_broadcast_ss() {
+        let r = avx::_mm_broadcast_ss(&3.0);
+        assert_eq!(r, f32x4::splat(3.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_broadcast_sd() {
+        let r = avx::_mm256_broadcast_sd(&3.0);
+        assert_eq!(r, f64x4::splat(3.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_broadcast_ps() {
+        let a = f32x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = avx::_mm256_broadcast_ps(&a);
+        let e = f32x8::new(4.0, 3.0, 2.0, 5.0, 4.0, 3.0, 2.0, 5.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_broadcast_pd() {
+        let a = f64x2::new(4.0, 3.0);
+        let r = avx::_mm256_broadcast_pd(&a);
+        let e = f64x4::new(4.0, 3.0, 4.0, 3.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insertf128_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let b = f32x4::new(4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm256_insertf128_ps(a, b, 0);
+        let e = f32x8::new(4.0, 9.0, 16.0, 25.0, 8.0, 9.0, 64.0, 50.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_insertf128_ps(a, b, 1);
+        let e = f32x8::new(4.0, 3.0, 2.0, 5.0, 4.0, 9.0, 16.0, 25.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insertf128_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x2::new(5.0, 6.0);
+        let r = avx::_mm256_insertf128_pd(a, b, 0);
+        let e = f64x4::new(5.0, 6.0, 3.0, 4.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_insertf128_pd(a, b, 1);
+        let e = f64x4::new(1.0, 2.0, 5.0, 6.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insertf128_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m128i::from(i64x2::new(5, 6));
+        let r = avx::_mm256_insertf128_si256(a, b, 0);
+        assert_eq!(i64x4::from(r), i64x4::new(5, 6, 3, 4));
+        let r = avx::_mm256_insertf128_si256(a, b, 1);
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 5, 6));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insert_epi8() {
+        let a = i8x32::splat(1);
+        let r = avx::_mm256_insert_epi8(a, 0, 31);
+        assert_eq!(r, i8x32::splat(1).replace(31, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insert_epi16() {
+        let a = i16x16::splat(1);
+        let r = avx::_mm256_insert_epi16(a, 0, 15);
+        assert_eq!(r, i16x16::splat(1).replace(15, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insert_epi32() {
+        let a = i32x8::splat(1);
+        let r = avx::_mm256_insert_epi32(a, 0, 7);
+        assert_eq!(r, i32x8::new(1, 1, 1, 1, 1, 1, 1, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_insert_epi64() {
+        let a = i64x4::splat(1);
+        let r = avx::_mm256_insert_epi64(a, 0, 3);
+        assert_eq!(r, i64x4::new(1, 1, 1, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_load_pd() {
+        let a = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let r = unsafe {
+            avx::_mm256_load_pd(&a as *const f64x4 as *const f64)
+        };
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_store_pd() {
+        let a = f64x4::new(4.0, 3.0, 2.0, 5.0);
+        let mut r = f64x4::splat(0.0);
+        unsafe {
+            avx::_mm256_store_pd(&mut r as *mut f64x4 as *mut f64, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_load_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let r = unsafe {
+            avx::_mm256_load_ps(&a as *const f32x8 as *const f32)
+        };
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_store_ps() {
+        let a = f32x8::new(4.0, 3.0, 2.0, 5.0, 8.0, 9.0, 64.0, 50.0);
+        let mut r = f32x8::splat(0.0);
+        unsafe {
+            avx::_mm256_store_ps(&mut r as *mut f32x8 as *mut f32, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu_pd() {
+        let a = [0.0, 1.0, 2.0, 3.0, 4.0f64];
+        let r = unsafe { avx::_mm256_loadu_pd(a[1..].as_ptr()) };
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let mut r = [0.0f64; 5];
+        unsafe {
+            avx::_mm256_storeu_pd(r[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(r, [0.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu_ps() {
+        let a = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0f32];
+        let r = unsafe { avx::_mm256_loadu_ps(a[1..].as_ptr()) };
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let mut r = [0.0f32; 9];
+        unsafe {
+            avx::_mm256_storeu_ps(r[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(r, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_load_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let r = unsafe { avx::_mm256_load_si256(&a as *const __m256i) };
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_store_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let mut r = avx::_mm256_setzero_si256();
+        unsafe {
+            avx::_mm256_store_si256(&mut r as *mut __m256i, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu_si256() {
+        let a = [0i64, 1, 2, 3, 4];
+        let r = unsafe {
+            avx::_mm256_loadu_si256(a[1..].as_ptr() as *const __m256i)
+        };
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let mut r = [0i64; 5];
+        unsafe {
+            avx::_mm256_storeu_si256(r[1..].as_mut_ptr() as *mut __m256i, a);
+        }
+        assert_eq!(r, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_maskload_pd() {
+        let a = [1.0, 2.0, 3.0, 4.0f64];
+        let mask = i64x4::new(0, -1, 0, -1);
+        let r = unsafe { avx::_mm256_maskload_pd(a.as_ptr(), mask) };
+        assert_eq!(r, f64x4::new(0.0, 2.0, 0.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_maskstore_pd() {
+        let mut r = [0.0f64; 4];
+        let mask = i64x4::new(0, -1, 0, -1);
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            avx::_mm256_maskstore_pd(r.as_mut_ptr(), mask, a);
+        }
+        assert_eq!(r, [0.0, 2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_maskload_pd() {
+        let a = [1.0, 2.0f64];
+        let mask = i64x2::new(0, -1);
+        let r = unsafe { avx::_mm_maskload_pd(a.as_ptr(), mask) };
+        assert_eq!(r, f64x2::new(0.0, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_maskstore_pd() {
+        let mut r = [0.0f64; 2];
+        let mask = i64x2::new(0, -1);
+        let a = f64x2::new(1.0, 2.0);
+        unsafe {
+            avx::_mm_maskstore_pd(r.as_mut_ptr(), mask, a);
+        }
+        assert_eq!(r, [0.0, 2.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_maskload_ps() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0f32];
+        let mask = i32x8::new(0, -1, 0, -1, 0, -1, 0, -1);
+        let r = unsafe { avx::_mm256_maskload_ps(a.as_ptr(), mask) };
+        let e = f32x8::new(0.0, 2.0, 0.0, 4.0, 0.0, 6.0, 0.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_maskstore_ps() {
+        let mut r = [0.0f32; 8];
+        let mask = i32x8::new(0, -1, 0, -1, 0, -1, 0, -1);
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        unsafe {
+            avx::_mm256_maskstore_ps(r.as_mut_ptr(), mask, a);
+        }
+        assert_eq!(r, [0.0, 2.0, 0.0, 4.0, 0.0, 6.0, 0.0, 8.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_maskload_ps() {
+        let a = [1.0, 2.0, 3.0, 4.0f32];
+        let mask = i32x4::new(0, -1, 0, -1);
+        let r = unsafe { avx::_mm_maskload_ps(a.as_ptr(), mask) };
+        assert_eq!(r, f32x4::new(0.0, 2.0, 0.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_maskstore_ps() {
+        let mut r = [0.0f32; 4];
+        let mask = i32x4::new(0, -1, 0, -1);
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            avx::_mm_maskstore_ps(r.as_mut_ptr(), mask, a);
+        }
+        assert_eq!(r, [0.0, 2.0, 0.0, 4.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_movehdup_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_movehdup_ps(a);
+        let e = f32x8::new(2.0, 2.0, 4.0, 4.0, 6.0, 6.0, 8.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_moveldup_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_moveldup_ps(a);
+        let e = f32x8::new(1.0, 1.0, 3.0, 3.0, 5.0, 5.0, 7.0, 7.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_movedup_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_movedup_pd(a);
+        let e = f64x4::new(1.0, 1.0, 3.0, 3.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_lddqu_si256() {
+        let a = [0i8, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+                 32];
+        let r = unsafe {
+            avx::_mm256_lddqu_si256(a[1..].as_ptr() as *const __m256i)
+        };
+        let e = i8x32::new(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_stream_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let mut r = avx::_mm256_undefined_si256();
+        unsafe {
+            avx::_mm256_stream_si256(&mut r as *mut __m256i, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_stream_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let mut r = avx::_mm256_undefined_pd();
+        unsafe {
+            avx::_mm256_stream_pd(&mut r as *mut __m256d as *mut f64, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_stream_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let mut r = avx::_mm256_undefined_ps();
+        unsafe {
+            avx::_mm256_stream_ps(&mut r as *mut __m256 as *mut f32, a);
+        }
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_rcp_ps() {
+        let a = f32x8::new(4.0, 13.0, 16.0, 100.0, 4.0, 13.0, 16.0, 100.0);
+        let r = avx::_mm256_rcp_ps(a);
+        let e = f32x8::new(
+            0.24993896, 0.0769043, 0.06248474, 0.0099983215,
+            0.24993896, 0.0769043, 0.06248474, 0.0099983215);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_rsqrt_ps() {
+        let a = f32x8::new(4.0, 13.0, 16.0, 100.0, 4.0, 13.0, 16.0, 100.0);
+        let r = avx::_mm256_rsqrt_ps(a);
+        let e = f32x8::new(
+            0.49987793, 0.2772827, 0.24993896, 0.099990845,
+            0.49987793, 0.2772827, 0.24993896, 0.099990845);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_sqrt_pd() {
+        let a = f64x4::new(4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm256_sqrt_pd(a);
+        let e = f64x4::new(2.0, 3.0, 4.0, 5.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_sqrt_ps() {
+        let a = f32x8::new(4.0, 9.0, 16.0, 25.0, 4.0, 9.0, 16.0, 25.0);
+        let r = avx::_mm256_sqrt_ps(a);
+        let e = f32x8::new(2.0, 3.0, 4.0, 5.0, 2.0, 3.0, 4.0, 5.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_round_pd() {
+        use x86::{_MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_ZERO};
+
+        let a = f64x4::new(1.55, 2.2, 3.99, -1.2);
+        let r = avx::_mm256_round_pd(a, _MM_FROUND_TO_NEAREST_INT);
+        assert_eq!(r, f64x4::new(2.0, 2.0, 4.0, -1.0));
+        let r = avx::_mm256_round_pd(a, _MM_FROUND_TO_ZERO);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, -1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_round_ps() {
+        use x86::{_MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_ZERO};
+
+        let a = f32x8::new(1.55, 2.2, 3.99, -1.2, 2.5, -2.5, 0.5, -3.7);
+        let r = avx::_mm256_round_ps(a, _MM_FROUND_TO_NEAREST_INT);
+        let e = f32x8::new(2.0, 2.0, 4.0, -1.0, 2.0, -2.0, 0.0, -4.0);
+        assert_eq!(r, e);
+        let r = avx::_mm256_round_ps(a, _MM_FROUND_TO_ZERO);
+        let e = f32x8::new(1.0, 2.0, 3.0, -1.0, 2.0, -2.0, 0.0, -3.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_ceil_pd() {
+        let a = f64x4::new(1.55, 2.2, 3.99, -1.2);
+        let r = avx::_mm256_ceil_pd(a);
+        assert_eq!(r, f64x4::new(2.0, 3.0, 4.0, -1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_floor_pd() {
+        let a = f64x4::new(1.55, 2.2, 3.99, -1.2);
+        let r = avx::_mm256_floor_pd(a);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, -2.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_ceil_ps() {
+        let a = f32x8::new(1.55, 2.2, 3.99, -1.2, 1.0, -0.5, 7.01, -7.01);
+        let r = avx::_mm256_ceil_ps(a);
+        let e = f32x8::new(2.0, 3.0, 4.0, -1.0, 1.0, -0.0, 8.0, -7.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_floor_ps() {
+        let a = f32x8::new(1.55, 2.2, 3.99, -1.2, 1.0, -0.5, 7.01, -7.01);
+        let r = avx::_mm256_floor_ps(a);
+        let e = f32x8::new(1.0, 2.0, 3.0, -2.0, 1.0, -1.0, 7.0, -8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_unpackhi_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_unpackhi_pd(a, b);
+        let e = f64x4::new(2.0, 6.0, 4.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_unpackhi_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let r = avx::_mm256_unpackhi_ps(a, b);
+        let e = f32x8::new(3.0, 11.0, 4.0, 12.0, 7.0, 15.0, 8.0, 16.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_unpacklo_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_unpacklo_pd(a, b);
+        let e = f64x4::new(1.0, 5.0, 3.0, 7.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_unpacklo_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0);
+        let r = avx::_mm256_unpacklo_ps(a, b);
+        let e = f32x8::new(1.0, 9.0, 2.0, 10.0, 5.0, 13.0, 6.0, 14.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testz_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m256i::from(i64x4::new(5, 6, 7, 8));
+        assert_eq!(avx::_mm256_testz_si256(a, b), 0);
+        let b = avx::_mm256_setzero_si256();
+        assert_eq!(avx::_mm256_testz_si256(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testc_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m256i::from(i64x4::new(5, 6, 7, 8));
+        assert_eq!(avx::_mm256_testc_si256(a, b), 0);
+        let b = __m256i::from(i64x4::new(1, 2, 1, 4));
+        assert_eq!(avx::_mm256_testc_si256(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testnzc_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m256i::from(i64x4::new(5, 6, 7, 8));
+        assert_eq!(avx::_mm256_testnzc_si256(a, b), 1);
+        let a = avx::_mm256_setzero_si256();
+        assert_eq!(avx::_mm256_testnzc_si256(a, b), 0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testz_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testz_pd(a, b), 1);
+        let a = f64x4::splat(-1.0);
+        assert_eq!(avx::_mm256_testz_pd(a, b), 0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testc_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testc_pd(a, b), 0);
+        let b = f64x4::splat(1.0);
+        assert_eq!(avx::_mm256_testc_pd(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testnzc_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f64x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testnzc_pd(a, b), 0);
+        let a = f64x4::new(1.0, -2.0, 3.0, 4.0);
+        assert_eq!(avx::_mm256_testnzc_pd(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testz_pd() {
+        let a = f64x2::new(1.0, 2.0);
+        let b = f64x2::new(5.0, -6.0);
+        assert_eq!(avx::_mm_testz_pd(a, b), 1);
+        let a = f64x2::splat(-1.0);
+        assert_eq!(avx::_mm_testz_pd(a, b), 0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testc_pd() {
+        let a = f64x2::new(1.0, 2.0);
+        let b = f64x2::new(5.0, -6.0);
+        assert_eq!(avx::_mm_testc_pd(a, b), 0);
+        let b = f64x2::splat(1.0);
+        assert_eq!(avx::_mm_testc_pd(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testnzc_pd() {
+        let a = f64x2::new(1.0, 2.0);
+        let b = f64x2::new(5.0, -6.0);
+        assert_eq!(avx::_mm_testnzc_pd(a, b), 0);
+        let a = f64x2::new(-1.0, 2.0);
+        let b = f64x2::new(-5.0, -6.0);
+        assert_eq!(avx::_mm_testnzc_pd(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testz_ps() {
+        let a = f32x8::splat(1.0);
+        let b = f32x8::new(5.0, -6.0, 7.0, -8.0, 5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testz_ps(a, b), 1);
+        let a = f32x8::splat(-1.0);
+        assert_eq!(avx::_mm256_testz_ps(a, b), 0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testc_ps() {
+        let a = f32x8::splat(1.0);
+        let b = f32x8::new(5.0, -6.0, 7.0, -8.0, 5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testc_ps(a, b), 0);
+        let b = f32x8::splat(1.0);
+        assert_eq!(avx::_mm256_testc_ps(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_testnzc_ps() {
+        let a = f32x8::splat(1.0);
+        let b = f32x8::new(5.0, -6.0, 7.0, -8.0, 5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm256_testnzc_ps(a, b), 0);
+        let a = f32x8::new(1.0, -1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+        assert_eq!(avx::_mm256_testnzc_ps(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testz_ps() {
+        let a = f32x4::splat(1.0);
+        let b = f32x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm_testz_ps(a, b), 1);
+        let a = f32x4::splat(-1.0);
+        assert_eq!(avx::_mm_testz_ps(a, b), 0);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testc_ps() {
+        let a = f32x4::splat(1.0);
+        let b = f32x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm_testc_ps(a, b), 0);
+        let b = f32x4::splat(1.0);
+        assert_eq!(avx::_mm_testc_ps(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm_testnzc_ps() {
+        let a = f32x4::splat(1.0);
+        let b = f32x4::new(5.0, -6.0, 7.0, -8.0);
+        assert_eq!(avx::_mm_testnzc_ps(a, b), 0);
+        let a = f32x4::new(1.0, -1.0, 1.0, 1.0);
+        assert_eq!(avx::_mm_testnzc_ps(a, b), 1);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_movemask_pd() {
+        let a = f64x4::new(1.0, -2.0, 3.0, -4.0);
+        let r = avx::_mm256_movemask_pd(a);
+        assert_eq!(r, 0b1010);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_movemask_ps() {
+        let a = f32x8::new(1.0, -2.0, 3.0, -4.0, 1.0, -2.0, 3.0, -4.0);
+        let r = avx::_mm256_movemask_ps(a);
+        assert_eq!(r, 0b1010_1010);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setzero_pd() {
+        assert_eq!(avx::_mm256_setzero_pd(), f64x4::splat(0.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setzero_ps() {
+        assert_eq!(avx::_mm256_setzero_ps(), f32x8::splat(0.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setzero_si256() {
+        let r = avx::_mm256_setzero_si256();
+        assert_eq!(i64x4::from(r), i64x4::splat(0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_pd() {
+        let r = avx::_mm256_set_pd(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, f64x4::new(4.0, 3.0, 2.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_ps() {
+        let r = avx::_mm256_set_ps(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let e = f32x8::new(8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_epi8() {
+        let r = avx::_mm256_set_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+        let e = i8x32::new(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17,
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_epi16() {
+        let r = avx::_mm256_set_epi16(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let e = i16x16::new(
+            16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_epi32() {
+        let r = avx::_mm256_set_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(r, i32x8::new(8, 7, 6, 5, 4, 3, 2, 1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_epi64x() {
+        let r = avx::_mm256_set_epi64x(1, 2, 3, 4);
+        assert_eq!(r, i64x4::new(4, 3, 2, 1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_pd() {
+        let r = avx::_mm256_setr_pd(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_ps() {
+        let r = avx::_mm256_setr_ps(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_epi8() {
+        let r = avx::_mm256_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+        let e = i8x32::new(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_epi16() {
+        let r = avx::_mm256_setr_epi16(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        let e = i16x16::new(
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_epi32() {
+        let r = avx::_mm256_setr_epi32(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(r, i32x8::new(1, 2, 3, 4, 5, 6, 7, 8));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_epi64x() {
+        let r = avx::_mm256_setr_epi64x(1, 2, 3, 4);
+        assert_eq!(r, i64x4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_pd() {
+        assert_eq!(avx::_mm256_set1_pd(1.0), f64x4::splat(1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_ps() {
+        assert_eq!(avx::_mm256_set1_ps(1.0), f32x8::splat(1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_epi8() {
+        assert_eq!(avx::_mm256_set1_epi8(1), i8x32::splat(1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_epi16() {
+        assert_eq!(avx::_mm256_set1_epi16(1), i16x16::splat(1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_epi32() {
+        assert_eq!(avx::_mm256_set1_epi32(1), i32x8::splat(1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set1_epi64x() {
+        assert_eq!(avx::_mm256_set1_epi64x(1), i64x4::splat(1));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castpd_ps() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_castpd_ps(a);
+        let e = f32x8::new(0.0, 1.875, 0.0, 2.0, 0.0, 2.125, 0.0, 2.25);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castps_pd() {
+        let a = f32x8::new(0.0, 1.875, 0.0, 2.0, 0.0, 2.125, 0.0, 2.25);
+        let r = avx::_mm256_castps_pd(a);
+        let e = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castps_si256() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_castps_si256(a);
+        let e = i32x8::new(
+            0x3f80_0000, 0x4000_0000, 0x4040_0000, 0x4080_0000,
+            0x40a0_0000, 0x40c0_0000, 0x40e0_0000, 0x4100_0000);
+        assert_eq!(i32x8::from(r), e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castpd_si256() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_castpd_si256(a);
+        let e = i64x4::new(
+            0x3ff0_0000_0000_0000, 0x4000_0000_0000_0000,
+            0x4008_0000_0000_0000, 0x4010_0000_0000_0000);
+        assert_eq!(i64x4::from(r), e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castsi256_ps() {
+        let a = __m256i::from(i32x8::new(
+            0x3f80_0000, 0x4000_0000, 0x4040_0000, 0x4080_0000,
+            0x40a0_0000, 0x40c0_0000, 0x40e0_0000, 0x4100_0000));
+        let r = avx::_mm256_castsi256_ps(a);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castsi256_pd() {
+        let a = __m256i::from(i64x4::new(
+            0x3ff0_0000_0000_0000, 0x4000_0000_0000_0000,
+            0x4008_0000_0000_0000, 0x4010_0000_0000_0000));
+        let r = avx::_mm256_castsi256_pd(a);
+        let e = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castps256_ps128() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_castps256_ps128(a);
+        assert_eq!(r, f32x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castpd256_pd128() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_castpd256_pd128(a);
+        assert_eq!(r, f64x2::new(1.0, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castsi256_si128() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let r = avx::_mm256_castsi256_si128(a);
+        assert_eq!(i64x2::from(r), i64x2::new(1, 2));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castps128_ps256() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_castps128_ps256(a);
+        assert_eq!(avx::_mm256_castps256_ps128(r), a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castpd128_pd256() {
+        let a = f64x2::new(1.0, 2.0);
+        let r = avx::_mm256_castpd128_pd256(a);
+        assert_eq!(avx::_mm256_castpd256_pd128(r), a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_castsi128_si256() {
+        let a = __m128i::from(i64x2::new(1, 2));
+        let r = avx::_mm256_castsi128_si256(a);
+        assert_eq!(avx::_mm256_castsi256_si128(r), a);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_zextps128_ps256() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_zextps128_ps256(a);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_zextpd128_pd256() {
+        let a = f64x2::new(1.0, 2.0);
+        let r = avx::_mm256_zextpd128_pd256(a);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_zextsi128_si256() {
+        let a = __m128i::from(i64x2::new(1, 2));
+        let r = avx::_mm256_zextsi128_si256(a);
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_undefined_ps() {
+        let _: __m256 = avx::_mm256_undefined_ps();
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_undefined_pd() {
+        let _: __m256d = avx::_mm256_undefined_pd();
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_undefined_si256() {
+        let _: __m256i = avx::_mm256_undefined_si256();
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_m128() {
+        let hi = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let lo = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx::_mm256_set_m128(hi, lo);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_m128d() {
+        let hi = f64x2::new(3.0, 4.0);
+        let lo = f64x2::new(1.0, 2.0);
+        let r = avx::_mm256_set_m128d(hi, lo);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_set_m128i() {
+        let hi = __m128i::from(i64x2::new(3, 4));
+        let lo = __m128i::from(i64x2::new(1, 2));
+        let r = avx::_mm256_set_m128i(hi, lo);
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_m128() {
+        let lo = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let hi = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = avx::_mm256_setr_m128(lo, hi);
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_m128d() {
+        let lo = f64x2::new(1.0, 2.0);
+        let hi = f64x2::new(3.0, 4.0);
+        let r = avx::_mm256_setr_m128d(lo, hi);
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_setr_m128i() {
+        let lo = __m128i::from(i64x2::new(1, 2));
+        let hi = __m128i::from(i64x2::new(3, 4));
+        let r = avx::_mm256_setr_m128i(lo, hi);
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu2_m128() {
+        let hi = [5.0, 6.0, 7.0, 8.0f32];
+        let lo = [1.0, 2.0, 3.0, 4.0f32];
+        let r = unsafe { avx::_mm256_loadu2_m128(hi.as_ptr(), lo.as_ptr()) };
+        let e = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu2_m128d() {
+        let hi = [3.0, 4.0f64];
+        let lo = [1.0, 2.0f64];
+        let r = unsafe { avx::_mm256_loadu2_m128d(hi.as_ptr(), lo.as_ptr()) };
+        assert_eq!(r, f64x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_loadu2_m128i() {
+        let hi = [3i64, 4];
+        let lo = [1i64, 2];
+        let r = unsafe {
+            avx::_mm256_loadu2_m128i(
+                hi.as_ptr() as *const __m128i, lo.as_ptr() as *const __m128i)
+        };
+        assert_eq!(i64x4::from(r), i64x4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu2_m128() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let mut hi = [0.0f32; 4];
+        let mut lo = [0.0f32; 4];
+        unsafe {
+            avx::_mm256_storeu2_m128(hi.as_mut_ptr(), lo.as_mut_ptr(), a);
+        }
+        assert_eq!(hi, [5.0, 6.0, 7.0, 8.0]);
+        assert_eq!(lo, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu2_m128d() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let mut hi = [0.0f64; 2];
+        let mut lo = [0.0f64; 2];
+        unsafe {
+            avx::_mm256_storeu2_m128d(hi.as_mut_ptr(), lo.as_mut_ptr(), a);
+        }
+        assert_eq!(hi, [3.0, 4.0]);
+        assert_eq!(lo, [1.0, 2.0]);
+    }
+
+    #[test]
+    #[target_feature = "+avx"]
+    fn _mm256_storeu2_m128i() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let mut hi = [0i64; 2];
+        let mut lo = [0i64; 2];
+        unsafe {
+            avx::_mm256_storeu2_m128i(
+                hi.as_mut_ptr() as *mut __m128i,
+                lo.as_mut_ptr() as *mut __m128i,
+                a);
+        }
+        assert_eq!(hi, [3, 4]);
+        assert_eq!(lo, [1, 2]);
+    }
+}
@@ -1,6 +1,11 @@
+use simd_llvm::{simd_cast, simd_shuffle2, simd_shuffle4, simd_shuffle8};
+use simd_llvm::{simd_shuffle16, simd_shuffle32};
 use v256::*;
 use v128::*;
-use x86::__m256i;
+use v64::{i8x8, u8x8};
+use x86::{__m128, __m128i, __m256, __m256d, __m256i};
+use x86::{_mm_cvtepi8_epi32, _mm_cvtepi16_epi32};
+use x86::{_mm_cvtepu8_epi32, _mm_cvtepu16_epi32};
 
 /// Computes the absolute values of packed 32-bit integers in `a`.
 #[inline(always)]
@@ -79,7 +84,70 @@ pub fn _mm256_adds_epu16(a: u16x16, b: u16x16) -> u16x16 {
     unsafe { paddusw(a, b) }
 }
 
-// TODO _mm256_alignr_epi8
+/// Concatenate pairs of 16-byte blocks in `a` and `b` into a 32-byte
+/// temporary result, shift the result right by `n` bytes, and return the low
+/// 16 bytes.
+///
+/// The two 128-bit lanes are processed independently. Shifts of more than 32
+/// bytes return zero.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_alignr_epi8(a: i8x32, b: i8x32, n: i32) -> i8x32 {
+    let n = n as u32 & 0xFF;
+
+    // If `palignr` is shifting the pair of vectors more than the size of two
+    // lanes, emit zero.
+    if n > 32 {
+        return i8x32::splat(0);
+    }
+    // If `palignr` is shifting the pair of input vectors more than one lane,
+    // but less than two lanes, convert to shifting in zeroes.
+    let (a, b, n) = if n > 16 {
+        (i8x32::splat(0), a, n - 16)
+    } else {
+        (a, b, n)
+    };
+    // Bytes shifted past the end of a lane of `b` come from the same lane of
+    // `a`, which starts 32 elements further in the concatenation.
+    const fn index(i: u32, n: u32) -> u32 {
+        i + n + 16 * ((i % 16 + n) / 16)
+    }
+    macro_rules! shuffle {
+        ($shift:expr) => {
+            unsafe {
+                simd_shuffle32::<i8x32, i8x32>(b, a, [
+                    index(0, $shift), index(1, $shift),
+                    index(2, $shift), index(3, $shift),
+                    index(4, $shift), index(5, $shift),
+                    index(6, $shift), index(7, $shift),
+                    index(8, $shift), index(9, $shift),
+                    index(10, $shift), index(11, $shift),
+                    index(12, $shift), index(13, $shift),
+                    index(14, $shift), index(15, $shift),
+                    index(16, $shift), index(17, $shift),
+                    index(18, $shift), index(19, $shift),
+                    index(20, $shift), index(21, $shift),
+                    index(22, $shift), index(23, $shift),
+                    index(24, $shift), index(25, $shift),
+                    index(26, $shift), index(27, $shift),
+                    index(28, $shift), index(29, $shift),
+                    index(30, $shift), index(31, $shift),
+                ])
+            }
+        }
+    }
+    match n {
+        0 => shuffle!(0), 1 => shuffle!(1),
+        2 => shuffle!(2), 3 => shuffle!(3),
+        4 => shuffle!(4), 5 => shuffle!(5),
+        6 => shuffle!(6), 7 => shuffle!(7),
+        8 => shuffle!(8), 9 => shuffle!(9),
+        10 => shuffle!(10), 11 => shuffle!(11),
+        12 => shuffle!(12), 13 => shuffle!(13),
+        14 => shuffle!(14), 15 => shuffle!(15),
+        _ => shuffle!(16),
+    }
+}
 
 /// Compute the bitwise AND of 256 bits (representing integer data)
 /// in `a` and `b`.
@@ -111,9 +179,89 @@ pub fn _mm256_avg_epu8 (a: u8x32, b: u8x32) -> u8x32 {
     unsafe { pavgb(a, b) }
 }
 
-// TODO _mm256_blend_epi16
-// TODO _mm_blend_epi32
-// TODO _mm256_blend_epi32
+/// Blend packed 16-bit integers from `a` and `b` using the mask `imm8`.
+///
+/// The same eight mask bits select the elements of both 128-bit lanes. A
+/// clear bit selects the corresponding element of `a`, and a set bit the
+/// corresponding element of `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_blend_epi16(a: i16x16, b: i16x16, imm8: i32) -> i16x16 {
+    macro_rules! blend {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle16(a, b, [
+                    blend_index!($imm8, 0, 16),
+                    blend_index!($imm8, 1, 16),
+                    blend_index!($imm8, 2, 16),
+                    blend_index!($imm8, 3, 16),
+                    blend_index!($imm8, 4, 16),
+                    blend_index!($imm8, 5, 16),
+                    blend_index!($imm8, 6, 16),
+                    blend_index!($imm8, 7, 16),
+                    8 + blend_index!($imm8, 0, 16),
+                    8 + blend_index!($imm8, 1, 16),
+                    8 + blend_index!($imm8, 2, 16),
+                    8 + blend_index!($imm8, 3, 16),
+                    8 + blend_index!($imm8, 4, 16),
+                    8 + blend_index!($imm8, 5, 16),
+                    8 + blend_index!($imm8, 6, 16),
+                    8 + blend_index!($imm8, 7, 16),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, blend)
+}
+
+/// Blend packed 32-bit integers from `a` and `b` using the mask `imm8`.
+///
+/// Only the low four bits of `imm8` are used. A clear bit selects the
+/// corresponding element of `a`, and a set bit the corresponding element
+/// of `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_blend_epi32(a: i32x4, b: i32x4, imm8: i32) -> i32x4 {
+    macro_rules! blend {
+        ($imm4:expr) => {
+            unsafe {
+                simd_shuffle4(a, b, [
+                    blend_index!($imm4, 0, 4),
+                    blend_index!($imm4, 1, 4),
+                    blend_index!($imm4, 2, 4),
+                    blend_index!($imm4, 3, 4),
+                ])
+            }
+        }
+    }
+    constify_imm4!(imm8, blend)
+}
+
+/// Blend packed 32-bit integers from `a` and `b` using the mask `imm8`.
+///
+/// A clear bit selects the corresponding element of `a`, and a set bit the
+/// corresponding element of `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_blend_epi32(a: i32x8, b: i32x8, imm8: i32) -> i32x8 {
+    macro_rules! blend {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, b, [
+                    blend_index!($imm8, 0, 8),
+                    blend_index!($imm8, 1, 8),
+                    blend_index!($imm8, 2, 8),
+                    blend_index!($imm8, 3, 8),
+                    blend_index!($imm8, 4, 8),
+                    blend_index!($imm8, 5, 8),
+                    blend_index!($imm8, 6, 8),
+                    blend_index!($imm8, 7, 8),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, blend)
+}
 
 /// Blend packed 8-bit integers from `a` and `b` using `mask`.
 #[inline(always)]
@@ -122,813 +270,2176 @@ pub fn _mm256_blendv_epi8(a:i8x32,b:i8x32,mask:__m256i) -> i8x32 {
     unsafe { pblendvb(a,b,mask) }
 }
 
-// TODO _mm_broadcastb_epi8
-// TODO _mm256_broadcastb_epi8
-// TODO _mm_broadcastd_epi32
-// TODO _mm256_broadcastd_epi32
-// TODO _mm_broadcastq_epi64
-// TODO _mm256_broadcastq_epi64
-// TODO _mm_broadcastsd_pd
-// TODO _mm256_broadcastsd_pd
-// TODO _mm_broadcastsi128_si256
-// TODO _mm256_broadcastsi128_si256
-// TODO _mm_broadcastss_ps
-// TODO _mm256_broadcastss_ps
-// TODO _mm_broadcastw_epi16
-// TODO _mm256_broadcastw_epi16
-// TODO _mm256_bslli_epi128
-// TODO _mm256_bsrli_epi128
-
-
-/// Compare packed 64-bit integers in `a` and `b` for equality.
+/// Broadcast the low packed 8-bit integer from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpeq_epi64(a: i64x4, b: i64x4) -> i64x4 {
-    a.eq(b)
+pub fn _mm_broadcastb_epi8(a: i8x16) -> i8x16 {
+    unsafe { simd_shuffle16(a, a, [0; 16]) }
 }
 
-/// Compare packed 32-bit integers in `a` and `b` for equality.
+/// Broadcast the low packed 8-bit integer from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpeq_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    a.eq(b)
+pub fn _mm256_broadcastb_epi8(a: i8x16) -> i8x32 {
+    unsafe { simd_shuffle32(a, a, [0; 32]) }
 }
 
-/// Compare packed 16-bit integers in `a` and `b` for equality.
+/// Broadcast the low packed 32-bit integer from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpeq_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    a.eq(b)
+pub fn _mm_broadcastd_epi32(a: i32x4) -> i32x4 {
+    unsafe { simd_shuffle4(a, a, [0; 4]) }
 }
 
-/// Compare packed 8-bit integers in `a` and `b` for equality.
+/// Broadcast the low packed 32-bit integer from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpeq_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    a.eq(b)
+pub fn _mm256_broadcastd_epi32(a: i32x4) -> i32x8 {
+    unsafe { simd_shuffle8(a, a, [0; 8]) }
 }
 
-/// Compare packed 64-bit integers in `a` and `b` for greater-than.
+/// Broadcast the low packed 64-bit integer from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpgt_epi64(a: i64x4, b: i64x4) -> i64x4 {
-    a.gt(b)
+pub fn _mm_broadcastq_epi64(a: i64x2) -> i64x2 {
+    unsafe { simd_shuffle2(a, a, [0; 2]) }
 }
 
-/// Compare packed 32-bit integers in `a` and `b` for greater-than.
+/// Broadcast the low packed 64-bit integer from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpgt_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    a.gt(b)
+pub fn _mm256_broadcastq_epi64(a: i64x2) -> i64x4 {
+    unsafe { simd_shuffle4(a, a, [0; 4]) }
 }
 
-/// Compare packed 16-bit integers in `a` and `b` for greater-than.
+/// Broadcast the low double-precision (64-bit) floating-point
+/// element from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpgt_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    a.gt(b)
+pub fn _mm_broadcastsd_pd(a: f64x2) -> f64x2 {
+    unsafe { simd_shuffle2(a, a, [0; 2]) }
 }
 
-/// Compare packed 8-bit integers in `a` and `b` for greater-than.
+/// Broadcast the low double-precision (64-bit) floating-point
+/// element from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_cmpgt_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    a.gt(b)
-}
-
-// TODO _mm256_cvtepi16_epi32
-// TODO _mm256_cvtepi16_epi64
-// TODO _mm256_cvtepi32_epi64
-// TODO _mm256_cvtepi8_epi16
-// TODO _mm256_cvtepi8_epi32
-// TODO _mm256_cvtepi8_epi64
-// TODO _mm256_cvtepu16_epi32
-// TODO _mm256_cvtepu16_epi64
-// TODO _mm256_cvtepu32_epi64
-// TODO _mm256_cvtepu8_epi16
-// TODO _mm256_cvtepu8_epi32
-// TODO _mm256_cvtepu8_epi64
-// TODO _m128i _mm256_extracti128_si256
+pub fn _mm256_broadcastsd_pd(a: f64x2) -> __m256d {
+    unsafe { simd_shuffle4(a, a, [0; 4]) }
+}
 
-/// Horizontally add adjacent pairs of 16-bit integers in `a` and `b`.
+/// Broadcast 128 bits of integer data from `a` to both 128-bit lanes of the
+/// returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hadd_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { phaddw(a, b) }
+pub fn _mm_broadcastsi128_si256(a: __m128i) -> __m256i {
+    let a = i64x2::from(a);
+    let r: i64x4 = unsafe { simd_shuffle4(a, a, [0, 1, 0, 1]) };
+    __m256i::from(r)
 }
 
-/// Horizontally add adjacent pairs of 32-bit integers in `a` and `b`.
+/// Broadcast 128 bits of integer data from `a` to both 128-bit lanes of the
+/// returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hadd_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    unsafe { phaddd(a, b) }
+pub fn _mm256_broadcastsi128_si256(a: __m128i) -> __m256i {
+    let a = i64x2::from(a);
+    let r: i64x4 = unsafe { simd_shuffle4(a, a, [0, 1, 0, 1]) };
+    __m256i::from(r)
 }
 
-/// Horizontally add adjacent pairs of 16-bit integers in `a` and `b`
-/// using saturation.
+/// Broadcast the low single-precision (32-bit) floating-point
+/// element from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hadds_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { phaddsw(a, b) }
+pub fn _mm_broadcastss_ps(a: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, a, [0; 4]) }
 }
 
-/// Horizontally substract adjacent pairs of 16-bit integers in `a` and `b`.
+/// Broadcast the low single-precision (32-bit) floating-point
+/// element from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hsub_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { phsubw(a, b) }
+pub fn _mm256_broadcastss_ps(a: __m128) -> __m256 {
+    unsafe { simd_shuffle8(a, a, [0; 8]) }
 }
 
-/// Horizontally substract adjacent pairs of 32-bit integers in `a` and `b`.
+/// Broadcast the low packed 16-bit integer from `a` to all elements of
+/// the 128-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hsub_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    unsafe { phsubd(a, b) }
+pub fn _mm_broadcastw_epi16(a: i16x8) -> i16x8 {
+    unsafe { simd_shuffle8(a, a, [0; 8]) }
 }
 
-/// Horizontally subtract adjacent pairs of 16-bit integers in `a` and `b`
-/// using saturation.
+/// Broadcast the low packed 16-bit integer from `a` to all elements of
+/// the 256-bit returned value.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_hsubs_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { phsubsw(a, b) }
+pub fn _mm256_broadcastw_epi16(a: i16x8) -> i16x16 {
+    unsafe { simd_shuffle16(a, a, [0; 16]) }
 }
 
-
-// TODO _mm_i32gather_epi32 (int const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i32gather_epi32 (__m128i src, int const* base_addr, __m128i vindex, __m128i mask, const int scale)
-// TODO _mm256_i32gather_epi32 (int const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i32gather_epi32 (__m256i src, int const* base_addr, __m256i vindex, __m256i mask, const int scale)
-// TODO _mm_i32gather_epi64 (__int64 const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i32gather_epi64 (__m128i src, __int64 const* base_addr, __m128i vindex, __m128i mask, const int scale)
-// TODO _mm256_i32gather_epi64 (__int64 const* base_addr, __m128i vindex, const int scale)
-// TODO _mm256_mask_i32gather_epi64 (__m256i src, __int64 const* base_addr, __m128i vindex, __m256i mask, const int scale)
-// TODO _mm_i32gather_pd (double const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i32gather_pd (__m128d src, double const* base_addr, __m128i vindex, __m128d mask, const int scale)
-// TODO _mm256_i32gather_pd (double const* base_addr, __m128i vindex, const int scale)
-// TODO _mm256_mask_i32gather_pd (__m256d src, double const* base_addr, __m128i vindex, __m256d mask, const int scale)
-// TODO _mm_i32gather_ps (float const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i32gather_ps (__m128 src, float const* base_addr, __m128i vindex, __m128 mask, const int scale)
-// TODO _mm256_i32gather_ps (float const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i32gather_ps (__m256 src, float const* base_addr, __m256i vindex, __m256 mask, const int scale)
-// TODO _mm_i64gather_epi32 (int const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i64gather_epi32 (__m128i src, int const* base_addr, __m128i vindex, __m128i mask, const int scale)
-// TODO _mm256_i64gather_epi32 (int const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i64gather_epi32 (__m128i src, int const* base_addr, __m256i vindex, __m128i mask, const int scale)
-// TODO _mm_i64gather_epi64 (__int64 const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i64gather_epi64 (__m128i src, __int64 const* base_addr, __m128i vindex, __m128i mask, const int scale)
-// TODO _mm256_i64gather_epi64 (__int64 const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i64gather_epi64 (__m256i src, __int64 const* base_addr, __m256i vindex, __m256i mask, const int scale)
-// TODO _mm_i64gather_pd (double const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i64gather_pd (__m128d src, double const* base_addr, __m128i vindex, __m128d mask, const int scale)
-// TODO _mm256_i64gather_pd (double const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i64gather_pd (__m256d src, double const* base_addr, __m256i vindex, __m256d mask, const int scale)
-// TODO _mm_i64gather_ps (float const* base_addr, __m128i vindex, const int scale)
-// TODO _mm_mask_i64gather_ps (__m128 src, float const* base_addr, __m128i vindex, __m128 mask, const int scale)
-// TODO _mm256_i64gather_ps (float const* base_addr, __m256i vindex, const int scale)
-// TODO _mm256_mask_i64gather_ps
-// TODO _mm256_inserti128_si256
-
-/// Multiply packed signed 16-bit integers in `a` and `b`, producing
-/// intermediate signed 32-bit integers. Horizontally add adjacent pairs
-/// of intermediate 32-bit integers.
+/// Shift 128-bit lanes in `a` left by `imm8` bytes while shifting in zeros.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_madd_epi16(a: i16x16, b: i16x16) -> i32x8 {
-    unsafe { pmaddwd(a, b) }
+pub fn _mm256_bslli_epi128(a: __m256i, imm8: i32) -> __m256i {
+    _mm256_slli_si256(a, imm8)
 }
 
-/// Vertically multiply each unsigned 8-bit integer from `a` with the
-/// corresponding signed 8-bit integer from `b`, producing intermediate
-/// signed 16-bit integers. Horizontally add adjacent pairs of intermediate
-/// signed 16-bit integers
+/// Shift 128-bit lanes in `a` right by `imm8` bytes while shifting in
+/// zeros.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_maddubs_epi16(a: u8x32, b: u8x32) -> i16x16 {
-    unsafe { pmaddubsw(a, b) }
+pub fn _mm256_bsrli_epi128(a: __m256i, imm8: i32) -> __m256i {
+    _mm256_srli_si256(a, imm8)
 }
 
-// TODO _mm_maskload_epi32 (int const* mem_addr, __m128i mask)
-// TODO _mm256_maskload_epi32 (int const* mem_addr, __m256i mask)
-// TODO _mm_maskload_epi64 (__int64 const* mem_addr, __m128i mask)
-// TODO _mm256_maskload_epi64 (__int64 const* mem_addr, __m256i mask)
-// TODO _mm_maskstore_epi32 (int* mem_addr, __m128i mask, __m128i a)
-// TODO _mm256_maskstore_epi32 (int* mem_addr, __m256i mask, __m256i a)
-// TODO _mm_maskstore_epi64 (__int64* mem_addr, __m128i mask, __m128i a)
-// TODO _mm256_maskstore_epi64 (__int64* mem_addr, __m256i mask, __m256i a)
 
-/// Compare packed 16-bit integers in `a` and `b`, and return the packed
-/// maximum values.
+/// Compare packed 64-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { pmaxsw(a, b) }
+pub fn _mm256_cmpeq_epi64(a: i64x4, b: i64x4) -> i64x4 {
+    a.eq(b).into()
 }
 
-/// Compare packed 32-bit integers in `a` and `b`, and return the packed
-/// maximum values.
+/// Compare packed 32-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    unsafe { pmaxsd(a, b) }
+pub fn _mm256_cmpeq_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    a.eq(b).into()
 }
 
-/// Compare packed 8-bit integers in `a` and `b`, and return the packed
-/// maximum values.
+/// Compare packed 16-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    unsafe { pmaxsb(a, b) }
+pub fn _mm256_cmpeq_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    a.eq(b).into()
 }
 
-/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
-/// the packed maximum values.
+/// Compare packed 8-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epu16(a: u16x16, b: u16x16) -> u16x16 {
-    unsafe { pmaxuw(a, b) }
+pub fn _mm256_cmpeq_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    a.eq(b).into()
 }
 
-/// Compare packed unsigned 32-bit integers in `a` and `b`, and return
-/// the packed maximum values.
+/// Compare packed 64-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epu32(a: u32x8, b: u32x8) -> u32x8 {
-    unsafe { pmaxud(a, b) }
+pub fn _mm256_cmpgt_epi64(a: i64x4, b: i64x4) -> i64x4 {
+    a.gt(b).into()
 }
 
-/// Compare packed unsigned 8-bit integers in `a` and `b`, and return
-/// the packed maximum values.
+/// Compare packed 32-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_max_epu8(a: u8x32, b: u8x32) -> u8x32 {
-    unsafe { pmaxub(a, b) }
+pub fn _mm256_cmpgt_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    a.gt(b).into()
 }
 
-/// Compare packed 16-bit integers in `a` and `b`, and return the packed
-/// minimum values.
+/// Compare packed 16-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { pminsw(a, b) }
+pub fn _mm256_cmpgt_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    a.gt(b).into()
 }
 
-/// Compare packed 32-bit integers in `a` and `b`, and return the packed
-/// minimum values.
+/// Compare packed 8-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    unsafe { pminsd(a, b) }
+pub fn _mm256_cmpgt_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    a.gt(b).into()
 }
 
-/// Compare packed 8-bit integers in `a` and `b`, and return the packed
-/// minimum values.
+/// Sign extend packed 16-bit integers in `a` to packed 32-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    unsafe { pminsb(a, b) }
+pub fn _mm256_cvtepi16_epi32(a: i16x8) -> i32x8 {
+    unsafe { simd_cast(a) }
 }
 
-/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
-/// the packed minimum values.
+/// Sign extend packed 16-bit integers in the low 8 bytes of `a` to packed
+/// 64-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epu16(a: u16x16, b: u16x16) -> u16x16 {
-    unsafe { pminuw(a, b) }
+pub fn _mm256_cvtepi16_epi64(a: i16x8) -> i64x4 {
+    _mm256_cvtepi32_epi64(_mm_cvtepi16_epi32(a))
 }
 
-/// Compare packed unsigned 32-bit integers in `a` and `b`, and return
-/// the packed minimum values.
+/// Sign extend packed 32-bit integers in `a` to packed 64-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epu32(a: u32x8, b: u32x8) -> u32x8 {
-    unsafe { pminud(a, b) }
+pub fn _mm256_cvtepi32_epi64(a: i32x4) -> i64x4 {
+    unsafe { simd_cast(a) }
 }
 
-/// Compare packed unsigned 8-bit integers in `a` and `b`, and return
-/// the packed minimum values.
+/// Sign extend packed 8-bit integers in `a` to packed 16-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_min_epu8(a: u8x32, b: u8x32) -> u8x32 {
-    unsafe { pminub(a, b) }
+pub fn _mm256_cvtepi8_epi16(a: i8x16) -> i16x16 {
+    unsafe { simd_cast(a) }
 }
 
-/*** The following two functions fail in debug, but work in release
-
-/// Create mask from the most significant bit of each 8-bit element in `a`,
-/// return the result.
+/// Sign extend packed 8-bit integers in the low 8 bytes of `a` to packed
+/// 32-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_movemask_epi8(a: i8x32) -> i32 {
-    unsafe { pmovmskb(a) }
+pub fn _mm256_cvtepi8_epi32(a: i8x16) -> i32x8 {
+    unsafe {
+        simd_cast::<i8x8, _>(simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]))
+    }
 }
 
-/// Compute the sum of absolute differences (SADs) of quadruplets of unsigned
-/// 8-bit integers in `a` compared to those in `b`, and store the 16-bit
-/// results in dst. Eight SADs are performed for each 128-bit lane using one
-/// quadruplet from `b` and eight quadruplets from `a`. One quadruplet is
-/// selected from `b` starting at on the offset specified in `imm8`. Eight
-/// quadruplets are formed from sequential 8-bit integers selected from `a`
-/// starting at the offset specified in `imm8`.
+/// Sign extend packed 8-bit integers in the low 4 bytes of `a` to packed
+/// 64-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mpsadbw_epu8(a: u8x32, b: u8x32, imm8: i32) -> u16x16 {
-    unsafe { mpsadbw(a, b, imm8) }
+pub fn _mm256_cvtepi8_epi64(a: i8x16) -> i64x4 {
+    _mm256_cvtepi32_epi64(_mm_cvtepi8_epi32(a))
 }
 
-***/
-
-/// Multiply the low 32-bit integers from each packed 64-bit element in
-/// `a` and `b`
-///
-/// Return the 64-bit results.
+/// Zero extend packed unsigned 16-bit integers in `a` to packed 32-bit
+/// integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mul_epi32(a: i32x8, b: i32x8) -> i64x4 {
-    unsafe { pmuldq(a, b) }
+pub fn _mm256_cvtepu16_epi32(a: u16x8) -> i32x8 {
+    unsafe { simd_cast(a) }
 }
 
-/// Multiply the low unsigned 32-bit integers from each packed 64-bit
-/// element in `a` and `b`
-///
-/// Return the unsigned 64-bit results.
+/// Zero extend packed unsigned 16-bit integers in the low 8 bytes of `a` to
+/// packed 64-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mul_epu32(a: u32x8, b: u32x8) -> u64x4 {
-    unsafe { pmuludq(a, b) }
+pub fn _mm256_cvtepu16_epi64(a: u16x8) -> i64x4 {
+    // The 32-bit intermediates are at most 65535, so sign extending them
+    // further is a zero extension.
+    _mm256_cvtepi32_epi64(_mm_cvtepu16_epi32(a))
 }
 
-/// Multiply the packed 16-bit integers in `a` and `b`, producing
-/// intermediate 32-bit integers and returning the high 16 bits of the
-/// intermediate integers.
+/// Zero extend packed unsigned 32-bit integers in `a` to packed 64-bit
+/// integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mulhi_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { pmulhw(a, b) }
+pub fn _mm256_cvtepu32_epi64(a: u32x4) -> i64x4 {
+    unsafe { simd_cast(a) }
 }
 
-/// Multiply the packed unsigned 16-bit integers in `a` and `b`, producing
-/// intermediate 32-bit integers and returning the high 16 bits of the
-/// intermediate integers.
+/// Zero extend packed unsigned 8-bit integers in `a` to packed 16-bit
+/// integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mulhi_epu16(a: u16x16, b: u16x16) -> u16x16 {
-    unsafe { pmulhuw(a, b) }
+pub fn _mm256_cvtepu8_epi16(a: u8x16) -> i16x16 {
+    unsafe { simd_cast(a) }
 }
 
-/// Multiply the packed 16-bit integers in `a` and `b`, producing
-/// intermediate 32-bit integers, and return the low 16 bits of the
-/// intermediate integers
+/// Zero extend packed unsigned 8-bit integers in the low 8 bytes of `a` to
+/// packed 32-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mullo_epi16(a: i16x16, b:i16x16) -> i16x16 {
-    a * b
+pub fn _mm256_cvtepu8_epi32(a: u8x16) -> i32x8 {
+    unsafe {
+        simd_cast::<u8x8, _>(simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]))
+    }
 }
 
-
-/// Multiply the packed 32-bit integers in `a` and `b`, producing
-/// intermediate 64-bit integers, and return the low 16 bits of the
-/// intermediate integers
+/// Zero extend packed unsigned 8-bit integers in the low 4 bytes of `a` to
+/// packed 64-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mullo_epi32(a: i32x8, b:i32x8) -> i32x8 {
-    a * b
+pub fn _mm256_cvtepu8_epi64(a: u8x16) -> i64x4 {
+    _mm256_cvtepi32_epi64(_mm_cvtepu8_epi32(a))
 }
 
-/// Multiply packed 16-bit integers in `a` and `b`, producing
-/// intermediate signed 32-bit integers. Truncate each intermediate
-/// integer to the 18 most significant bits, round by adding 1, and
-/// return bits [16:1]
+/// Extract 128 bits (of integer data) from `a` selected with `imm8`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_mulhrs_epi16(a: i16x16, b:i16x16) -> i16x16 {
-    unsafe { pmulhrsw(a, b) }
+pub fn _mm256_extracti128_si256(a: __m256i, imm8: i32) -> __m128i {
+    let a = i64x4::from(a);
+    let r: i64x2 = match imm8 & 1 {
+        0 => unsafe { simd_shuffle2(a, a, [0, 1]) },
+        _ => unsafe { simd_shuffle2(a, a, [2, 3]) },
+    };
+    __m128i::from(r)
 }
 
-/// Compute the bitwise OR of 256 bits (representing integer data) in `a`
-/// and `b`
+/// Horizontally add adjacent pairs of 16-bit integers in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_or_si256(a: __m256i, b: __m256i) -> __m256i {
-    a | b
+pub fn _mm256_hadd_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { phaddw(a, b) }
 }
 
-/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
-/// using signed saturation
+/// Horizontally add adjacent pairs of 32-bit integers in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_packs_epi16(a: i16x16, b: i16x16) -> i8x32 {
-    unsafe { packsswb(a, b) }
+pub fn _mm256_hadd_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { phaddd(a, b) }
 }
 
-/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
-/// using signed saturation
+/// Horizontally add adjacent pairs of 16-bit integers in `a` and `b`
+/// using saturation.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_packs_epi32(a: i32x8, b: i32x8) -> i16x16 {
-    unsafe { packssdw(a, b) }
+pub fn _mm256_hadds_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { phaddsw(a, b) }
 }
 
-/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
-/// using unsigned saturation
+/// Horizontally substract adjacent pairs of 16-bit integers in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_packus_epi16(a: i16x16, b: i16x16) -> u8x32 {
-    unsafe { packuswb(a, b) }
+pub fn _mm256_hsub_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { phsubw(a, b) }
 }
 
-/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
-/// using unsigned saturation
+/// Horizontally substract adjacent pairs of 32-bit integers in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_packus_epi32(a: i32x8, b: i32x8) -> u16x16 {
-    unsafe { packusdw(a, b) }
+pub fn _mm256_hsub_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { phsubd(a, b) }
 }
 
-// TODO _mm256_permute2x128_si256 (__m256i a, __m256i b, const int imm8)
-// TODO _mm256_permute4x64_epi64 (__m256i a, const int imm8)
-// TODO _mm256_permute4x64_pd (__m256d a, const int imm8)
-// TODO _mm256_permutevar8x32_epi32 (__m256i a, __m256i idx)
-// TODO _mm256_permutevar8x32_ps (__m256 a, __m256i idx)
-
-/// Compute the absolute differences of packed unsigned 8-bit integers in `a`
-/// and `b`, then horizontally sum each consecutive 8 differences to
-/// produce four unsigned 16-bit integers, and pack these unsigned 16-bit
-/// integers in the low 16 bits of the 64-bit return value
+/// Horizontally subtract adjacent pairs of 16-bit integers in `a` and `b`
+/// using saturation.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sad_epu8 (a: u8x32, b: u8x32) -> u64x4 {
-    unsafe { psadbw(a, b) }
+pub fn _mm256_hsubs_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { phsubsw(a, b) }
 }
 
-// TODO _mm256_shuffle_epi32 (__m256i a, const int imm8)
-// TODO _mm256_shuffle_epi8 (__m256i a, __m256i b)
-// TODO _mm256_shufflehi_epi16 (__m256i a, const int imm8)
-// TODO _mm256_shufflelo_epi16 (__m256i a, const int imm8)
-
-/// Negate packed 16-bit integers in `a` when the corresponding signed
-/// 16-bit integer in `b` is negative, and return the results.
-/// Results are zeroed out when the corresponding element in `b` is zero.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sign_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { psignw(a, b) }
+pub unsafe fn _mm_i32gather_epi32(
+    slice: *const i32,
+    offsets: i32x4,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdd(
+                i32x4::splat(0),
+                slice as *const i8,
+                offsets,
+                i32x4::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Negate packed 32-bit integers in `a` when the corresponding signed
-/// 32-bit integer in `b` is negative, and return the results.
-/// Results are zeroed out when the corresponding element in `b` is zero.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sign_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    unsafe { psignd(a, b) }
+pub unsafe fn _mm_mask_i32gather_epi32(
+    src: i32x4,
+    slice: *const i32,
+    offsets: i32x4,
+    mask: i32x4,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdd(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Negate packed 8-bit integers in `a` when the corresponding signed
-/// 8-bit integer in `b` is negative, and return the results.
-/// Results are zeroed out when the corresponding element in `b` is zero.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sign_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    unsafe { psignb(a, b) }
+pub unsafe fn _mm256_i32gather_epi32(
+    slice: *const i32,
+    offsets: i32x8,
+    scale: i32,
+) -> i32x8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdd256(
+                i32x8::splat(0),
+                slice as *const i8,
+                offsets,
+                i32x8::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 16-bit integers in `a` left by `count` while
-/// shifting in zeros, and return the result
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sll_epi16(a: i16x16, count: i16x8) -> i16x16 {
-    unsafe { psllw(a, count) }
+pub unsafe fn _mm256_mask_i32gather_epi32(
+    src: i32x8,
+    slice: *const i32,
+    offsets: i32x8,
+    mask: i32x8,
+    scale: i32,
+) -> i32x8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdd256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` left by `count` while
-/// shifting in zeros, and return the result
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sll_epi32(a: i32x8, count: i32x4) -> i32x8 {
-    unsafe { pslld(a, count) }
+pub unsafe fn _mm_i32gather_ps(
+    slice: *const f32,
+    offsets: i32x4,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdps(
+                f32x4::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f32x4::splat(::std::mem::transmute(-1i32)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` left by `count` while
-/// shifting in zeros, and return the result
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sll_epi64(a: i64x4, count: i64x2) -> i64x4 {
-    unsafe { psllq(a, count) }
+pub unsafe fn _mm_mask_i32gather_ps(
+    src: f32x4,
+    slice: *const f32,
+    offsets: i32x4,
+    mask: f32x4,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdps(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 16-bit integers in `a` left by `imm8` while
-/// shifting in zeros, return the results;
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_slli_epi16(a: i16x16, imm8: i32) -> i16x16 {
-    unsafe { pslliw(a, imm8) }
+pub unsafe fn _mm256_i32gather_ps(
+    slice: *const f32,
+    offsets: i32x8,
+    scale: i32,
+) -> f32x8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdps256(
+                f32x8::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f32x8::splat(::std::mem::transmute(-1i32)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` left by `imm8` while
-/// shifting in zeros, return the results;
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_slli_epi32(a: i32x8, imm8: i32) -> i32x8 {
-    unsafe { psllid(a, imm8) }
+pub unsafe fn _mm256_mask_i32gather_ps(
+    src: f32x8,
+    slice: *const f32,
+    offsets: i32x8,
+    mask: f32x8,
+    scale: i32,
+) -> f32x8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdps256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` left by `imm8` while
-/// shifting in zeros, return the results;
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_slli_epi64(a: i64x4, imm8: i32) -> i64x4 {
-    unsafe { pslliq(a, imm8) }
+pub unsafe fn _mm_i32gather_epi64(
+    slice: *const i64,
+    offsets: i32x4,
+    scale: i32,
+) -> i64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdq(
+                i64x2::splat(0),
+                slice as *const i8,
+                offsets,
+                i64x2::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-// TODO _mm256_slli_si256 (__m256i a, const int imm8)
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm_mask_i32gather_epi64(
+    src: i64x2,
+    slice: *const i64,
+    offsets: i32x4,
+    mask: i64x2,
+    scale: i32,
+) -> i64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdq(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
+}
 
-/// Shift packed 32-bit integers in `a` left by the amount
-/// specified by the corresponding element in `count` while
-/// shifting in zeros, and return the result.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm_sllv_epi32(a: i32x4, count: i32x4) -> i32x4 {
-    unsafe { psllvd(a, count) }
+pub unsafe fn _mm256_i32gather_epi64(
+    slice: *const i64,
+    offsets: i32x4,
+    scale: i32,
+) -> i64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdq256(
+                i64x4::splat(0),
+                slice as *const i8,
+                offsets,
+                i64x4::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` left by the amount
-/// specified by the corresponding element in `count` while
-/// shifting in zeros, and return the result.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sllv_epi32(a: i32x8, count: i32x8) -> i32x8 {
-    unsafe { psllvd256(a, count) }
+pub unsafe fn _mm256_mask_i32gather_epi64(
+    src: i64x4,
+    slice: *const i64,
+    offsets: i32x4,
+    mask: i64x4,
+    scale: i32,
+) -> i64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdq256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` left by the amount
-/// specified by the corresponding element in `count` while
-/// shifting in zeros, and return the result.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm_sllv_epi64(a: i64x2, count: i64x2) -> i64x2 {
-    unsafe { psllvq(a, count) }
+pub unsafe fn _mm_i32gather_pd(
+    slice: *const f64,
+    offsets: i32x4,
+    scale: i32,
+) -> f64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdpd(
+                f64x2::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f64x2::splat(::std::mem::transmute(-1i64)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` left by the amount
-/// specified by the corresponding element in `count` while
-/// shifting in zeros, and return the result.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sllv_epi64(a: i64x4, count: i64x4) -> i64x4 {
-    unsafe { psllvq256(a, count) }
+pub unsafe fn _mm_mask_i32gather_pd(
+    src: f64x2,
+    slice: *const f64,
+    offsets: i32x4,
+    mask: f64x2,
+    scale: i32,
+) -> f64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdpd(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 16-bit integers in `a` right by `count` while
-/// shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sra_epi16(a: i16x16, count: i16x8) -> i16x16 {
-    unsafe { psraw(a, count) }
+pub unsafe fn _mm256_i32gather_pd(
+    slice: *const f64,
+    offsets: i32x4,
+    scale: i32,
+) -> f64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdpd256(
+                f64x4::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f64x4::splat(::std::mem::transmute(-1i64)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by `count` while
-/// shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sra_epi32(a: i32x8, count: i32x4) -> i32x8 {
-    unsafe { psrad(a, count) }
+pub unsafe fn _mm256_mask_i32gather_pd(
+    src: f64x4,
+    slice: *const f64,
+    offsets: i32x4,
+    mask: f64x4,
+    scale: i32,
+) -> f64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherdpd256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 16-bit integers in `a` right by `imm8` while
-/// shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
+///
+/// Only the low two elements of the result are gathered, the upper two are
+/// zeroed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srai_epi16(a: i16x16, imm8: i32) -> i16x16 {
-    unsafe { psraiw(a, imm8) }
+pub unsafe fn _mm_i64gather_epi32(
+    slice: *const i32,
+    offsets: i64x2,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqd(
+                i32x4::splat(0),
+                slice as *const i8,
+                offsets,
+                i32x4::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by `imm8` while
-/// shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
+///
+/// Only the low two elements of the result are gathered, the upper two are
+/// zeroed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srai_epi32(a: i32x8, imm8: i32) -> i32x8 {
-    unsafe { psraid(a, imm8) }
+pub unsafe fn _mm_mask_i64gather_epi32(
+    src: i32x4,
+    slice: *const i32,
+    offsets: i64x2,
+    mask: i32x4,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqd(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by the amount specified by the
-/// corresponding element in `count` while shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm_srav_epi32(a: i32x4, count: i32x4) -> i32x4 {
-    unsafe { psravd(a, count) }
+pub unsafe fn _mm256_i64gather_epi32(
+    slice: *const i32,
+    offsets: i64x4,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqd256(
+                i32x4::splat(0),
+                slice as *const i8,
+                offsets,
+                i32x4::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by the amount specified by the
-/// corresponding element in `count` while shifting in sign bits.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srav_epi32(a: i32x8, count: i32x8) -> i32x8 {
-    unsafe { psravd256(a, count) }
+pub unsafe fn _mm256_mask_i64gather_epi32(
+    src: i32x4,
+    slice: *const i32,
+    offsets: i64x4,
+    mask: i32x4,
+    scale: i32,
+) -> i32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqd256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm_i64gather_epi64(
+    slice: *const i64,
+    offsets: i64x2,
+    scale: i32,
+) -> i64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqq(
+                i64x2::splat(0),
+                slice as *const i8,
+                offsets,
+                i64x2::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
+}
 
-/// Shift packed 16-bit integers in `a` right by `count` while shifting in
-/// zeros.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srl_epi16(a: i16x16, count: i16x8) -> i16x16 {
-    unsafe { psrlw(a, count) }
+pub unsafe fn _mm_mask_i64gather_epi64(
+    src: i64x2,
+    slice: *const i64,
+    offsets: i64x2,
+    mask: i64x2,
+    scale: i32,
+) -> i64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqq(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by `count` while shifting in
-/// zeros.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srl_epi32(a: i32x8, count: i32x4) -> i32x8 {
-    unsafe { psrld(a, count) }
+pub unsafe fn _mm256_i64gather_epi64(
+    slice: *const i64,
+    offsets: i64x4,
+    scale: i32,
+) -> i64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqq256(
+                i64x4::splat(0),
+                slice as *const i8,
+                offsets,
+                i64x4::splat(-1),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` right by `count` while shifting in
-/// zeros.
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srl_epi64(a: i64x4, count: i64x2) -> i64x4 {
-    unsafe { psrlq(a, count) }
+pub unsafe fn _mm256_mask_i64gather_epi64(
+    src: i64x4,
+    slice: *const i64,
+    offsets: i64x4,
+    mask: i64x4,
+    scale: i32,
+) -> i64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqq256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 16-bit integers in `a` right by `imm8` while shifting in
-/// zeros
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srli_epi16(a: i16x16, imm8: i32) -> i16x16 {
-    unsafe { psrliw(a, imm8) }
+pub unsafe fn _mm_i64gather_pd(
+    slice: *const f64,
+    offsets: i64x2,
+    scale: i32,
+) -> f64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqpd(
+                f64x2::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f64x2::splat(::std::mem::transmute(-1i64)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by `imm8` while shifting in
-/// zeros
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srli_epi32(a: i32x8, imm8: i32) -> i32x8 {
-    unsafe { psrlid(a, imm8) }
+pub unsafe fn _mm_mask_i64gather_pd(
+    src: f64x2,
+    slice: *const f64,
+    offsets: i64x2,
+    mask: f64x2,
+    scale: i32,
+) -> f64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqpd(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` right by `imm8` while shifting in
-/// zeros
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srli_epi64(a: i64x4, imm8: i32) -> i64x4 {
-    unsafe { psrliq(a, imm8) }
+pub unsafe fn _mm256_i64gather_pd(
+    slice: *const f64,
+    offsets: i64x4,
+    scale: i32,
+) -> f64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqpd256(
+                f64x4::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f64x4::splat(::std::mem::transmute(-1i64)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by the amount specified by
-/// the corresponding element in `count` while shifting in zeros,
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm_srlv_epi32(a: i32x4, count: i32x4) -> i32x4 {
-    unsafe { psrlvd(a, count) }
+pub unsafe fn _mm256_mask_i64gather_pd(
+    src: f64x4,
+    slice: *const f64,
+    offsets: i64x4,
+    mask: f64x4,
+    scale: i32,
+) -> f64x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqpd256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 32-bit integers in `a` right by the amount specified by
-/// the corresponding element in `count` while shifting in zeros,
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
+///
+/// Only the low two elements of the result are gathered, the upper two are
+/// zeroed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srlv_epi32(a: i32x8, count: i32x8) -> i32x8 {
-    unsafe { psrlvd256(a, count) }
+pub unsafe fn _mm_i64gather_ps(
+    slice: *const f32,
+    offsets: i64x2,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqps(
+                f32x4::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f32x4::splat(::std::mem::transmute(-1i32)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` right by the amount specified by
-/// the corresponding element in `count` while shifting in zeros,
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
+///
+/// Only the low two elements of the result are gathered, the upper two are
+/// zeroed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm_srlv_epi64(a: i64x2, count: i64x2) -> i64x2 {
-    unsafe { psrlvq(a, count) }
+pub unsafe fn _mm_mask_i64gather_ps(
+    src: f32x4,
+    slice: *const f32,
+    offsets: i64x2,
+    mask: f32x4,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqps(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-/// Shift packed 64-bit integers in `a` right by the amount specified by
-/// the corresponding element in `count` while shifting in zeros,
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_srlv_epi64(a: i64x4, count: i64x4) -> i64x4 {
-    unsafe { psrlvq256(a, count) }
+pub unsafe fn _mm256_i64gather_ps(
+    slice: *const f32,
+    offsets: i64x4,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqps256(
+                f32x4::splat(0.0),
+                slice as *const i8,
+                offsets,
+                f32x4::splat(::std::mem::transmute(-1i32)),
+                $imm8,
+            )
+        }
+    }
+    constify_scale!(scale, call)
 }
 
-// TODO _mm256_stream_load_si256 (__m256i const* mem_addr)
+/// Returns values from `slice` at byte offsets determined by
+/// `offsets * scale`, where `scale` is 1, 2, 4 or 8. If the highest bit
+/// of the corresponding element of `mask` is not set, the element is taken
+/// from `src` instead.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm256_mask_i64gather_ps(
+    src: f32x4,
+    slice: *const f32,
+    offsets: i64x4,
+    mask: f32x4,
+    scale: i32,
+) -> f32x4 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pgatherqps256(src, slice as *const i8, offsets, mask, $imm8)
+        }
+    }
+    constify_scale!(scale, call)
+}
 
-/// Subtract packed 16-bit integers in `b` from packed 16-bit integers in `a`
+/// Copy `a`, and insert 128 bits (of integer data) from `b` at the location
+/// specified by `imm8`.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sub_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    a - b
+pub fn _mm256_inserti128_si256(
+    a: __m256i,
+    b: __m128i,
+    imm8: i32,
+) -> __m256i {
+    let a = i64x4::from(a);
+    let b = i64x2::from(b);
+    let b: i64x4 = unsafe { simd_shuffle4(b, b, [0, 1, 0, 1]) };
+    let r: i64x4 = match imm8 & 1 {
+        0 => unsafe { simd_shuffle4(a, b, [4, 5, 2, 3]) },
+        _ => unsafe { simd_shuffle4(a, b, [0, 1, 4, 5]) },
+    };
+    __m256i::from(r)
 }
 
-/// Subtract packed 32-bit integers in `b` from packed 16-bit integers in `a`
+/// Multiply packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Horizontally add adjacent pairs
+/// of intermediate 32-bit integers.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sub_epi32(a: i32x8, b: i32x8) -> i32x8 {
-    a - b
+pub fn _mm256_madd_epi16(a: i16x16, b: i16x16) -> i32x8 {
+    unsafe { pmaddwd(a, b) }
 }
 
-/// Subtract packed 64-bit integers in `b` from packed 16-bit integers in `a`
+/// Vertically multiply each unsigned 8-bit integer from `a` with the
+/// corresponding signed 8-bit integer from `b`, producing intermediate
+/// signed 16-bit integers. Horizontally add adjacent pairs of intermediate
+/// signed 16-bit integers
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sub_epi64(a: i64x4, b: i64x4) -> i64x4 {
-    a - b
+pub fn _mm256_maddubs_epi16(a: u8x32, b: u8x32) -> i16x16 {
+    unsafe { pmaddubsw(a, b) }
 }
 
-/// Subtract packed 8-bit integers in `b` from packed 16-bit integers in `a`
+/// Load packed 32-bit integers from memory pointed by `mem_addr` using
+/// `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_sub_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    a - b
+pub unsafe fn _mm_maskload_epi32(mem_addr: *const i32, mask: i32x4) -> i32x4 {
+    maskloadd(mem_addr as *const i8, mask)
 }
 
-/// Subtract packed 16-bit integers in `b` from packed 16-bit integers in
-/// `a` using saturation.
+/// Load packed 32-bit integers from memory pointed by `mem_addr` using
+/// `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_subs_epi16(a: i16x16, b: i16x16) -> i16x16 {
-    unsafe { psubsw(a, b) }
+pub unsafe fn _mm256_maskload_epi32(
+    mem_addr: *const i32,
+    mask: i32x8,
+) -> i32x8 {
+    maskloadd256(mem_addr as *const i8, mask)
 }
 
-/// Subtract packed 8-bit integers in `b` from packed 8-bit integers in
-/// `a` using saturation.
+/// Load packed 64-bit integers from memory pointed by `mem_addr` using
+/// `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_subs_epi8(a: i8x32, b: i8x32) -> i8x32 {
-    unsafe { psubsb(a, b) }
+pub unsafe fn _mm_maskload_epi64(mem_addr: *const i64, mask: i64x2) -> i64x2 {
+    maskloadq(mem_addr as *const i8, mask)
 }
 
-/// Subtract packed unsigned 16-bit integers in `b` from packed 16-bit
-/// integers in `a` using saturation.
+/// Load packed 64-bit integers from memory pointed by `mem_addr` using
+/// `mask`.
+///
+/// Elements are zeroed out when the highest bit of the corresponding element
+/// of `mask` is not set, and their memory is not accessed.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_subs_epu16(a: u16x16, b: u16x16) -> u16x16 {
-    unsafe { psubusw(a, b) }
+pub unsafe fn _mm256_maskload_epi64(
+    mem_addr: *const i64,
+    mask: i64x4,
+) -> i64x4 {
+    maskloadq256(mem_addr as *const i8, mask)
 }
 
-/// Subtract packed unsigned 8-bit integers in `b` from packed 8-bit
-/// integers in `a` using saturation.
+/// Store packed 32-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_subs_epu8(a: u8x32, b: u8x32) -> u8x32 {
-    unsafe { psubusb(a, b) }
+pub unsafe fn _mm_maskstore_epi32(mem_addr: *mut i32, mask: i32x4, a: i32x4) {
+    maskstored(mem_addr as *mut i8, mask, a)
 }
 
-// TODO __mm256_unpackhi_epi16 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpackhi_epi32 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpackhi_epi64 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpackhi_epi8 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpacklo_epi16 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpacklo_epi32 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpacklo_epi64 (__m256i a, __m256i b)
-// TODO __m256i _mm256_unpacklo_epi8 (__m256i a, __m256i b)
+/// Store packed 32-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm256_maskstore_epi32(
+    mem_addr: *mut i32,
+    mask: i32x8,
+    a: i32x8,
+) {
+    maskstored256(mem_addr as *mut i8, mask, a)
+}
 
-/// Compute the bitwise XOR of 256 bits (representing integer data)
-/// in `a` and `b`
+/// Store packed 64-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
 #[inline(always)]
 #[target_feature = "+avx2"]
-pub fn _mm256_xor_si256(a: __m256i, b: __m256i) -> __m256i {
-    a ^ b
+pub unsafe fn _mm_maskstore_epi64(mem_addr: *mut i64, mask: i64x2, a: i64x2) {
+    maskstoreq(mem_addr as *mut i8, mask, a)
 }
 
+/// Store packed 64-bit integers from `a` into memory pointed by `mem_addr`
+/// using `mask`.
+///
+/// Elements are not stored when the highest bit of the corresponding element
+/// of `mask` is not set.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm256_maskstore_epi64(
+    mem_addr: *mut i64,
+    mask: i64x4,
+    a: i64x4,
+) {
+    maskstoreq256(mem_addr as *mut i8, mask, a)
+}
 
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.x86.avx2.pabs.b"]
-    fn pabsb(a: i8x32) -> i8x32;
-    #[link_name = "llvm.x86.avx2.pabs.w"]
-    fn pabsw(a: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.pabs.d"]
-    fn pabsd(a: i32x8) -> i32x8;
-    #[link_name = "llvm.x86.avx2.padds.b"]
-    fn paddsb(a: i8x32, b: i8x32) -> i8x32;
-    #[link_name = "llvm.x86.avx2.padds.w"]
-    fn paddsw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.paddus.b"]
-    fn paddusb(a: u8x32, b: u8x32) -> u8x32;
-    #[link_name = "llvm.x86.avx2.paddus.w"]
-    fn paddusw(a: u16x16, b: u16x16) -> u16x16;
-    #[link_name = "llvm.x86.avx2.pavg.b"]
-    fn pavgb(a: u8x32, b: u8x32) -> u8x32;
-    #[link_name = "llvm.x86.avx2.pavg.w"]
-    fn pavgw(a: u16x16, b: u16x16) -> u16x16;
-    #[link_name = "llvm.x86.avx2.pblendvb"]
-    fn pblendvb(a: i8x32, b: i8x32, mask: __m256i) -> i8x32;
-    #[link_name = "llvm.x86.avx2.phadd.w"]
-    fn phaddw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.phadd.d"]
-    fn phaddd(a: i32x8, b: i32x8) -> i32x8;
-    #[link_name = "llvm.x86.avx2.phadd.sw"]
-    fn phaddsw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.phsub.w"]
-    fn phsubw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.phsub.d"]
-    fn phsubd(a: i32x8, b: i32x8) -> i32x8;
-    #[link_name = "llvm.x86.avx2.phsub.sw"]
-    fn phsubsw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.pmadd.wd"]
-    fn pmaddwd(a: i16x16, b: i16x16) -> i32x8;
-    #[link_name = "llvm.x86.avx2.pmadd.ub.sw"]
-    fn pmaddubsw(a: u8x32, b: u8x32) -> i16x16;
-    #[link_name = "llvm.x86.avx2.pmaxs.w"]
-    fn pmaxsw(a: i16x16, b: i16x16) -> i16x16;
-    #[link_name = "llvm.x86.avx2.pmaxs.d"]
+/// Compare packed 16-bit integers in `a` and `b`, and return the packed
+/// maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { pmaxsw(a, b) }
+}
+
+/// Compare packed 32-bit integers in `a` and `b`, and return the packed
+/// maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { pmaxsd(a, b) }
+}
+
+/// Compare packed 8-bit integers in `a` and `b`, and return the packed
+/// maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe { pmaxsb(a, b) }
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
+/// the packed maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epu16(a: u16x16, b: u16x16) -> u16x16 {
+    unsafe { pmaxuw(a, b) }
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b`, and return
+/// the packed maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epu32(a: u32x8, b: u32x8) -> u32x8 {
+    unsafe { pmaxud(a, b) }
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b`, and return
+/// the packed maximum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_max_epu8(a: u8x32, b: u8x32) -> u8x32 {
+    unsafe { pmaxub(a, b) }
+}
+
+/// Compare packed 16-bit integers in `a` and `b`, and return the packed
+/// minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { pminsw(a, b) }
+}
+
+/// Compare packed 32-bit integers in `a` and `b`, and return the packed
+/// minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { pminsd(a, b) }
+}
+
+/// Compare packed 8-bit integers in `a` and `b`, and return the packed
+/// minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe { pminsb(a, b) }
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
+/// the packed minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epu16(a: u16x16, b: u16x16) -> u16x16 {
+    unsafe { pminuw(a, b) }
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b`, and return
+/// the packed minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epu32(a: u32x8, b: u32x8) -> u32x8 {
+    unsafe { pminud(a, b) }
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b`, and return
+/// the packed minimum values.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_min_epu8(a: u8x32, b: u8x32) -> u8x32 {
+    unsafe { pminub(a, b) }
+}
+
+/// Create mask from the most significant bit of each 8-bit element in `a`,
+/// return the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_movemask_epi8(a: i8x32) -> i32 {
+    unsafe { pmovmskb(a) }
+}
+
+/// Compute the sum of absolute differences (SADs) of quadruplets of unsigned
+/// 8-bit integers in `a` compared to those in `b`, and store the 16-bit
+/// results in dst. Eight SADs are performed for each 128-bit lane using one
+/// quadruplet from `b` and eight quadruplets from `a`. One quadruplet is
+/// selected from `b` starting at on the offset specified in `imm8`. Eight
+/// quadruplets are formed from sequential 8-bit integers selected from `a`
+/// starting at the offset specified in `imm8`.
+///
+/// Bits `[2:0]` of `imm8` select the offsets for the low 128-bit lane, as in
+/// `_mm_mpsadbw_epu8`, and bits `[5:3]` those for the high lane.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mpsadbw_epu8(a: u8x32, b: u8x32, imm8: i32) -> u16x16 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { mpsadbw(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Multiply the low 32-bit integers from each packed 64-bit element in
+/// `a` and `b`
+///
+/// Return the 64-bit results.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mul_epi32(a: i32x8, b: i32x8) -> i64x4 {
+    unsafe { pmuldq(a, b) }
+}
+
+/// Multiply the low unsigned 32-bit integers from each packed 64-bit
+/// element in `a` and `b`
+///
+/// Return the unsigned 64-bit results.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mul_epu32(a: u32x8, b: u32x8) -> u64x4 {
+    unsafe { pmuludq(a, b) }
+}
+
+/// Multiply the packed 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers and returning the high 16 bits of the
+/// intermediate integers.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mulhi_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { pmulhw(a, b) }
+}
+
+/// Multiply the packed unsigned 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers and returning the high 16 bits of the
+/// intermediate integers.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mulhi_epu16(a: u16x16, b: u16x16) -> u16x16 {
+    unsafe { pmulhuw(a, b) }
+}
+
+/// Multiply the packed 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and return the low 16 bits of the
+/// intermediate integers
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mullo_epi16(a: i16x16, b:i16x16) -> i16x16 {
+    a * b
+}
+
+
+/// Multiply the packed 32-bit integers in `a` and `b`, producing
+/// intermediate 64-bit integers, and return the low 16 bits of the
+/// intermediate integers
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mullo_epi32(a: i32x8, b:i32x8) -> i32x8 {
+    a * b
+}
+
+/// Multiply packed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Truncate each intermediate
+/// integer to the 18 most significant bits, round by adding 1, and
+/// return bits [16:1]
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_mulhrs_epi16(a: i16x16, b:i16x16) -> i16x16 {
+    unsafe { pmulhrsw(a, b) }
+}
+
+/// Compute the bitwise OR of 256 bits (representing integer data) in `a`
+/// and `b`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_or_si256(a: __m256i, b: __m256i) -> __m256i {
+    a | b
+}
+
+/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using signed saturation
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_packs_epi16(a: i16x16, b: i16x16) -> i8x32 {
+    unsafe { packsswb(a, b) }
+}
+
+/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using signed saturation
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_packs_epi32(a: i32x8, b: i32x8) -> i16x16 {
+    unsafe { packssdw(a, b) }
+}
+
+/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using unsigned saturation
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_packus_epi16(a: i16x16, b: i16x16) -> u8x32 {
+    unsafe { packuswb(a, b) }
+}
+
+/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using unsigned saturation
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_packus_epi32(a: i32x8, b: i32x8) -> u16x16 {
+    unsafe { packusdw(a, b) }
+}
+
+/// Shuffle 128 bits of integer data selected by `imm8` from `a` and `b`.
+///
+/// The low and high nibbles of `imm8` select the low and high lanes of the
+/// result: `0` and `1` select the low and high lanes of `a`, `2` and `3`
+/// those of `b`. If bit 3 of a nibble is set, the lane is zeroed instead.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_permute2x128_si256(
+    a: __m256i,
+    b: __m256i,
+    imm8: i32,
+) -> __m256i {
+    let a = i64x4::from(a);
+    let b = i64x4::from(b);
+    macro_rules! call {
+        ($imm8:expr) => {
+            __m256i::from(unsafe { vperm2i128(a, b, $imm8) })
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Permute 64-bit integers from `a` using the control in `imm8`.
+///
+/// Each pair of bits of `imm8` selects the element of `a` for the
+/// corresponding element of the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_permute4x64_epi64(a: i64x4, imm8: i32) -> i64x4 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle4(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Permute double-precision (64-bit) floating-point elements from `a` using
+/// the control in `imm8`.
+///
+/// Each pair of bits of `imm8` selects the element of `a` for the
+/// corresponding element of the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_permute4x64_pd(a: __m256d, imm8: i32) -> __m256d {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle4(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Permute packed 32-bit integers from `a` across lanes using the
+/// corresponding index in `idx`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_permutevar8x32_epi32(a: i32x8, idx: i32x8) -> i32x8 {
+    unsafe { permd(a, idx) }
+}
+
+/// Permute packed single-precision (32-bit) floating-point elements from `a`
+/// across lanes using the corresponding index in `idx`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_permutevar8x32_ps(a: __m256, idx: i32x8) -> __m256 {
+    unsafe { permps(a, idx) }
+}
+
+/// Compute the absolute differences of packed unsigned 8-bit integers in `a`
+/// and `b`, then horizontally sum each consecutive 8 differences to
+/// produce four unsigned 16-bit integers, and pack these unsigned 16-bit
+/// integers in the low 16 bits of the 64-bit return value
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sad_epu8 (a: u8x32, b: u8x32) -> u64x4 {
+    unsafe { psadbw(a, b) }
+}
+
+/// Shuffle 32-bit integers in the 128-bit lanes of `a` using the control in
+/// `imm8`.
+///
+/// The same control applies to both lanes.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_shuffle_epi32(a: i32x8, imm8: i32) -> i32x8 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                    4 + ($imm8 & 0b11),
+                    4 + (($imm8 >> 2) & 0b11),
+                    4 + (($imm8 >> 4) & 0b11),
+                    4 + (($imm8 >> 6) & 0b11),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Shuffle bytes from `a` according to the content of `b`.
+///
+/// Each byte of `b` selects a byte of the same 128-bit lane of `a` with its
+/// low four bits, or zeroes the byte if its highest bit is set.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_shuffle_epi8(a: u8x32, b: u8x32) -> u8x32 {
+    unsafe { pshufb(a, b) }
+}
+
+/// Shuffle 16-bit integers in the high 64 bits of the 128-bit lanes of `a`
+/// using the control in `imm8`.
+///
+/// The low 64 bits of the lanes are copied from `a`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_shufflehi_epi16(a: i16x16, imm8: i32) -> i16x16 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle16(a, a, [
+                    0, 1, 2, 3,
+                    4 + ($imm8 & 0b11),
+                    4 + (($imm8 >> 2) & 0b11),
+                    4 + (($imm8 >> 4) & 0b11),
+                    4 + (($imm8 >> 6) & 0b11),
+                    8, 9, 10, 11,
+                    12 + ($imm8 & 0b11),
+                    12 + (($imm8 >> 2) & 0b11),
+                    12 + (($imm8 >> 4) & 0b11),
+                    12 + (($imm8 >> 6) & 0b11),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Shuffle 16-bit integers in the low 64 bits of the 128-bit lanes of `a`
+/// using the control in `imm8`.
+///
+/// The high 64 bits of the lanes are copied from `a`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_shufflelo_epi16(a: i16x16, imm8: i32) -> i16x16 {
+    macro_rules! shuffle {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle16(a, a, [
+                    $imm8 & 0b11,
+                    ($imm8 >> 2) & 0b11,
+                    ($imm8 >> 4) & 0b11,
+                    ($imm8 >> 6) & 0b11,
+                    4, 5, 6, 7,
+                    8 + ($imm8 & 0b11),
+                    8 + (($imm8 >> 2) & 0b11),
+                    8 + (($imm8 >> 4) & 0b11),
+                    8 + (($imm8 >> 6) & 0b11),
+                    12, 13, 14, 15,
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, shuffle)
+}
+
+/// Negate packed 16-bit integers in `a` when the corresponding signed
+/// 16-bit integer in `b` is negative, and return the results.
+/// Results are zeroed out when the corresponding element in `b` is zero.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sign_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { psignw(a, b) }
+}
+
+/// Negate packed 32-bit integers in `a` when the corresponding signed
+/// 32-bit integer in `b` is negative, and return the results.
+/// Results are zeroed out when the corresponding element in `b` is zero.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sign_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { psignd(a, b) }
+}
+
+/// Negate packed 8-bit integers in `a` when the corresponding signed
+/// 8-bit integer in `b` is negative, and return the results.
+/// Results are zeroed out when the corresponding element in `b` is zero.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sign_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe { psignb(a, b) }
+}
+
+/// Shift packed 16-bit integers in `a` left by `count` while
+/// shifting in zeros, and return the result
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sll_epi16(a: i16x16, count: i16x8) -> i16x16 {
+    unsafe { psllw(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` left by `count` while
+/// shifting in zeros, and return the result
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sll_epi32(a: i32x8, count: i32x4) -> i32x8 {
+    unsafe { pslld(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` left by `count` while
+/// shifting in zeros, and return the result
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sll_epi64(a: i64x4, count: i64x2) -> i64x4 {
+    unsafe { psllq(a, count) }
+}
+
+/// Shift packed 16-bit integers in `a` left by `imm8` while
+/// shifting in zeros, return the results;
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_slli_epi16(a: i16x16, imm8: i32) -> i16x16 {
+    unsafe { pslliw(a, imm8) }
+}
+
+/// Shift packed 32-bit integers in `a` left by `imm8` while
+/// shifting in zeros, return the results;
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_slli_epi32(a: i32x8, imm8: i32) -> i32x8 {
+    unsafe { psllid(a, imm8) }
+}
+
+/// Shift packed 64-bit integers in `a` left by `imm8` while
+/// shifting in zeros, return the results;
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_slli_epi64(a: i64x4, imm8: i32) -> i64x4 {
+    unsafe { pslliq(a, imm8) }
+}
+
+/// Shift 128-bit lanes in `a` left by `imm8` bytes while shifting in zeros.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_slli_si256(a: __m256i, imm8: i32) -> __m256i {
+    let imm8 = imm8 as u32 & 0xFF;
+    if imm8 > 15 {
+        return __m256i::splat(0);
+    }
+    // Shifting the concatenation of a zero lane and a lane of `a` right by
+    // `16 - imm8` bytes leaves the lane of `a` shifted left by `imm8` bytes.
+    _mm256_alignr_epi8(a, __m256i::splat(0), (16 - imm8) as i32)
+}
+
+/// Shift packed 32-bit integers in `a` left by the amount
+/// specified by the corresponding element in `count` while
+/// shifting in zeros, and return the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_sllv_epi32(a: i32x4, count: i32x4) -> i32x4 {
+    unsafe { psllvd(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` left by the amount
+/// specified by the corresponding element in `count` while
+/// shifting in zeros, and return the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sllv_epi32(a: i32x8, count: i32x8) -> i32x8 {
+    unsafe { psllvd256(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` left by the amount
+/// specified by the corresponding element in `count` while
+/// shifting in zeros, and return the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_sllv_epi64(a: i64x2, count: i64x2) -> i64x2 {
+    unsafe { psllvq(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` left by the amount
+/// specified by the corresponding element in `count` while
+/// shifting in zeros, and return the result.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sllv_epi64(a: i64x4, count: i64x4) -> i64x4 {
+    unsafe { psllvq256(a, count) }
+}
+
+/// Shift packed 16-bit integers in `a` right by `count` while
+/// shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sra_epi16(a: i16x16, count: i16x8) -> i16x16 {
+    unsafe { psraw(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` right by `count` while
+/// shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sra_epi32(a: i32x8, count: i32x4) -> i32x8 {
+    unsafe { psrad(a, count) }
+}
+
+/// Shift packed 16-bit integers in `a` right by `imm8` while
+/// shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srai_epi16(a: i16x16, imm8: i32) -> i16x16 {
+    unsafe { psraiw(a, imm8) }
+}
+
+/// Shift packed 32-bit integers in `a` right by `imm8` while
+/// shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srai_epi32(a: i32x8, imm8: i32) -> i32x8 {
+    unsafe { psraid(a, imm8) }
+}
+
+/// Shift packed 32-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_srav_epi32(a: i32x4, count: i32x4) -> i32x4 {
+    unsafe { psravd(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` right by the amount specified by the
+/// corresponding element in `count` while shifting in sign bits.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srav_epi32(a: i32x8, count: i32x8) -> i32x8 {
+    unsafe { psravd256(a, count) }
+}
+
+
+/// Shift packed 16-bit integers in `a` right by `count` while shifting in
+/// zeros.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srl_epi16(a: i16x16, count: i16x8) -> i16x16 {
+    unsafe { psrlw(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` right by `count` while shifting in
+/// zeros.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srl_epi32(a: i32x8, count: i32x4) -> i32x8 {
+    unsafe { psrld(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` right by `count` while shifting in
+/// zeros.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srl_epi64(a: i64x4, count: i64x2) -> i64x4 {
+    unsafe { psrlq(a, count) }
+}
+
+/// Shift packed 16-bit integers in `a` right by `imm8` while shifting in
+/// zeros
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srli_epi16(a: i16x16, imm8: i32) -> i16x16 {
+    unsafe { psrliw(a, imm8) }
+}
+
+/// Shift packed 32-bit integers in `a` right by `imm8` while shifting in
+/// zeros
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srli_epi32(a: i32x8, imm8: i32) -> i32x8 {
+    unsafe { psrlid(a, imm8) }
+}
+
+/// Shift packed 64-bit integers in `a` right by `imm8` while shifting in
+/// zeros
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srli_epi64(a: i64x4, imm8: i32) -> i64x4 {
+    unsafe { psrliq(a, imm8) }
+}
+
+/// Shift 128-bit lanes in `a` right by `imm8` bytes while shifting in
+/// zeros.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srli_si256(a: __m256i, imm8: i32) -> __m256i {
+    _mm256_alignr_epi8(__m256i::splat(0), a, imm8)
+}
+
+/// Shift packed 32-bit integers in `a` right by the amount specified by
+/// the corresponding element in `count` while shifting in zeros,
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_srlv_epi32(a: i32x4, count: i32x4) -> i32x4 {
+    unsafe { psrlvd(a, count) }
+}
+
+/// Shift packed 32-bit integers in `a` right by the amount specified by
+/// the corresponding element in `count` while shifting in zeros,
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srlv_epi32(a: i32x8, count: i32x8) -> i32x8 {
+    unsafe { psrlvd256(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` right by the amount specified by
+/// the corresponding element in `count` while shifting in zeros,
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm_srlv_epi64(a: i64x2, count: i64x2) -> i64x2 {
+    unsafe { psrlvq(a, count) }
+}
+
+/// Shift packed 64-bit integers in `a` right by the amount specified by
+/// the corresponding element in `count` while shifting in zeros,
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_srlv_epi64(a: i64x4, count: i64x4) -> i64x4 {
+    unsafe { psrlvq256(a, count) }
+}
+
+/// Load 256-bits of integer data from memory using a non-temporal memory
+/// hint.
+///
+/// `mem_addr` must be aligned on a 32-byte boundary or a general-protection
+/// exception may be generated.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub unsafe fn _mm256_stream_load_si256(mem_addr: *const __m256i) -> __m256i {
+    __m256i::from(movntdqa(mem_addr as *const i8))
+}
+
+/// Subtract packed 16-bit integers in `b` from packed 16-bit integers in `a`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sub_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    a - b
+}
+
+/// Subtract packed 32-bit integers in `b` from packed 16-bit integers in `a`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sub_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    a - b
+}
+
+/// Subtract packed 64-bit integers in `b` from packed 16-bit integers in `a`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sub_epi64(a: i64x4, b: i64x4) -> i64x4 {
+    a - b
+}
+
+/// Subtract packed 8-bit integers in `b` from packed 16-bit integers in `a`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_sub_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    a - b
+}
+
+/// Subtract packed 16-bit integers in `b` from packed 16-bit integers in
+/// `a` using saturation.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_subs_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe { psubsw(a, b) }
+}
+
+/// Subtract packed 8-bit integers in `b` from packed 8-bit integers in
+/// `a` using saturation.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_subs_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe { psubsb(a, b) }
+}
+
+/// Subtract packed unsigned 16-bit integers in `b` from packed 16-bit
+/// integers in `a` using saturation.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_subs_epu16(a: u16x16, b: u16x16) -> u16x16 {
+    unsafe { psubusw(a, b) }
+}
+
+/// Subtract packed unsigned 8-bit integers in `b` from packed 8-bit
+/// integers in `a` using saturation.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_subs_epu8(a: u8x32, b: u8x32) -> u8x32 {
+    unsafe { psubusb(a, b) }
+}
+
+/// Unpack and interleave 8-bit integers from the high half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpackhi_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe {
+        simd_shuffle32(a, b, [
+            8, 40, 9, 41, 10, 42, 11, 43,
+            12, 44, 13, 45, 14, 46, 15, 47,
+            24, 56, 25, 57, 26, 58, 27, 59,
+            28, 60, 29, 61, 30, 62, 31, 63,
+        ])
+    }
+}
+
+/// Unpack and interleave 16-bit integers from the high half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpackhi_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe {
+        simd_shuffle16(a, b, [
+            4, 20, 5, 21, 6, 22, 7, 23,
+            12, 28, 13, 29, 14, 30, 15, 31,
+        ])
+    }
+}
+
+/// Unpack and interleave 32-bit integers from the high half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpackhi_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { simd_shuffle8(a, b, [2, 10, 3, 11, 6, 14, 7, 15]) }
+}
+
+/// Unpack and interleave 64-bit integers from the high half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpackhi_epi64(a: i64x4, b: i64x4) -> i64x4 {
+    unsafe { simd_shuffle4(a, b, [1, 5, 3, 7]) }
+}
+
+/// Unpack and interleave 8-bit integers from the low half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpacklo_epi8(a: i8x32, b: i8x32) -> i8x32 {
+    unsafe {
+        simd_shuffle32(a, b, [
+            0, 32, 1, 33, 2, 34, 3, 35,
+            4, 36, 5, 37, 6, 38, 7, 39,
+            16, 48, 17, 49, 18, 50, 19, 51,
+            20, 52, 21, 53, 22, 54, 23, 55,
+        ])
+    }
+}
+
+/// Unpack and interleave 16-bit integers from the low half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpacklo_epi16(a: i16x16, b: i16x16) -> i16x16 {
+    unsafe {
+        simd_shuffle16(a, b, [
+            0, 16, 1, 17, 2, 18, 3, 19,
+            8, 24, 9, 25, 10, 26, 11, 27,
+        ])
+    }
+}
+
+/// Unpack and interleave 32-bit integers from the low half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpacklo_epi32(a: i32x8, b: i32x8) -> i32x8 {
+    unsafe { simd_shuffle8(a, b, [0, 8, 1, 9, 4, 12, 5, 13]) }
+}
+
+/// Unpack and interleave 64-bit integers from the low half of each
+/// 128-bit lane in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_unpacklo_epi64(a: i64x4, b: i64x4) -> i64x4 {
+    unsafe { simd_shuffle4(a, b, [0, 4, 2, 6]) }
+}
+
+/// Compute the bitwise XOR of 256 bits (representing integer data)
+/// in `a` and `b`
+#[inline(always)]
+#[target_feature = "+avx2"]
+pub fn _mm256_xor_si256(a: __m256i, b: __m256i) -> __m256i {
+    a ^ b
+}
+
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx2.pabs.b"]
+    fn pabsb(a: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.avx2.pabs.w"]
+    fn pabsw(a: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.pabs.d"]
+    fn pabsd(a: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.padds.b"]
+    fn paddsb(a: i8x32, b: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.avx2.padds.w"]
+    fn paddsw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.paddus.b"]
+    fn paddusb(a: u8x32, b: u8x32) -> u8x32;
+    #[link_name = "llvm.x86.avx2.paddus.w"]
+    fn paddusw(a: u16x16, b: u16x16) -> u16x16;
+    #[link_name = "llvm.x86.avx2.pavg.b"]
+    fn pavgb(a: u8x32, b: u8x32) -> u8x32;
+    #[link_name = "llvm.x86.avx2.pavg.w"]
+    fn pavgw(a: u16x16, b: u16x16) -> u16x16;
+    #[link_name = "llvm.x86.avx2.pblendvb"]
+    fn pblendvb(a: i8x32, b: i8x32, mask: __m256i) -> i8x32;
+    #[link_name = "llvm.x86.avx2.phadd.w"]
+    fn phaddw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.phadd.d"]
+    fn phaddd(a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.phadd.sw"]
+    fn phaddsw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.phsub.w"]
+    fn phsubw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.phsub.d"]
+    fn phsubd(a: i32x8, b: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.phsub.sw"]
+    fn phsubsw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.gather.d.d"]
+    fn pgatherdd(
+        src: i32x4, slice: *const i8, offsets: i32x4, mask: i32x4, scale: i8,
+    ) -> i32x4;
+    #[link_name = "llvm.x86.avx2.gather.d.d.256"]
+    fn pgatherdd256(
+        src: i32x8, slice: *const i8, offsets: i32x8, mask: i32x8, scale: i8,
+    ) -> i32x8;
+    #[link_name = "llvm.x86.avx2.gather.d.ps"]
+    fn pgatherdps(
+        src: f32x4, slice: *const i8, offsets: i32x4, mask: f32x4, scale: i8,
+    ) -> f32x4;
+    #[link_name = "llvm.x86.avx2.gather.d.ps.256"]
+    fn pgatherdps256(
+        src: f32x8, slice: *const i8, offsets: i32x8, mask: f32x8, scale: i8,
+    ) -> f32x8;
+    #[link_name = "llvm.x86.avx2.gather.d.q"]
+    fn pgatherdq(
+        src: i64x2, slice: *const i8, offsets: i32x4, mask: i64x2, scale: i8,
+    ) -> i64x2;
+    #[link_name = "llvm.x86.avx2.gather.d.q.256"]
+    fn pgatherdq256(
+        src: i64x4, slice: *const i8, offsets: i32x4, mask: i64x4, scale: i8,
+    ) -> i64x4;
+    #[link_name = "llvm.x86.avx2.gather.d.pd"]
+    fn pgatherdpd(
+        src: f64x2, slice: *const i8, offsets: i32x4, mask: f64x2, scale: i8,
+    ) -> f64x2;
+    #[link_name = "llvm.x86.avx2.gather.d.pd.256"]
+    fn pgatherdpd256(
+        src: f64x4, slice: *const i8, offsets: i32x4, mask: f64x4, scale: i8,
+    ) -> f64x4;
+    #[link_name = "llvm.x86.avx2.gather.q.d"]
+    fn pgatherqd(
+        src: i32x4, slice: *const i8, offsets: i64x2, mask: i32x4, scale: i8,
+    ) -> i32x4;
+    #[link_name = "llvm.x86.avx2.gather.q.d.256"]
+    fn pgatherqd256(
+        src: i32x4, slice: *const i8, offsets: i64x4, mask: i32x4, scale: i8,
+    ) -> i32x4;
+    #[link_name = "llvm.x86.avx2.gather.q.q"]
+    fn pgatherqq(
+        src: i64x2, slice: *const i8, offsets: i64x2, mask: i64x2, scale: i8,
+    ) -> i64x2;
+    #[link_name = "llvm.x86.avx2.gather.q.q.256"]
+    fn pgatherqq256(
+        src: i64x4, slice: *const i8, offsets: i64x4, mask: i64x4, scale: i8,
+    ) -> i64x4;
+    #[link_name = "llvm.x86.avx2.gather.q.pd"]
+    fn pgatherqpd(
+        src: f64x2, slice: *const i8, offsets: i64x2, mask: f64x2, scale: i8,
+    ) -> f64x2;
+    #[link_name = "llvm.x86.avx2.gather.q.pd.256"]
+    fn pgatherqpd256(
+        src: f64x4, slice: *const i8, offsets: i64x4, mask: f64x4, scale: i8,
+    ) -> f64x4;
+    #[link_name = "llvm.x86.avx2.gather.q.ps"]
+    fn pgatherqps(
+        src: f32x4, slice: *const i8, offsets: i64x2, mask: f32x4, scale: i8,
+    ) -> f32x4;
+    #[link_name = "llvm.x86.avx2.gather.q.ps.256"]
+    fn pgatherqps256(
+        src: f32x4, slice: *const i8, offsets: i64x4, mask: f32x4, scale: i8,
+    ) -> f32x4;
+    #[link_name = "llvm.x86.avx2.maskload.d"]
+    fn maskloadd(mem_addr: *const i8, mask: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.avx2.maskload.d.256"]
+    fn maskloadd256(mem_addr: *const i8, mask: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.maskload.q"]
+    fn maskloadq(mem_addr: *const i8, mask: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.avx2.maskload.q.256"]
+    fn maskloadq256(mem_addr: *const i8, mask: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.avx2.maskstore.d"]
+    fn maskstored(mem_addr: *mut i8, mask: i32x4, a: i32x4);
+    #[link_name = "llvm.x86.avx2.maskstore.d.256"]
+    fn maskstored256(mem_addr: *mut i8, mask: i32x8, a: i32x8);
+    #[link_name = "llvm.x86.avx2.maskstore.q"]
+    fn maskstoreq(mem_addr: *mut i8, mask: i64x2, a: i64x2);
+    #[link_name = "llvm.x86.avx2.maskstore.q.256"]
+    fn maskstoreq256(mem_addr: *mut i8, mask: i64x4, a: i64x4);
+    #[link_name = "llvm.x86.avx2.pmadd.wd"]
+    fn pmaddwd(a: i16x16, b: i16x16) -> i32x8;
+    #[link_name = "llvm.x86.avx2.pmadd.ub.sw"]
+    fn pmaddubsw(a: u8x32, b: u8x32) -> i16x16;
+    #[link_name = "llvm.x86.avx2.pmaxs.w"]
+    fn pmaxsw(a: i16x16, b: i16x16) -> i16x16;
+    #[link_name = "llvm.x86.avx2.pmaxs.d"]
     fn pmaxsd(a: i32x8, b: i32x8) -> i32x8;
     #[link_name = "llvm.x86.avx2.pmaxs.b"]
     fn pmaxsb(a: i8x32, b: i8x32) -> i8x32;
@@ -950,10 +2461,10 @@ extern "C" {
     fn pminud(a: u32x8, b: u32x8) -> u32x8;
     #[link_name = "llvm.x86.avx2.pminu.b"]
     fn pminub(a: u8x32, b: u8x32) -> u8x32;
-    #[link_name = "llvm.x86.avx2.pmovmskb"]  //fails in debug
+    #[link_name = "llvm.x86.avx2.pmovmskb"]
     fn pmovmskb(a: i8x32) -> i32;
-    #[link_name = "llvm.x86.avx2.mpsadbw"] //fails in debug
-    fn mpsadbw(a: u8x32, b: u8x32, imm8: i32) -> u16x16;
+    #[link_name = "llvm.x86.avx2.mpsadbw"]
+    fn mpsadbw(a: u8x32, b: u8x32, imm8: u8) -> u16x16;
     #[link_name = "llvm.x86.avx2.pmulhu.w"]
     fn pmulhuw(a: u16x16, b: u16x16) -> u16x16;
     #[link_name = "llvm.x86.avx2.pmulh.w"]
@@ -972,8 +2483,16 @@ extern "C" {
     fn packuswb(a: i16x16, b: i16x16) -> u8x32;
     #[link_name = "llvm.x86.avx2.packusdw"]
     fn packusdw(a: i32x8, b: i32x8) -> u16x16;
+    #[link_name = "llvm.x86.avx2.vperm2i128"]
+    fn vperm2i128(a: i64x4, b: i64x4, imm8: u8) -> i64x4;
+    #[link_name = "llvm.x86.avx2.permd"]
+    fn permd(a: i32x8, idx: i32x8) -> i32x8;
+    #[link_name = "llvm.x86.avx2.permps"]
+    fn permps(a: __m256, idx: i32x8) -> __m256;
     #[link_name = "llvm.x86.avx2.psad.bw"]
     fn psadbw(a: u8x32, b: u8x32) -> u64x4;
+    #[link_name = "llvm.x86.avx2.pshuf.b"]
+    fn pshufb(a: u8x32, b: u8x32) -> u8x32;
     #[link_name = "llvm.x86.avx2.psign.b"]
     fn psignb(a: i8x32, b: i8x32) -> i8x32;
     #[link_name = "llvm.x86.avx2.psign.w"]
@@ -1032,6 +2551,8 @@ extern "C" {
     fn psrlvq(a: i64x2, count: i64x2) -> i64x2;
     #[link_name = "llvm.x86.avx2.psrlv.q.256"]
     fn psrlvq256(a: i64x4, count: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.avx2.movntdqa"]
+    fn movntdqa(mem_addr: *const i8) -> i64x4;
     #[link_name = "llvm.x86.avx2.psubs.b"]
     fn psubsb(a: i8x32, b: i8x32) -> i8x32;
     #[link_name = "llvm.x86.avx2.psubs.w"]
@@ -1041,414 +2562,1267 @@ extern "C" {
     #[link_name = "llvm.x86.avx2.psubus.w"]
     fn psubusw(a: u16x16, b: u16x16) -> u16x16;
 
-}
+}
+
+
+#[cfg(test)]
+mod tests {
+    use v256::*;
+    use v128::*;
+    use x86::avx2;
+    use x86::{__m128i, __m256i};
+    use std;
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_abs_epi32() {
+        let a = i32x8::new(
+            0, 1, -1, std::i32::MAX,
+            std::i32::MIN + 1, 100, -100, -32);
+        let r = avx2::_mm256_abs_epi32(a);
+        let e = i32x8::new(
+            0, 1, 1, std::i32::MAX,
+            (std::i32::MIN + 1).abs(), 100, 100, 32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_abs_epi16() {
+        let a = i16x16::new(
+            0, 1, -1, 2,
+            -2, 3, -3, 4,
+            -4, 5, -5, std::i16::MAX,
+            std::i16::MIN + 1, 100, -100, -32);
+        let r = avx2::_mm256_abs_epi16(a);
+        let e = i16x16::new(
+            0, 1, 1, 2,
+            2, 3, 3, 4,
+            4, 5, 5, std::i16::MAX,
+            (std::i16::MIN + 1).abs(), 100, 100, 32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_abs_epi8() {
+        let a = i8x32::new(
+            0, 1, -1, 2,
+            -2, 3, -3, 4,
+            -4, 5, -5, std::i8::MAX,
+            std::i8::MIN + 1, 100, -100, -32,
+            0, 1, -1, 2,
+            -2, 3, -3, 4,
+            -4, 5, -5, std::i8::MAX,
+            std::i8::MIN + 1, 100, -100, -32);
+        let r = avx2::_mm256_abs_epi8(a);
+        let e = i8x32::new(
+            0, 1, 1, 2, 2, 3, 3, 4,
+            4, 5, 5, std::i8::MAX, (std::i8::MIN + 1).abs(), 100, 100, 32,
+            0, 1, 1, 2, 2, 3, 3, 4,
+            4, 5, 5, std::i8::MAX, (std::i8::MIN + 1).abs(), 100, 100, 32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_add_epi64() {
+        let a = i64x4::new(-10, 0, 100, 1_000_000_000);
+        let b = i64x4::new(-1, 0, 1, 2);
+        let r = avx2::_mm256_add_epi64(a, b);
+        let e = i64x4::new(-11, 0, 101, 1_000_000_002);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_add_epi32() {
+        let a = i32x8::new(-1, 0, 1, 2, 3, 4, 5, 6);
+        let b = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let r = avx2::_mm256_add_epi32(a, b);
+        let e = i32x8::new(0, 2, 4, 6, 8, 10, 12, 14);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_add_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15);
+        let b = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15);
+        let r = avx2::_mm256_add_epi16(a, b);
+        let e = i16x16::new(
+            0, 2, 4, 6, 8, 10, 12, 14,
+            16, 18, 20, 22, 24, 26, 28, 30);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_add_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31);
+        let b = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31);
+        let r = avx2::_mm256_add_epi8(a, b);
+        let e = i8x32::new(
+            0, 2, 4, 6, 8, 10, 12, 14, 16,
+            18, 20, 22, 24, 26, 28, 30, 32,
+            34, 36, 38, 40, 42, 44, 46, 48,
+            50, 52, 54, 56, 58, 60, 62);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+        let b = i8x32::new(
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63);
+        let r = avx2::_mm256_adds_epi8(a, b);
+        let e = i8x32::new(
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+            64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi8_saturate_positive() {
+        let a = i8x32::splat(0x7F);
+        let b = i8x32::splat(1);
+        let r = avx2::_mm256_adds_epi8(a, b);
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi8_saturate_negative() {
+        let a = i8x32::splat(-0x80);
+        let b = i8x32::splat(-1);
+        let r = avx2::_mm256_adds_epi8(a, b);
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = i16x16::new(
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47);
+        let r = avx2::_mm256_adds_epi16(a,  b);
+        let e = i16x16::new(
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi16_saturate_positive() {
+        let a = i16x16::splat(0x7FFF);
+        let b = i16x16::splat(1);
+        let r = avx2::_mm256_adds_epi16(a, b);
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epi16_saturate_negative() {
+        let a = i16x16::splat(-0x8000);
+        let b = i16x16::splat(-1);
+        let r = avx2::_mm256_adds_epi16(a, b);
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epu8() {
+        let a = u8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+        let b = u8x32::new(
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63);
+        let r = avx2::_mm256_adds_epu8(a, b);
+        let e = u8x32::new(
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+            64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epu8_saturate() {
+        let a = u8x32::splat(0xFF);
+        let b = u8x32::splat(1);
+        let r = avx2::_mm256_adds_epu8(a, b);
+        assert_eq!(r, a);
+    }
+
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epu16() {
+        let a = u16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = u16x16::new(
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47);
+        let r = avx2::_mm256_adds_epu16(a, b);
+        let e = u16x16::new(
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_adds_epu16_saturate() {
+        let a = u16x16::splat(0xFFFF);
+        let b = u16x16::splat(1);
+        let r = avx2::_mm256_adds_epu16(a, b);
+        assert_eq!(r, a);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_alignr_epi8() {
+        let a = i8x32::new(
+            1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32);
+        let b = i8x32::new(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24,
+            -25, -26, -27, -28, -29, -30, -31, -32);
+        let r = avx2::_mm256_alignr_epi8(a, b, 33);
+        assert_eq!(r, i8x32::splat(0));
+
+        let r = avx2::_mm256_alignr_epi8(a, b, 18);
+        let expected = i8x32::new(
+            3, 4, 5, 6, 7, 8, 9, 10,
+            11, 12, 13, 14, 15, 16, 0, 0,
+            19, 20, 21, 22, 23, 24, 25, 26,
+            27, 28, 29, 30, 31, 32, 0, 0);
+        assert_eq!(r, expected);
+
+        let r = avx2::_mm256_alignr_epi8(a, b, 16);
+        assert_eq!(r, a);
+
+        let r = avx2::_mm256_alignr_epi8(a, b, 2);
+        let expected = i8x32::new(
+            -3, -4, -5, -6, -7, -8, -9, -10,
+            -11, -12, -13, -14, -15, -16, 1, 2,
+            -19, -20, -21, -22, -23, -24, -25, -26,
+            -27, -28, -29, -30, -31, -32, 17, 18);
+        assert_eq!(r, expected);
+
+        let r = avx2::_mm256_alignr_epi8(a, b, 0);
+        assert_eq!(r, b);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_and_si256() {
+        assert_eq!(
+            avx2::_mm256_and_si256(
+                __m256i::splat(5), __m256i::splat(3)),__m256i::splat(1));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_andnot_si256() {
+        assert_eq!(
+            avx2::_mm256_andnot_si256(__m256i::splat(5), __m256i::splat(3)),
+            __m256i::splat(2));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_avg_epu8() {
+        let (a, b) = (u8x32::splat(3), u8x32::splat(9));
+        let r = avx2::_mm256_avg_epu8(a, b);
+        assert_eq!(r, u8x32::splat(6));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_avg_epu16() {
+        let (a, b) = (u16x16::splat(3), u16x16::splat(9));
+        let r = avx2::_mm256_avg_epu16(a, b);
+        assert_eq!(r, u16x16::splat(6));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_blend_epi16() {
+        let (a, b) = (i16x16::splat(9), i16x16::splat(-9));
+        let r = avx2::_mm256_blend_epi16(a, b, 0x01);
+        assert_eq!(r, a.replace(0, -9).replace(8, -9));
+
+        let r = avx2::_mm256_blend_epi16(b, a, 0xFE);
+        assert_eq!(r, a.replace(0, -9).replace(8, -9));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_blend_epi32() {
+        let (a, b) = (i32x4::splat(3), i32x4::splat(9));
+        let r = avx2::_mm_blend_epi32(a, b, 0x01);
+        assert_eq!(r, i32x4::new(9, 3, 3, 3));
+
+        let r = avx2::_mm_blend_epi32(a, b, 0x0E);
+        assert_eq!(r, i32x4::new(3, 9, 9, 9));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_blend_epi32() {
+        let (a, b) = (i32x8::splat(3), i32x8::splat(9));
+        let r = avx2::_mm256_blend_epi32(a, b, 0x01);
+        assert_eq!(r, i32x8::new(9, 3, 3, 3, 3, 3, 3, 3));
+
+        let r = avx2::_mm256_blend_epi32(a, b, 0x82);
+        assert_eq!(r, i32x8::new(3, 9, 3, 3, 3, 3, 3, 9));
+
+        let r = avx2::_mm256_blend_epi32(a, b, 0x7c);
+        assert_eq!(r, i32x8::new(3, 3, 9, 9, 9, 9, 9, 3));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_blendv_epi8() {
+        let (a,b) = (i8x32::splat(4),i8x32::splat(2));
+        let mask = i8x32::splat(0).replace(2,-1);
+        let e = i8x32::splat(4).replace(2,2);
+        let r= avx2::_mm256_blendv_epi8(a,b,mask);
+        assert_eq!(r,e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastb_epi8() {
+        let a = i8x16::splat(0x00).replace(0, 0x2a);
+        let r = avx2::_mm_broadcastb_epi8(a);
+        assert_eq!(r, i8x16::splat(0x2a));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastb_epi8() {
+        let a = i8x16::splat(0x00).replace(0, 0x2a);
+        let r = avx2::_mm256_broadcastb_epi8(a);
+        assert_eq!(r, i8x32::splat(0x2a));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastd_epi32() {
+        let a = i32x4::splat(0x00).replace(0, 0x2a).replace(1, 0x8000000);
+        let r = avx2::_mm_broadcastd_epi32(a);
+        assert_eq!(r, i32x4::splat(0x2a));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastd_epi32() {
+        let a = i32x4::splat(0x00).replace(0, 0x2a).replace(1, 0x8000000);
+        let r = avx2::_mm256_broadcastd_epi32(a);
+        assert_eq!(r, i32x8::splat(0x2a));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastq_epi64() {
+        let a = i64x2::splat(0x00).replace(0, 0x1ffffffff);
+        let r = avx2::_mm_broadcastq_epi64(a);
+        assert_eq!(r, i64x2::splat(0x1ffffffff));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastq_epi64() {
+        let a = i64x2::splat(0x00).replace(0, 0x1ffffffff);
+        let r = avx2::_mm256_broadcastq_epi64(a);
+        assert_eq!(r, i64x4::splat(0x1ffffffff));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastsd_pd() {
+        let a = f64x2::splat(3.14f64).replace(0, 6.28);
+        let r = avx2::_mm_broadcastsd_pd(a);
+        assert_eq!(r, f64x2::splat(6.28f64));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastsd_pd() {
+        let a = f64x2::splat(3.14f64).replace(0, 6.28);
+        let r = avx2::_mm256_broadcastsd_pd(a);
+        assert_eq!(r, f64x4::splat(6.28f64));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastsi128_si256() {
+        let a = i64x2::new(0x0987654321012334, 0x5678909876543210);
+        let a = __m128i::from(a);
+        let r = avx2::_mm_broadcastsi128_si256(a);
+        let e = i64x4::new(
+            0x0987654321012334, 0x5678909876543210,
+            0x0987654321012334, 0x5678909876543210);
+        assert_eq!(r, __m256i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastsi128_si256() {
+        let a = i64x2::new(0x0987654321012334, 0x5678909876543210);
+        let a = __m128i::from(a);
+        let r = avx2::_mm256_broadcastsi128_si256(a);
+        let e = i64x4::new(
+            0x0987654321012334, 0x5678909876543210,
+            0x0987654321012334, 0x5678909876543210);
+        assert_eq!(r, __m256i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastss_ps() {
+        let a = f32x4::splat(3.14f32).replace(0, 6.28);
+        let r = avx2::_mm_broadcastss_ps(a);
+        assert_eq!(r, f32x4::splat(6.28f32));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastss_ps() {
+        let a = f32x4::splat(3.14f32).replace(0, 6.28);
+        let r = avx2::_mm256_broadcastss_ps(a);
+        assert_eq!(r, f32x8::splat(6.28f32));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_broadcastw_epi16() {
+        let a = i16x8::splat(0x2a).replace(0, 0x22b);
+        let r = avx2::_mm_broadcastw_epi16(a);
+        assert_eq!(r, i16x8::splat(0x22b));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_broadcastw_epi16() {
+        let a = i16x8::splat(0x2a).replace(0, 0x22b);
+        let r = avx2::_mm256_broadcastw_epi16(a);
+        assert_eq!(r, i16x16::splat(0x22b));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_bslli_epi128() {
+        let a = __m256i::from(i64x4::new(-1, 0, -1, 0));
+        let r = avx2::_mm256_bslli_epi128(a, 3);
+        let e = i64x4::new(-1 << 24, 0xFF_FFFF, -1 << 24, 0xFF_FFFF);
+        assert_eq!(r, __m256i::from(e));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_bsrli_epi128() {
+        let a = __m256i::from(i64x4::new(0, 0, -1, -1));
+        let r = avx2::_mm256_bsrli_epi128(a, 3);
+        let e = i64x4::new(0, 0, -1, 0xFF_FFFF_FFFF);
+        assert_eq!(r, __m256i::from(e));
+    }
+
+    #[test]
+    fn _mm256_cmpeq_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+        let b = i8x32::new(
+            31, 30, 2, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let r = avx2::_mm256_cmpeq_epi8(a, b);
+        assert_eq!(r, i8x32::splat(0).replace(2,0xFFu8 as i8));
+    }
+
+    #[test]
+    fn _mm256_cmpeq_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = i16x16::new(
+            15, 14, 2, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+        let r = avx2::_mm256_cmpeq_epi16(a, b);
+        assert_eq!(r, i16x16::splat(0).replace(2, 0xFFFFu16 as i16));
+    }
+
+    #[test]
+    fn _mm256_cmpeq_epi32() {
+        let a = i32x8::new(0, 1, 2, 3,4,5,6,7);
+        let b = i32x8::new(7,6,2,4,3, 2, 1, 0);
+        let r = avx2::_mm256_cmpeq_epi32(a, b);
+        assert_eq!(r, i32x8::splat(0).replace(2, 0xFFFFFFFFu32 as i32));
+    }
+
+    #[test]
+    fn _mm256_cmpeq_epi64() {
+        let a = i64x4::new(0, 1, 2, 3);
+        let b = i64x4::new(3, 2, 2, 0);
+        let r = avx2::_mm256_cmpeq_epi64(a, b);
+        assert_eq!(r, i64x4::splat(0).replace(
+            2, 0xFFFFFFFFFFFFFFFFu64 as i64));
+    }
+
+    #[test]
+    fn _mm256_cmpgt_epi8() {
+        let a = i8x32::splat(0).replace(0, 5);
+        let b = i8x32::splat(0);
+        let r = avx2::_mm256_cmpgt_epi8(a, b);
+        assert_eq!(r, i8x32::splat(0).replace(0, 0xFFu8 as i8));
+    }
+
+    #[test]
+    fn _mm256_cmpgt_epi16() {
+        let a = i16x16::splat(0).replace(0, 5);
+        let b = i16x16::splat(0);
+        let r = avx2::_mm256_cmpgt_epi16(a, b);
+        assert_eq!(r, i16x16::splat(0).replace(0, 0xFFFFu16 as i16));
+    }
+
+    #[test]
+    fn _mm256_cmpgt_epi32() {
+        let a = i32x8::splat(0).replace(0, 5);
+        let b = i32x8::splat(0);
+        let r = avx2::_mm256_cmpgt_epi32(a, b);
+        assert_eq!(r, i32x8::splat(0).replace(0, 0xFFFFFFFFu32 as i32));
+    }
+
+    #[test]
+    fn _mm256_cmpgt_epi64() {
+        let a = i64x4::splat(0).replace(0, 5);
+        let b = i64x4::splat(0);
+        let r = avx2::_mm256_cmpgt_epi64(a, b);
+        assert_eq!(r, i64x4::splat(0).replace(
+            0, 0xFFFFFFFFFFFFFFFFu64 as i64));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepi16_epi32() {
+        let a = i16x8::new(0, 0, -1, 1, -2, 2, -3, 3);
+        let r = avx2::_mm256_cvtepi16_epi32(a);
+        assert_eq!(r, i32x8::new(0, 0, -1, 1, -2, 2, -3, 3));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepi16_epi64() {
+        let a = i16x8::new(0, 0, -1, 1, -2, 2, -3, 3);
+        let r = avx2::_mm256_cvtepi16_epi64(a);
+        assert_eq!(r, i64x4::new(0, 0, -1, 1));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepi32_epi64() {
+        let a = i32x4::new(0, 0, -1, 1);
+        let r = avx2::_mm256_cvtepi32_epi64(a);
+        assert_eq!(r, i64x4::new(0, 0, -1, 1));
+    }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepi8_epi16() {
+        let a = i8x16::new(
+            0, 0, -1, 1, -2, 2, -3, 3, -4, 4, -5, 5, -6, 6, -7, 7);
+        let r = avx2::_mm256_cvtepi8_epi16(a);
+        let e = i16x16::new(
+            0, 0, -1, 1, -2, 2, -3, 3, -4, 4, -5, 5, -6, 6, -7, 7);
+        assert_eq!(r, e);
+    }
 
-#[cfg(test)]
-mod tests {
-    use v256::*;
-    use v128::*;
-    use x86::avx2;
-    use x86::__m256i;
-    use std;
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepi8_epi32() {
+        let a = i8x16::new(
+            0, 0, -1, 1, -2, 2, -3, 3, -4, 4, -5, 5, -6, 6, -7, 7);
+        let r = avx2::_mm256_cvtepi8_epi32(a);
+        assert_eq!(r, i32x8::new(0, 0, -1, 1, -2, 2, -3, 3));
+
+        let a = i8x16::new(
+            -128, 127, -127, 126, -1, 1, -64, 64, 9, 9, 9, 9, 9, 9, 9, 9);
+        let r = avx2::_mm256_cvtepi8_epi32(a);
+        assert_eq!(r, i32x8::new(-128, 127, -127, 126, -1, 1, -64, 64));
+    }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_abs_epi32() {
-        let a = i32x8::new(
-            0, 1, -1, std::i32::MAX,
-            std::i32::MIN + 1, 100, -100, -32);
-        let r = avx2::_mm256_abs_epi32(a);
-        let e = i32x8::new(
-            0, 1, 1, std::i32::MAX,
-            (std::i32::MIN + 1).abs(), 100, 100, 32);
-        assert_eq!(r, e);
+    fn _mm256_cvtepi8_epi64() {
+        let a = i8x16::new(
+            0, 0, -1, 1, -2, 2, -3, 3, -4, 4, -5, 5, -6, 6, -7, 7);
+        let r = avx2::_mm256_cvtepi8_epi64(a);
+        assert_eq!(r, i64x4::new(0, 0, -1, 1));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_abs_epi16() {
-        let a = i16x16::new(
-            0, 1, -1, 2,
-            -2, 3, -3, 4,
-            -4, 5, -5, std::i16::MAX,
-            std::i16::MIN + 1, 100, -100, -32);
-        let r = avx2::_mm256_abs_epi16(a);
+    fn _mm256_cvtepu16_epi32() {
+        let a = u16x8::new(0, 1, 2, 3, 4, 5, 6, 0xFFFF);
+        let r = avx2::_mm256_cvtepu16_epi32(a);
+        assert_eq!(r, i32x8::new(0, 1, 2, 3, 4, 5, 6, 0xFFFF));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepu16_epi64() {
+        let a = u16x8::new(0, 1, 2, 0xFFFF, 4, 5, 6, 7);
+        let r = avx2::_mm256_cvtepu16_epi64(a);
+        assert_eq!(r, i64x4::new(0, 1, 2, 0xFFFF));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepu32_epi64() {
+        let a = u32x4::new(0, 1, 2, 0xFFFF_FFFF);
+        let r = avx2::_mm256_cvtepu32_epi64(a);
+        assert_eq!(r, i64x4::new(0, 1, 2, 0xFFFF_FFFF));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_cvtepu8_epi16() {
+        let a = u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0xFF);
+        let r = avx2::_mm256_cvtepu8_epi16(a);
         let e = i16x16::new(
-            0, 1, 1, 2,
-            2, 3, 3, 4,
-            4, 5, 5, std::i16::MAX,
-            (std::i16::MIN + 1).abs(), 100, 100, 32);
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 0xFF);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_abs_epi8() {
-        let a = i8x32::new(
-            0, 1, -1, 2,
-            -2, 3, -3, 4,
-            -4, 5, -5, std::i8::MAX,
-            std::i8::MIN + 1, 100, -100, -32,
-            0, 1, -1, 2,
-            -2, 3, -3, 4,
-            -4, 5, -5, std::i8::MAX,
-            std::i8::MIN + 1, 100, -100, -32);
-        let r = avx2::_mm256_abs_epi8(a);
-        let e = i8x32::new(
-            0, 1, 1, 2, 2, 3, 3, 4,
-            4, 5, 5, std::i8::MAX, (std::i8::MIN + 1).abs(), 100, 100, 32,
-            0, 1, 1, 2, 2, 3, 3, 4,
-            4, 5, 5, std::i8::MAX, (std::i8::MIN + 1).abs(), 100, 100, 32);
+    fn _mm256_cvtepu8_epi32() {
+        let a = u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 0xFF, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = avx2::_mm256_cvtepu8_epi32(a);
+        assert_eq!(r, i32x8::new(0, 1, 2, 3, 4, 5, 6, 0xFF));
+
+        let a = u8x16::new(
+            0x80, 0xFF, 0x7F, 0xFE, 0x81, 1, 0xC0, 0x40,
+            9, 9, 9, 9, 9, 9, 9, 9);
+        let r = avx2::_mm256_cvtepu8_epi32(a);
+        let e = i32x8::new(0x80, 0xFF, 0x7F, 0xFE, 0x81, 1, 0xC0, 0x40);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_add_epi64() {
-        let a = i64x4::new(-10, 0, 100, 1_000_000_000);
-        let b = i64x4::new(-1, 0, 1, 2);
-        let r = avx2::_mm256_add_epi64(a, b);
-        let e = i64x4::new(-11, 0, 101, 1_000_000_002);
+    fn _mm256_cvtepu8_epi64() {
+        let a = u8x16::new(
+            0, 1, 2, 0xFF, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = avx2::_mm256_cvtepu8_epi64(a);
+        assert_eq!(r, i64x4::new(0, 1, 2, 0xFF));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_extracti128_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let r = avx2::_mm256_extracti128_si256(a, 0);
+        assert_eq!(r, __m128i::from(i64x2::new(1, 2)));
+        let r = avx2::_mm256_extracti128_si256(a, 1);
+        assert_eq!(r, __m128i::from(i64x2::new(3, 4)));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_hadd_epi16() {
+        let a = i16x16::splat(2);
+        let b = i16x16::splat(4);
+        let r = avx2::_mm256_hadd_epi16(a, b);
+        let e = i16x16::new(4, 4, 4, 4, 8, 8, 8, 8, 4, 4, 4, 4, 8, 8, 8, 8);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_add_epi32() {
-        let a = i32x8::new(-1, 0, 1, 2, 3, 4, 5, 6);
-        let b = i32x8::new(1, 2, 3, 4, 5, 6, 7, 8);
-        let r = avx2::_mm256_add_epi32(a, b);
-        let e = i32x8::new(0, 2, 4, 6, 8, 10, 12, 14);
+    fn _mm256_hadd_epi32() {
+        let a = i32x8::splat(2);
+        let b = i32x8::splat(4);
+        let r = avx2::_mm256_hadd_epi32(a, b);
+        let e = i32x8::new(4, 4, 8, 8, 4, 4, 8, 8);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_add_epi16() {
-        let a = i16x16::new(
-            0, 1, 2, 3, 4, 5, 6, 7,
-            8, 9, 10, 11, 12, 13, 14, 15);
-        let b = i16x16::new(
-            0, 1, 2, 3, 4, 5, 6, 7,
-            8, 9, 10, 11, 12, 13, 14, 15);
-        let r = avx2::_mm256_add_epi16(a, b);
+    fn _mm256_hadds_epi16() {
+        let a = i16x16::splat(2).replace(0,0x7FFF).replace(1,1);
+        let b = i16x16::splat(4);
+        let r = avx2::_mm256_hadds_epi16(a, b);
         let e = i16x16::new(
-            0, 2, 4, 6, 8, 10, 12, 14,
-            16, 18, 20, 22, 24, 26, 28, 30);
+            0x7FFF, 4, 4, 4, 8, 8, 8, 8, 4, 4, 4, 4, 8, 8, 8, 8);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature ="+avx2"]
+    fn _mm256_hsub_epi16() {
+        let a = i16x16::splat(2);
+        let b = i16x16::splat(4);
+        let r = avx2::_mm256_hsub_epi16(a, b);
+        let e = i16x16::splat(0);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_add_epi8() {
-        let a = i8x32::new(
-            0, 1, 2, 3, 4, 5, 6, 7,
-            8, 9, 10, 11, 12, 13, 14, 15,
-            16, 17, 18, 19, 20, 21, 22, 23,
-            24, 25, 26, 27, 28, 29, 30, 31);
-        let b = i8x32::new(
-            0, 1, 2, 3, 4, 5, 6, 7,
-            8, 9, 10, 11, 12, 13, 14, 15,
-            16, 17, 18, 19, 20, 21, 22, 23,
-            24, 25, 26, 27, 28, 29, 30, 31);
-        let r = avx2::_mm256_add_epi8(a, b);
-        let e = i8x32::new(
-            0, 2, 4, 6, 8, 10, 12, 14, 16,
-            18, 20, 22, 24, 26, 28, 30, 32,
-            34, 36, 38, 40, 42, 44, 46, 48,
-            50, 52, 54, 56, 58, 60, 62);
+    fn _mm256_hsub_epi32() {
+        let a = i32x8::splat(2);
+        let b = i32x8::splat(4);
+        let r = avx2::_mm256_hsub_epi32(a, b);
+        let e = i32x8::splat(0);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi8() {
-        let a = i8x32::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
-            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
-        let b = i8x32::new(
-            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
-            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63);
-        let r = avx2::_mm256_adds_epi8(a, b);
-        let e = i8x32::new(
-            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
-            64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94);
+    fn _mm256_hsubs_epi16() {
+        let a = i16x16::splat(2).replace(0,0x7FFF).replace(1,-1);
+        let b = i16x16::splat(4);
+        let r = avx2::_mm256_hsubs_epi16(a, b);
+        let e = i16x16::splat(0).replace(0,0x7FFF);
         assert_eq!(r, e);
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi8_saturate_positive() {
-        let a = i8x32::splat(0x7F);
-        let b = i8x32::splat(1);
-        let r = avx2::_mm256_adds_epi8(a, b);
-        assert_eq!(r, a);
+    fn _mm_i32gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        // A multiplier of 4 is word-addressing
+        let r = unsafe {
+            avx2::_mm_i32gather_epi32(
+                arr.as_ptr(), i32x4::new(0, 16, 32, 48), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 16, 32, 48));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi8_saturate_negative() {
-        let a = i8x32::splat(-0x80);
-        let b = i8x32::splat(-1);
-        let r = avx2::_mm256_adds_epi8(a, b);
-        assert_eq!(r, a);
+    fn _mm_mask_i32gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i32gather_epi32(
+                i32x4::splat(256), arr.as_ptr(), i32x4::new(0, 16, 64, 96),
+                i32x4::new(-1, -1, -1, 0), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 16, 64, 256));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi16() {
-        let a = i16x16::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
-        let b = i16x16::new(
-            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47);
-        let r = avx2::_mm256_adds_epi16(a,  b);
-        let e = i16x16::new(
-            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+    fn _mm256_i32gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm256_i32gather_epi32(
+                arr.as_ptr(), i32x8::new(0, 16, 32, 48, 1, 2, 3, 4), 4)
+        };
+        assert_eq!(r, i32x8::new(0, 16, 32, 48, 1, 2, 3, 4));
+    }
 
-        assert_eq!(r, e);
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_mask_i32gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i32gather_epi32(
+                i32x8::splat(256), arr.as_ptr(),
+                i32x8::new(0, 16, 64, 96, 0, 0, 0, 0),
+                i32x8::new(-1, -1, -1, 0, 0, 0, 0, 0), 4)
+        };
+        assert_eq!(r, i32x8::new(0, 16, 64, 256, 256, 256, 256, 256));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi16_saturate_positive() {
-        let a = i16x16::splat(0x7FFF);
-        let b = i16x16::splat(1);
-        let r = avx2::_mm256_adds_epi16(a, b);
-        assert_eq!(r, a);
+    fn _mm_i32gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_i32gather_ps(
+                arr.as_ptr(), i32x4::new(0, 16, 32, 48), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 16.0, 32.0, 48.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epi16_saturate_negative() {
-        let a = i16x16::splat(-0x8000);
-        let b = i16x16::splat(-1);
-        let r = avx2::_mm256_adds_epi16(a, b);
-        assert_eq!(r, a);
+    fn _mm_mask_i32gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i32gather_ps(
+                f32x4::splat(256.0), arr.as_ptr(), i32x4::new(0, 16, 64, 96),
+                f32x4::new(-1.0, -1.0, -1.0, 0.0), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 16.0, 64.0, 256.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epu8() {
-        let a = u8x32::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
-            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
-        let b = u8x32::new(
-            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
-            48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63);
-        let r = avx2::_mm256_adds_epu8(a, b);
-        let e = u8x32::new(
-            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
-            64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94);
-        assert_eq!(r, e);
+    fn _mm256_i32gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_i32gather_ps(
+                arr.as_ptr(), i32x8::new(0, 16, 32, 48, 1, 2, 3, 4), 4)
+        };
+        assert_eq!(r, f32x8::new(0.0, 16.0, 32.0, 48.0, 1.0, 2.0, 3.0, 4.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epu8_saturate() {
-        let a = u8x32::splat(0xFF);
-        let b = u8x32::splat(1);
-        let r = avx2::_mm256_adds_epu8(a, b);
-        assert_eq!(r, a);
+    fn _mm256_mask_i32gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i32gather_ps(
+                f32x8::splat(256.0), arr.as_ptr(),
+                i32x8::new(0, 16, 64, 96, 0, 0, 0, 0),
+                f32x8::new(-1.0, -1.0, -1.0, 0.0, 0.0, 0.0, 0.0, 0.0), 4)
+        };
+        assert_eq!(
+            r,
+            f32x8::new(0.0, 16.0, 64.0, 256.0, 256.0, 256.0, 256.0, 256.0));
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_i32gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm_i32gather_epi64(
+                arr.as_ptr(), i32x4::new(0, 16, 0, 0), 8)
+        };
+        assert_eq!(r, i64x2::new(0, 16));
+    }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epu16() {
-        let a = u16x16::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
-        let b = u16x16::new(
-            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47);
-        let r = avx2::_mm256_adds_epu16(a, b);
-        let e = u16x16::new(
-            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62);
+    fn _mm_mask_i32gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i32gather_epi64(
+                i64x2::splat(256), arr.as_ptr(), i32x4::new(16, 16, 16, 16),
+                i64x2::new(-1, 0), 8)
+        };
+        assert_eq!(r, i64x2::new(16, 256));
+    }
 
-        assert_eq!(r, e);
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_i32gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm256_i32gather_epi64(
+                arr.as_ptr(), i32x4::new(0, 16, 32, 48), 8)
+        };
+        assert_eq!(r, i64x4::new(0, 16, 32, 48));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_adds_epu16_saturate() {
-        let a = u16x16::splat(0xFFFF);
-        let b = u16x16::splat(1);
-        let r = avx2::_mm256_adds_epu16(a, b);
-        assert_eq!(r, a);
+    fn _mm256_mask_i32gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i32gather_epi64(
+                i64x4::splat(256), arr.as_ptr(), i32x4::new(0, 16, 64, 96),
+                i64x4::new(-1, -1, -1, 0), 8)
+        };
+        assert_eq!(r, i64x4::new(0, 16, 64, 256));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_and_si256() {
-        assert_eq!(
-            avx2::_mm256_and_si256(
-                __m256i::splat(5), __m256i::splat(3)),__m256i::splat(1));
+    fn _mm_i32gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_i32gather_pd(
+                arr.as_ptr(), i32x4::new(0, 16, 0, 0), 8)
+        };
+        assert_eq!(r, f64x2::new(0.0, 16.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_andnot_si256() {
-        assert_eq!(
-            avx2::_mm256_andnot_si256(__m256i::splat(5), __m256i::splat(3)),
-            __m256i::splat(2));
+    fn _mm_mask_i32gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i32gather_pd(
+                f64x2::splat(256.0), arr.as_ptr(), i32x4::new(16, 16, 16, 16),
+                f64x2::new(-1.0, 0.0), 8)
+        };
+        assert_eq!(r, f64x2::new(16.0, 256.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_avg_epu8() {
-        let (a, b) = (u8x32::splat(3), u8x32::splat(9));
-        let r = avx2::_mm256_avg_epu8(a, b);
-        assert_eq!(r, u8x32::splat(6));
+    fn _mm256_i32gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_i32gather_pd(
+                arr.as_ptr(), i32x4::new(0, 16, 32, 48), 8)
+        };
+        assert_eq!(r, f64x4::new(0.0, 16.0, 32.0, 48.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_avg_epu16() {
-        let (a, b) = (u16x16::splat(3), u16x16::splat(9));
-        let r = avx2::_mm256_avg_epu16(a, b);
-        assert_eq!(r, u16x16::splat(6));
+    fn _mm256_mask_i32gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i32gather_pd(
+                f64x4::splat(256.0), arr.as_ptr(), i32x4::new(0, 16, 64, 96),
+                f64x4::new(-1.0, -1.0, -1.0, 0.0), 8)
+        };
+        assert_eq!(r, f64x4::new(0.0, 16.0, 64.0, 256.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_blendv_epi8() {
-        let (a,b) = (i8x32::splat(4),i8x32::splat(2));
-        let mask = i8x32::splat(0).replace(2,-1);
-        let e = i8x32::splat(4).replace(2,2);
-        let r= avx2::_mm256_blendv_epi8(a,b,mask);
-        assert_eq!(r,e);
+    fn _mm_i64gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm_i64gather_epi32(
+                arr.as_ptr(), i64x2::new(0, 16), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 16, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_mask_i64gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i64gather_epi32(
+                i32x4::splat(256), arr.as_ptr(), i64x2::new(0, 16),
+                i32x4::new(-1, 0, -1, 0), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 256, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_i64gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm256_i64gather_epi32(
+                arr.as_ptr(), i64x4::new(0, 16, 32, 48), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 16, 32, 48));
     }
 
     #[test]
-    fn _mm256_cmpeq_epi8() {
-        let a = i8x32::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
-            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
-        let b = i8x32::new(
-            31, 30, 2, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16,
-            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
-        let r = avx2::_mm256_cmpeq_epi8(a, b);
-        assert_eq!(r, i8x32::splat(0).replace(2,0xFFu8 as i8));
+    #[target_feature = "+avx2"]
+    fn _mm256_mask_i64gather_epi32() {
+        let mut arr = [0i32; 128];
+        for i in 0..128 {
+            arr[i] = i as i32;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i64gather_epi32(
+                i32x4::splat(256), arr.as_ptr(), i64x4::new(0, 16, 64, 96),
+                i32x4::new(-1, -1, -1, 0), 4)
+        };
+        assert_eq!(r, i32x4::new(0, 16, 64, 256));
     }
 
     #[test]
-    fn _mm256_cmpeq_epi16() {
-        let a = i16x16::new(
-            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
-        let b = i16x16::new(
-            15, 14, 2, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
-        let r = avx2::_mm256_cmpeq_epi16(a, b);
-        assert_eq!(r, i16x16::splat(0).replace(2, 0xFFFFu16 as i16));
+    #[target_feature = "+avx2"]
+    fn _mm_i64gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm_i64gather_epi64(
+                arr.as_ptr(), i64x2::new(0, 16), 8)
+        };
+        assert_eq!(r, i64x2::new(0, 16));
     }
 
     #[test]
-    fn _mm256_cmpeq_epi32() {
-        let a = i32x8::new(0, 1, 2, 3,4,5,6,7);
-        let b = i32x8::new(7,6,2,4,3, 2, 1, 0);
-        let r = avx2::_mm256_cmpeq_epi32(a, b);
-        assert_eq!(r, i32x8::splat(0).replace(2, 0xFFFFFFFFu32 as i32));
+    #[target_feature = "+avx2"]
+    fn _mm_mask_i64gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i64gather_epi64(
+                i64x2::splat(256), arr.as_ptr(), i64x2::new(16, 16),
+                i64x2::new(-1, 0), 8)
+        };
+        assert_eq!(r, i64x2::new(16, 256));
     }
 
     #[test]
-    fn _mm256_cmpeq_epi64() {
-        let a = i64x4::new(0, 1, 2, 3);
-        let b = i64x4::new(3, 2, 2, 0);
-        let r = avx2::_mm256_cmpeq_epi64(a, b);
-        assert_eq!(r, i64x4::splat(0).replace(
-            2, 0xFFFFFFFFFFFFFFFFu64 as i64));
+    #[target_feature = "+avx2"]
+    fn _mm256_i64gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm256_i64gather_epi64(
+                arr.as_ptr(), i64x4::new(0, 16, 32, 48), 8)
+        };
+        assert_eq!(r, i64x4::new(0, 16, 32, 48));
     }
 
     #[test]
-    fn _mm256_cmpgt_epi8() {
-        let a = i8x32::splat(0).replace(0, 5);
-        let b = i8x32::splat(0);
-        let r = avx2::_mm256_cmpgt_epi8(a, b);
-        assert_eq!(r, i8x32::splat(0).replace(0, 0xFFu8 as i8));
+    #[target_feature = "+avx2"]
+    fn _mm256_mask_i64gather_epi64() {
+        let mut arr = [0i64; 128];
+        for i in 0..128i64 {
+            arr[i as usize] = i;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i64gather_epi64(
+                i64x4::splat(256), arr.as_ptr(), i64x4::new(0, 16, 64, 96),
+                i64x4::new(-1, -1, -1, 0), 8)
+        };
+        assert_eq!(r, i64x4::new(0, 16, 64, 256));
     }
 
     #[test]
-    fn _mm256_cmpgt_epi16() {
-        let a = i16x16::splat(0).replace(0, 5);
-        let b = i16x16::splat(0);
-        let r = avx2::_mm256_cmpgt_epi16(a, b);
-        assert_eq!(r, i16x16::splat(0).replace(0, 0xFFFFu16 as i16));
+    #[target_feature = "+avx2"]
+    fn _mm_i64gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_i64gather_pd(
+                arr.as_ptr(), i64x2::new(0, 16), 8)
+        };
+        assert_eq!(r, f64x2::new(0.0, 16.0));
     }
 
     #[test]
-    fn _mm256_cmpgt_epi32() {
-        let a = i32x8::splat(0).replace(0, 5);
-        let b = i32x8::splat(0);
-        let r = avx2::_mm256_cmpgt_epi32(a, b);
-        assert_eq!(r, i32x8::splat(0).replace(0, 0xFFFFFFFFu32 as i32));
+    #[target_feature = "+avx2"]
+    fn _mm_mask_i64gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i64gather_pd(
+                f64x2::splat(256.0), arr.as_ptr(), i64x2::new(16, 16),
+                f64x2::new(-1.0, 0.0), 8)
+        };
+        assert_eq!(r, f64x2::new(16.0, 256.0));
     }
 
     #[test]
-    fn _mm256_cmpgt_epi64() {
-        let a = i64x4::splat(0).replace(0, 5);
-        let b = i64x4::splat(0);
-        let r = avx2::_mm256_cmpgt_epi64(a, b);
-        assert_eq!(r, i64x4::splat(0).replace(
-            0, 0xFFFFFFFFFFFFFFFFu64 as i64));
+    #[target_feature = "+avx2"]
+    fn _mm256_i64gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_i64gather_pd(
+                arr.as_ptr(), i64x4::new(0, 16, 32, 48), 8)
+        };
+        assert_eq!(r, f64x4::new(0.0, 16.0, 32.0, 48.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_hadd_epi16() {
-        let a = i16x16::splat(2);
-        let b = i16x16::splat(4);
-        let r = avx2::_mm256_hadd_epi16(a, b);
-        let e = i16x16::new(4, 4, 4, 4, 8, 8, 8, 8, 4, 4, 4, 4, 8, 8, 8, 8);
-        assert_eq!(r, e);
+    fn _mm256_mask_i64gather_pd() {
+        let mut arr = [0.0f64; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i64gather_pd(
+                f64x4::splat(256.0), arr.as_ptr(), i64x4::new(0, 16, 64, 96),
+                f64x4::new(-1.0, -1.0, -1.0, 0.0), 8)
+        };
+        assert_eq!(r, f64x4::new(0.0, 16.0, 64.0, 256.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_hadd_epi32() {
-        let a = i32x8::splat(2);
-        let b = i32x8::splat(4);
-        let r = avx2::_mm256_hadd_epi32(a, b);
-        let e = i32x8::new(4, 4, 8, 8, 4, 4, 8, 8);
-        assert_eq!(r, e);
+    fn _mm_i64gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_i64gather_ps(
+                arr.as_ptr(), i64x2::new(0, 16), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 16.0, 0.0, 0.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_hadds_epi16() {
-        let a = i16x16::splat(2).replace(0,0x7FFF).replace(1,1);
-        let b = i16x16::splat(4);
-        let r = avx2::_mm256_hadds_epi16(a, b);
-        let e = i16x16::new(
-            0x7FFF, 4, 4, 4, 8, 8, 8, 8, 4, 4, 4, 4, 8, 8, 8, 8);
-        assert_eq!(r, e);
+    fn _mm_mask_i64gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm_mask_i64gather_ps(
+                f32x4::splat(256.0), arr.as_ptr(), i64x2::new(0, 16),
+                f32x4::new(-1.0, 0.0, -1.0, 0.0), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 256.0, 0.0, 0.0));
     }
 
     #[test]
-    #[target_feature ="+avx2"]
-    fn _mm256_hsub_epi16() {
-        let a = i16x16::splat(2);
-        let b = i16x16::splat(4);
-        let r = avx2::_mm256_hsub_epi16(a, b);
-        let e = i16x16::splat(0);
-        assert_eq!(r, e);
+    #[target_feature = "+avx2"]
+    fn _mm256_i64gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_i64gather_ps(
+                arr.as_ptr(), i64x4::new(0, 16, 32, 48), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 16.0, 32.0, 48.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_hsub_epi32() {
-        let a = i32x8::splat(2);
-        let b = i32x8::splat(4);
-        let r = avx2::_mm256_hsub_epi32(a, b);
-        let e = i32x8::splat(0);
-        assert_eq!(r, e);
+    fn _mm256_mask_i64gather_ps() {
+        let mut arr = [0.0f32; 128];
+        let mut j = 0.0;
+        for i in 0..128 {
+            arr[i] = j;
+            j += 1.0;
+        }
+        let r = unsafe {
+            avx2::_mm256_mask_i64gather_ps(
+                f32x4::splat(256.0), arr.as_ptr(), i64x4::new(0, 16, 64, 96),
+                f32x4::new(-1.0, -1.0, -1.0, 0.0), 4)
+        };
+        assert_eq!(r, f32x4::new(0.0, 16.0, 64.0, 256.0));
     }
 
     #[test]
     #[target_feature = "+avx2"]
-    fn _mm256_hsubs_epi16() {
-        let a = i16x16::splat(2).replace(0,0x7FFF).replace(1,-1);
-        let b = i16x16::splat(4);
-        let r = avx2::_mm256_hsubs_epi16(a, b);
-        let e = i16x16::splat(0).replace(0,0x7FFF);
-        assert_eq!(r, e);
+    fn _mm256_inserti128_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m128i::from(i64x2::new(7, 8));
+        let r = avx2::_mm256_inserti128_si256(a, b, 0);
+        assert_eq!(r, __m256i::from(i64x4::new(7, 8, 3, 4)));
+        let r = avx2::_mm256_inserti128_si256(a, b, 1);
+        assert_eq!(r, __m256i::from(i64x4::new(1, 2, 7, 8)));
     }
 
     #[test]
@@ -1471,6 +3845,86 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_maskload_epi32() {
+        let nums = [1, 2, 3, 4];
+        let a = &nums as *const i32;
+        let mask = i32x4::new(-1, 0, 0, -1);
+        let r = unsafe { avx2::_mm_maskload_epi32(a, mask) };
+        assert_eq!(r, i32x4::new(1, 0, 0, 4));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_maskload_epi32() {
+        let nums = [1, 2, 3, 4, 5, 6, 7, 8];
+        let a = &nums as *const i32;
+        let mask = i32x8::new(-1, 0, 0, -1, 0, -1, -1, 0);
+        let r = unsafe { avx2::_mm256_maskload_epi32(a, mask) };
+        assert_eq!(r, i32x8::new(1, 0, 0, 4, 0, 6, 7, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_maskload_epi64() {
+        let nums = [1_i64, 2_i64];
+        let a = &nums as *const i64;
+        let mask = i64x2::new(0, -1);
+        let r = unsafe { avx2::_mm_maskload_epi64(a, mask) };
+        assert_eq!(r, i64x2::new(0, 2));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_maskload_epi64() {
+        let nums = [1_i64, 2_i64, 3_i64, 4_i64];
+        let a = &nums as *const i64;
+        let mask = i64x4::new(0, -1, -1, 0);
+        let r = unsafe { avx2::_mm256_maskload_epi64(a, mask) };
+        assert_eq!(r, i64x4::new(0, 2, 3, 0));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_maskstore_epi32() {
+        let a = i32x4::new(1, 2, 3, 4);
+        let mut arr = [-1, -1, -1, -1];
+        let mask = i32x4::new(-1, 0, 0, -1);
+        unsafe { avx2::_mm_maskstore_epi32(arr.as_mut_ptr(), mask, a) };
+        assert_eq!(arr, [1, -1, -1, 4]);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_maskstore_epi32() {
+        let a = i32x8::new(1, 2, 3, 42, 5, 6, 7, 8);
+        let mut arr = [-1, -1, -1, -1, -1, -1, -1, -1];
+        let mask = i32x8::new(-1, 0, 0, -1, 0, -1, -1, 0);
+        unsafe { avx2::_mm256_maskstore_epi32(arr.as_mut_ptr(), mask, a) };
+        assert_eq!(arr, [1, -1, -1, 42, -1, 6, 7, -1]);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm_maskstore_epi64() {
+        let a = i64x2::new(1_i64, 2_i64);
+        let mut arr = [-1_i64, -1_i64];
+        let mask = i64x2::new(0, -1);
+        unsafe { avx2::_mm_maskstore_epi64(arr.as_mut_ptr(), mask, a) };
+        assert_eq!(arr, [-1, 2]);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_maskstore_epi64() {
+        let a = i64x4::new(1_i64, 2_i64, 3_i64, 4_i64);
+        let mut arr = [-1_i64, -1_i64, -1_i64, -1_i64];
+        let mask = i64x4::new(0, -1, -1, 0);
+        unsafe { avx2::_mm256_maskstore_epi64(arr.as_mut_ptr(), mask, a) };
+        assert_eq!(arr, [-1, 2, 3, -1]);
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_max_epi16() {
@@ -1579,11 +4033,8 @@ mod tests {
         assert_eq!(r, a);
     }
 
-
-/**
-    // TODO this fails in debug but not release, why?
     #[test]
-    #[target_feature ="+avx2"]
+    #[target_feature = "+avx2"]
     fn _mm256_movemask_epi8() {
         let a = i8x32::splat(-1);
         let r = avx2::_mm256_movemask_epi8(a);
@@ -1591,7 +4042,6 @@ mod tests {
         assert_eq!(r, e);
     }
 
-    // TODO This fails in debug but not in release, whhhy?
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_mpsadbw_epu8() {
@@ -1601,7 +4051,6 @@ mod tests {
         let e = u16x16::splat(8);
         assert_eq!(r, e);
     }
-**/
 
     #[test]
     #[target_feature = "+avx2"]
@@ -1742,6 +4191,53 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_permute2x128_si256() {
+        let a = __m256i::from(i64x4::new(1, 2, 3, 4));
+        let b = __m256i::from(i64x4::new(5, 6, 7, 8));
+        let r = avx2::_mm256_permute2x128_si256(a, b, 0x31);
+        assert_eq!(r, __m256i::from(i64x4::new(3, 4, 7, 8)));
+        let r = avx2::_mm256_permute2x128_si256(a, b, 0x82);
+        assert_eq!(r, __m256i::from(i64x4::new(5, 6, 0, 0)));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_permute4x64_epi64() {
+        let a = i64x4::new(100, 200, 300, 400);
+        let r = avx2::_mm256_permute4x64_epi64(a, 0b00_01_00_11);
+        assert_eq!(r, i64x4::new(400, 100, 200, 100));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_permute4x64_pd() {
+        let a = f64x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = avx2::_mm256_permute4x64_pd(a, 0b00_01_00_11);
+        assert_eq!(r, f64x4::new(4.0, 1.0, 2.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_permutevar8x32_epi32() {
+        let a = i32x8::new(100, 200, 300, 400, 500, 600, 700, 800);
+        let b = i32x8::new(5, 0, 5, 1, 7, 6, 3, 4);
+        let r = avx2::_mm256_permutevar8x32_epi32(a, b);
+        let e = i32x8::new(600, 100, 600, 200, 800, 700, 400, 500);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_permutevar8x32_ps() {
+        let a = f32x8::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = i32x8::new(5, 0, 5, 1, 7, 6, 3, 4);
+        let r = avx2::_mm256_permutevar8x32_ps(a, b);
+        let e = f32x8::new(6.0, 1.0, 6.0, 2.0, 8.0, 7.0, 4.0, 5.0);
+        assert_eq!(r, e);
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_sad_epu8() {
@@ -1752,6 +4248,59 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_shuffle_epi32() {
+        let a = i32x8::new(5, 10, 15, 20, 25, 30, 35, 40);
+        let r = avx2::_mm256_shuffle_epi32(a, 0b00_01_01_11);
+        let e = i32x8::new(20, 10, 10, 5, 40, 30, 30, 25);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_shuffle_epi8() {
+        let a = u8x32::new(
+            1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 27, 28, 29, 30, 31, 32);
+        let b = u8x32::new(
+            4, 128, 4, 3, 24, 12, 6, 19,
+            12, 5, 5, 10, 4, 1, 8, 0,
+            4, 128, 4, 3, 24, 12, 6, 19,
+            12, 5, 5, 10, 4, 1, 8, 0);
+        let expected = u8x32::new(
+            5, 0, 5, 4, 9, 13, 7, 4,
+            13, 6, 6, 11, 5, 2, 9, 1,
+            21, 0, 21, 20, 25, 29, 23, 20,
+            29, 22, 22, 27, 21, 18, 25, 17);
+        let r = avx2::_mm256_shuffle_epi8(a, b);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_shufflehi_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = avx2::_mm256_shufflehi_epi16(a, 0b00_01_01_11);
+        let e = i16x16::new(
+            0, 1, 2, 3, 7, 5, 5, 4, 8, 9, 10, 11, 15, 13, 13, 12);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_shufflelo_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let r = avx2::_mm256_shufflelo_epi16(a, 0b00_01_01_11);
+        let e = i16x16::new(
+            3, 1, 1, 0, 4, 5, 6, 7, 11, 9, 9, 8, 12, 13, 14, 15);
+        assert_eq!(r, e);
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_sign_epi16() {
@@ -1833,6 +4382,16 @@ mod tests {
             i64x4::splat(0xFFFFFFFF0));
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_slli_si256() {
+        let a = __m256i::from(i64x4::new(-1, 0, -1, 0));
+        let r = avx2::_mm256_slli_si256(a, 3);
+        let e = i64x4::new(-1 << 24, 0xFF_FFFF, -1 << 24, 0xFF_FFFF);
+        assert_eq!(r, __m256i::from(e));
+        assert_eq!(avx2::_mm256_slli_si256(a, 16), __m256i::splat(0));
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm_sllv_epi32() {
@@ -1976,6 +4535,16 @@ mod tests {
             i64x4::splat(0xFFFFFFF));
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_srli_si256() {
+        let a = __m256i::from(i64x4::new(0, 0, -1, -1));
+        let r = avx2::_mm256_srli_si256(a, 3);
+        let e = i64x4::new(0, 0, -1, 0xFF_FFFF_FFFF);
+        assert_eq!(r, __m256i::from(e));
+        assert_eq!(avx2::_mm256_srli_si256(a, 16), __m256i::splat(0));
+    }
+
     #[test]
     #[target_feature ="+avx2"]
     fn _mm_srlv_epi32() {
@@ -2017,6 +4586,16 @@ mod tests {
         assert_eq!(r, e);
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_stream_load_si256() {
+        let a = __m256i::from(i64x4::new(5, 6, 7, 8));
+        let r = unsafe {
+            avx2::_mm256_stream_load_si256(&a as *const __m256i)
+        };
+        assert_eq!(r, a);
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_sub_epi16() {
@@ -2089,6 +4668,118 @@ mod tests {
         assert_eq!(r, b);
     }
 
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpackhi_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31);
+        let b = i8x32::new(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24,
+            -25, -26, -27, -28, -29, -30, -31, -32);
+        let r = avx2::_mm256_unpackhi_epi8(a, b);
+        let e = i8x32::new(
+            8, -9, 9, -10, 10, -11, 11, -12,
+            12, -13, 13, -14, 14, -15, 15, -16,
+            24, -25, 25, -26, 26, -27, 27, -28,
+            28, -29, 29, -30, 30, -31, 31, -32);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpackhi_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15);
+        let b = i16x16::new(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16);
+        let r = avx2::_mm256_unpackhi_epi16(a, b);
+        let e = i16x16::new(
+            4, -5, 5, -6, 6, -7, 7, -8,
+            12, -13, 13, -14, 14, -15, 15, -16);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpackhi_epi32() {
+        let a = i32x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = i32x8::new(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = avx2::_mm256_unpackhi_epi32(a, b);
+        assert_eq!(r, i32x8::new(2, -3, 3, -4, 6, -7, 7, -8));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpackhi_epi64() {
+        let a = i64x4::new(0, 1, 2, 3);
+        let b = i64x4::new(-1, -2, -3, -4);
+        let r = avx2::_mm256_unpackhi_epi64(a, b);
+        assert_eq!(r, i64x4::new(1, -2, 3, -4));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpacklo_epi8() {
+        let a = i8x32::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31);
+        let b = i8x32::new(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16,
+            -17, -18, -19, -20, -21, -22, -23, -24,
+            -25, -26, -27, -28, -29, -30, -31, -32);
+        let r = avx2::_mm256_unpacklo_epi8(a, b);
+        let e = i8x32::new(
+            0, -1, 1, -2, 2, -3, 3, -4,
+            4, -5, 5, -6, 6, -7, 7, -8,
+            16, -17, 17, -18, 18, -19, 19, -20,
+            20, -21, 21, -22, 22, -23, 23, -24);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpacklo_epi16() {
+        let a = i16x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15);
+        let b = i16x16::new(
+            -1, -2, -3, -4, -5, -6, -7, -8,
+            -9, -10, -11, -12, -13, -14, -15, -16);
+        let r = avx2::_mm256_unpacklo_epi16(a, b);
+        let e = i16x16::new(
+            0, -1, 1, -2, 2, -3, 3, -4,
+            8, -9, 9, -10, 10, -11, 11, -12);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpacklo_epi32() {
+        let a = i32x8::new(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = i32x8::new(-1, -2, -3, -4, -5, -6, -7, -8);
+        let r = avx2::_mm256_unpacklo_epi32(a, b);
+        assert_eq!(r, i32x8::new(0, -1, 1, -2, 4, -5, 5, -6));
+    }
+
+    #[test]
+    #[target_feature = "+avx2"]
+    fn _mm256_unpacklo_epi64() {
+        let a = i64x4::new(0, 1, 2, 3);
+        let b = i64x4::new(-1, -2, -3, -4);
+        let r = avx2::_mm256_unpacklo_epi64(a, b);
+        assert_eq!(r, i64x4::new(0, -1, 2, -3));
+    }
+
     #[test]
     #[target_feature = "+avx2"]
     fn _mm256_xor_si256() {
@@ -0,0 +1,265 @@
+/// Bitwise logical `AND` of inverted `a` with `b`.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _andn_u32(a: u32, b: u32) -> u32 {
+    !a & b
+}
+
+/// Bitwise logical `AND` of inverted `a` with `b`.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _andn_u64(a: u64, b: u64) -> u64 {
+    !a & b
+}
+
+/// Extract `len` bits of `a`, starting at bit `start`, into the least
+/// significant bits of the result.
+///
+/// Only the low 8 bits of `start` and `len` are used. Bits past the end of
+/// `a` are read as zero.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _bextr_u32(a: u32, start: u32, len: u32) -> u32 {
+    _bextr2_u32(a, (start & 0xFF) | ((len & 0xFF) << 8))
+}
+
+/// Extract `len` bits of `a`, starting at bit `start`, into the least
+/// significant bits of the result.
+///
+/// Only the low 8 bits of `start` and `len` are used. Bits past the end of
+/// `a` are read as zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _bextr_u64(a: u64, start: u32, len: u32) -> u64 {
+    _bextr2_u64(a, ((start & 0xFF) | ((len & 0xFF) << 8)) as u64)
+}
+
+/// Extract bits of `a` specified by `control` into the least significant
+/// bits of the result.
+///
+/// Bits `[7:0]` of `control` specify the index of the first bit to extract,
+/// and bits `[15:8]` the number of bits to extract.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _bextr2_u32(a: u32, control: u32) -> u32 {
+    unsafe { bextr_32(a, control) }
+}
+
+/// Extract bits of `a` specified by `control` into the least significant
+/// bits of the result.
+///
+/// Bits `[7:0]` of `control` specify the index of the first bit to extract,
+/// and bits `[15:8]` the number of bits to extract.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _bextr2_u64(a: u64, control: u64) -> u64 {
+    unsafe { bextr_64(a, control) }
+}
+
+/// Extract the lowest set bit of `x`.
+///
+/// Returns zero if `x` is zero.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsi_u32(x: u32) -> u32 {
+    x & x.wrapping_neg()
+}
+
+/// Extract the lowest set bit of `x`.
+///
+/// Returns zero if `x` is zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsi_u64(x: u64) -> u64 {
+    x & x.wrapping_neg()
+}
+
+/// Return a mask of the bits of `x` up to and including its lowest set bit.
+///
+/// Returns all ones if `x` is zero.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsmsk_u32(x: u32) -> u32 {
+    x ^ x.wrapping_sub(1)
+}
+
+/// Return a mask of the bits of `x` up to and including its lowest set bit.
+///
+/// Returns all ones if `x` is zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsmsk_u64(x: u64) -> u64 {
+    x ^ x.wrapping_sub(1)
+}
+
+/// Clear the lowest set bit of `x`.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsr_u32(x: u32) -> u32 {
+    x & x.wrapping_sub(1)
+}
+
+/// Clear the lowest set bit of `x`.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _blsr_u64(x: u64) -> u64 {
+    x & x.wrapping_sub(1)
+}
+
+/// Count the trailing zero bits of `x`.
+///
+/// Returns 32 if `x` is zero.
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _tzcnt_u32(x: u32) -> u32 {
+    x.trailing_zeros()
+}
+
+/// Count the trailing zero bits of `x`.
+///
+/// Returns 64 if `x` is zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi"]
+pub fn _tzcnt_u64(x: u64) -> u64 {
+    x.trailing_zeros() as u64
+}
+
+extern {
+    #[link_name = "llvm.x86.bmi.bextr.32"]
+    fn bextr_32(a: u32, control: u32) -> u32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.bmi.bextr.64"]
+    fn bextr_64(a: u64, control: u64) -> u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use std;
+
+    use x86::bmi;
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _andn_u32() {
+        assert_eq!(bmi::_andn_u32(0, 0), 0);
+        assert_eq!(bmi::_andn_u32(0, 0b1111_0000), 0b1111_0000);
+        assert_eq!(bmi::_andn_u32(0b1010_1010, 0b1100_1100), 0b0100_0100);
+        assert_eq!(bmi::_andn_u32(std::u32::MAX, 0b1100_1100), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _andn_u64() {
+        assert_eq!(bmi::_andn_u64(0, 0), 0);
+        assert_eq!(bmi::_andn_u64(0, 0b1111_0000), 0b1111_0000);
+        assert_eq!(bmi::_andn_u64(0b1010_1010, 0b1100_1100), 0b0100_0100);
+        assert_eq!(bmi::_andn_u64(std::u64::MAX, 0b1100_1100), 0);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _bextr_u32() {
+        assert_eq!(bmi::_bextr_u32(0b0101_0000, 4, 4), 0b0000_0101);
+        assert_eq!(bmi::_bextr_u32(0b0101_0000, 4, 0), 0);
+        assert_eq!(bmi::_bextr_u32(0x8000_0000, 28, 8), 0b1000);
+        assert_eq!(bmi::_bextr_u32(std::u32::MAX, 32, 8), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _bextr_u64() {
+        assert_eq!(bmi::_bextr_u64(0b0101_0000, 4, 4), 0b0000_0101);
+        assert_eq!(bmi::_bextr_u64(0x8000_0000 << 32, 60, 8), 0b1000);
+        assert_eq!(bmi::_bextr_u64(std::u64::MAX, 64, 8), 0);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _bextr2_u32() {
+        assert_eq!(bmi::_bextr2_u32(0b0101_0000, 0x0404), 0b0000_0101);
+        assert_eq!(bmi::_bextr2_u32(0b0101_0000, 0x0004), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _bextr2_u64() {
+        assert_eq!(bmi::_bextr2_u64(0b0101_0000, 0x0404), 0b0000_0101);
+        assert_eq!(bmi::_bextr2_u64(0b0101_0000 << 32, 0x0424), 0b0000_0101);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _blsi_u32() {
+        assert_eq!(bmi::_blsi_u32(0b1101_0000), 0b0001_0000);
+        assert_eq!(bmi::_blsi_u32(0x8000_0000), 0x8000_0000);
+        assert_eq!(bmi::_blsi_u32(0), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _blsi_u64() {
+        assert_eq!(bmi::_blsi_u64(0b1101_0000), 0b0001_0000);
+        assert_eq!(bmi::_blsi_u64(1 << 63), 1 << 63);
+        assert_eq!(bmi::_blsi_u64(0), 0);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _blsmsk_u32() {
+        assert_eq!(bmi::_blsmsk_u32(0b0011_0000), 0b0001_1111);
+        assert_eq!(bmi::_blsmsk_u32(0), std::u32::MAX);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _blsmsk_u64() {
+        assert_eq!(bmi::_blsmsk_u64(0b0011_0000), 0b0001_1111);
+        assert_eq!(bmi::_blsmsk_u64(0), std::u64::MAX);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _blsr_u32() {
+        assert_eq!(bmi::_blsr_u32(0b0011_0000), 0b0010_0000);
+        assert_eq!(bmi::_blsr_u32(0), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _blsr_u64() {
+        assert_eq!(bmi::_blsr_u64(0b0011_0000), 0b0010_0000);
+        assert_eq!(bmi::_blsr_u64(0), 0);
+    }
+
+    #[test]
+    #[target_feature = "+bmi"]
+    fn _tzcnt_u32() {
+        assert_eq!(bmi::_tzcnt_u32(0b0000_0001), 0);
+        assert_eq!(bmi::_tzcnt_u32(0b0000_1000), 3);
+        assert_eq!(bmi::_tzcnt_u32(0x8000_0000), 31);
+        assert_eq!(bmi::_tzcnt_u32(0), 32);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi"]
+    fn _tzcnt_u64() {
+        assert_eq!(bmi::_tzcnt_u64(0b0000_0001), 0);
+        assert_eq!(bmi::_tzcnt_u64(0b0000_1000), 3);
+        assert_eq!(bmi::_tzcnt_u64(1 << 63), 63);
+        assert_eq!(bmi::_tzcnt_u64(0), 64);
+    }
+}
@@ -0,0 +1,232 @@
+/// Multiply unsigned 32-bit integers `a` and `b` without affecting the
+/// flags.
+///
+/// The high 32 bits of the product are stored in `hi`, and the low 32 bits
+/// are returned.
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _mulx_u32(a: u32, b: u32, hi: &mut u32) -> u32 {
+    let result = (a as u64) * (b as u64);
+    *hi = (result >> 32) as u32;
+    result as u32
+}
+
+/// Multiply unsigned 64-bit integers `a` and `b` without affecting the
+/// flags.
+///
+/// The high 64 bits of the product are stored in `hi`, and the low 64 bits
+/// are returned.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _mulx_u64(a: u64, b: u64, hi: &mut u64) -> u64 {
+    let result = (a as u128) * (b as u128);
+    *hi = (result >> 64) as u64;
+    result as u64
+}
+
+/// Zero the bits of `a` at positions greater than or equal to `index`.
+///
+/// Only the low 8 bits of `index` are used. If they are 32 or greater, `a`
+/// is returned unchanged.
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _bzhi_u32(a: u32, index: u32) -> u32 {
+    unsafe { bzhi_32(a, index) }
+}
+
+/// Zero the bits of `a` at positions greater than or equal to `index`.
+///
+/// Only the low 8 bits of `index` are used. If they are 64 or greater, `a`
+/// is returned unchanged.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _bzhi_u64(a: u64, index: u32) -> u64 {
+    unsafe { bzhi_64(a, index as u64) }
+}
+
+/// Scatter the contiguous low bits of `a` to the positions of the set bits
+/// of `mask`, from the least to the most significant.
+///
+/// The other bits of the result are zero.
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _pdep_u32(a: u32, mask: u32) -> u32 {
+    unsafe { pdep_32(a, mask) }
+}
+
+/// Scatter the contiguous low bits of `a` to the positions of the set bits
+/// of `mask`, from the least to the most significant.
+///
+/// The other bits of the result are zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _pdep_u64(a: u64, mask: u64) -> u64 {
+    unsafe { pdep_64(a, mask) }
+}
+
+/// Gather the bits of `a` at the positions of the set bits of `mask` into
+/// the contiguous low bits of the result.
+///
+/// The other bits of the result are zero.
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _pext_u32(a: u32, mask: u32) -> u32 {
+    unsafe { pext_32(a, mask) }
+}
+
+/// Gather the bits of `a` at the positions of the set bits of `mask` into
+/// the contiguous low bits of the result.
+///
+/// The other bits of the result are zero.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+bmi2"]
+pub fn _pext_u64(a: u64, mask: u64) -> u64 {
+    unsafe { pext_64(a, mask) }
+}
+
+extern {
+    #[link_name = "llvm.x86.bmi.bzhi.32"]
+    fn bzhi_32(a: u32, index: u32) -> u32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.bmi.bzhi.64"]
+    fn bzhi_64(a: u64, index: u64) -> u64;
+    #[link_name = "llvm.x86.bmi.pdep.32"]
+    fn pdep_32(a: u32, mask: u32) -> u32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.bmi.pdep.64"]
+    fn pdep_64(a: u64, mask: u64) -> u64;
+    #[link_name = "llvm.x86.bmi.pext.32"]
+    fn pext_32(a: u32, mask: u32) -> u32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.bmi.pext.64"]
+    fn pext_64(a: u64, mask: u64) -> u64;
+}
+
+#[cfg(test)]
+mod tests {
+    use std;
+
+    use x86::bmi2;
+
+    #[test]
+    #[target_feature = "+bmi2"]
+    fn _mulx_u32() {
+        let mut hi = 0;
+        let lo = bmi2::_mulx_u32(std::u32::MAX, std::u32::MAX, &mut hi);
+        assert_eq!(lo, 1);
+        assert_eq!(hi, 0xFFFF_FFFE);
+
+        let lo = bmi2::_mulx_u32(0x1234, 0x10, &mut hi);
+        assert_eq!(lo, 0x12340);
+        assert_eq!(hi, 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi2"]
+    fn _mulx_u64() {
+        let mut hi = 0;
+        let lo = bmi2::_mulx_u64(std::u64::MAX, 2, &mut hi);
+        assert_eq!(lo, 0xFFFF_FFFF_FFFF_FFFE);
+        assert_eq!(hi, 1);
+
+        let lo = bmi2::_mulx_u64(std::u64::MAX, std::u64::MAX, &mut hi);
+        assert_eq!(lo, 1);
+        assert_eq!(hi, 0xFFFF_FFFF_FFFF_FFFE);
+    }
+
+    #[test]
+    #[target_feature = "+bmi2"]
+    fn _bzhi_u32() {
+        let n = 0b1111_0010u32;
+        assert_eq!(bmi2::_bzhi_u32(n, 5), 0b0001_0010);
+        assert_eq!(bmi2::_bzhi_u32(n, 0), 0);
+        assert_eq!(bmi2::_bzhi_u32(n, 32), n);
+        assert_eq!(bmi2::_bzhi_u32(std::u32::MAX, 31), 0x7FFF_FFFF);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi2"]
+    fn _bzhi_u64() {
+        let n = 0b1111_0010u64;
+        assert_eq!(bmi2::_bzhi_u64(n, 5), 0b0001_0010);
+        assert_eq!(bmi2::_bzhi_u64(n, 0), 0);
+        assert_eq!(bmi2::_bzhi_u64(n, 64), n);
+        assert_eq!(bmi2::_bzhi_u64(std::u64::MAX, 63), std::u64::MAX >> 1);
+    }
+
+    #[test]
+    #[target_feature = "+bmi2"]
+    fn _pdep_u32() {
+        let n = 0b1011_1110_1001_0011u32;
+
+        let m0 = 0b0110_0011_1000_0101u32;
+        let s0 = 0b0000_0010_0000_0101u32;
+
+        let m1 = 0b1110_1011_1110_1111u32;
+        let s1 = 0b1110_1001_0010_0011u32;
+
+        assert_eq!(bmi2::_pdep_u32(n, m0), s0);
+        assert_eq!(bmi2::_pdep_u32(n, m1), s1);
+        assert_eq!(bmi2::_pdep_u32(n, std::u32::MAX), n);
+        assert_eq!(bmi2::_pdep_u32(n, 0), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi2"]
+    fn _pdep_u64() {
+        let n = 0b1011_1110_1001_0011u64;
+
+        let m0 = 0b0110_0011_1000_0101u64;
+        let s0 = 0b0000_0010_0000_0101u64;
+
+        let m1 = 0b1110_1011_1110_1111u64;
+        let s1 = 0b1110_1001_0010_0011u64;
+
+        assert_eq!(bmi2::_pdep_u64(n, m0), s0);
+        assert_eq!(bmi2::_pdep_u64(n, m1), s1);
+        assert_eq!(bmi2::_pdep_u64(n, m0 << 32), s0 << 32);
+        assert_eq!(bmi2::_pdep_u64(n, 0), 0);
+    }
+
+    #[test]
+    #[target_feature = "+bmi2"]
+    fn _pext_u32() {
+        let n = 0b1011_1110_1001_0011u32;
+
+        let m0 = 0b0110_0011_1000_0101u32;
+        let s0 = 0b0000_0000_0011_0101u32;
+
+        let m1 = 0b1110_1011_1110_1111u32;
+        let s1 = 0b0001_0111_0100_0011u32;
+
+        assert_eq!(bmi2::_pext_u32(n, m0), s0);
+        assert_eq!(bmi2::_pext_u32(n, m1), s1);
+        assert_eq!(bmi2::_pext_u32(n, std::u32::MAX), n);
+        assert_eq!(bmi2::_pext_u32(n, 0), 0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+bmi2"]
+    fn _pext_u64() {
+        let n = 0b1011_1110_1001_0011u64;
+
+        let m0 = 0b0110_0011_1000_0101u64;
+        let s0 = 0b0000_0000_0011_0101u64;
+
+        let m1 = 0b1110_1011_1110_1111u64;
+        let s1 = 0b0001_0111_0100_0011u64;
+
+        assert_eq!(bmi2::_pext_u64(n, m0), s0);
+        assert_eq!(bmi2::_pext_u64(n, m1), s1);
+        assert_eq!(bmi2::_pext_u64(n << 32, m0 << 32), s0);
+        assert_eq!(bmi2::_pext_u64(n, 0), 0);
+    }
+}
@@ -0,0 +1,324 @@
+//! Run-time detection of x86 CPU features.
+//!
+//! The `#[target_feature]` functions in this module's siblings are only sound
+//! to call on CPUs that actually support the feature. The functions here
+//! query the running CPU with `cpuid` (and `xgetbv` for the features that
+//! need operating system support) so that callers can pick a code path at
+//! run time.
+
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+/// The result of a `cpuid` instruction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CpuidResult {
+    pub eax: u32,
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+}
+
+/// Returns `true` if the running CPU has the `cpuid` instruction.
+///
+/// Every x86_64 CPU has it.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub fn has_cpuid() -> bool {
+    true
+}
+
+/// Returns `true` if the running CPU has the `cpuid` instruction.
+///
+/// CPUs before some i486 models lack it. The ones that have it let software
+/// toggle the `ID` flag (bit 21) of `EFLAGS`.
+#[cfg(target_arch = "x86")]
+#[inline]
+pub fn has_cpuid() -> bool {
+    let (before, after): (u32, u32);
+    unsafe {
+        asm!("pushfl
+              pushfl
+              popl %eax
+              movl %eax, %ecx
+              xorl $$0x200000, %eax
+              pushl %eax
+              popfl
+              pushfl
+              popl %eax
+              popfl"
+             : "={eax}"(after), "={ecx}"(before)
+             :
+             : "cc"
+             : "volatile");
+    }
+    (before ^ after) & 0x200000 != 0
+}
+
+/// Returns the result of the `cpuid` instruction for the given `leaf` (`EAX`)
+/// and `sub_leaf` (`ECX`).
+///
+/// On 32-bit x86 this raises an invalid opcode exception if `has_cpuid`
+/// returns `false`.
+#[inline]
+pub fn __cpuid_count(leaf: u32, sub_leaf: u32) -> CpuidResult {
+    let (eax, ebx, ecx, edx);
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        asm!("cpuid"
+             : "={eax}"(eax), "={ebx}"(ebx), "={ecx}"(ecx), "={edx}"(edx)
+             : "{eax}"(leaf), "{ecx}"(sub_leaf)
+             :
+             : "volatile");
+    }
+    // On 32-bit x86 `ebx` holds the GOT pointer in position-independent
+    // code and cannot be an asm operand, so `cpuid` writes it to `esi`
+    // and the caller's `ebx` is restored afterwards.
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        asm!("xchgl %ebx, %esi
+              cpuid
+              xchgl %ebx, %esi"
+             : "={eax}"(eax), "={esi}"(ebx), "={ecx}"(ecx), "={edx}"(edx)
+             : "{eax}"(leaf), "{ecx}"(sub_leaf)
+             :
+             : "volatile");
+    }
+    CpuidResult { eax: eax, ebx: ebx, ecx: ecx, edx: edx }
+}
+
+/// Returns the result of the `cpuid` instruction for the given `leaf` (`EAX`)
+/// with a sub-leaf of zero.
+#[inline]
+pub fn __cpuid(leaf: u32) -> CpuidResult {
+    __cpuid_count(leaf, 0)
+}
+
+/// Reads the contents of the extended control register `XCR` specified in
+/// `xcr_no`.
+///
+/// This may only be called if `cpuid` reports `OSXSAVE`, otherwise it raises
+/// an invalid opcode exception.
+#[inline]
+pub unsafe fn _xgetbv(xcr_no: u32) -> u64 {
+    let (eax, edx): (u32, u32);
+    asm!("xgetbv"
+         : "={eax}"(eax), "={edx}"(edx)
+         : "{ecx}"(xcr_no)
+         :
+         : "volatile");
+    ((edx as u64) << 32) | eax as u64
+}
+
+macro_rules! cpu_features {
+    ($($(#[$doc:meta])* $method:ident = $bit:expr, $name:expr;)+) => {
+        impl CpuFeatures {
+            $(
+                $(#[$doc])*
+                #[inline]
+                pub fn $method(&self) -> bool {
+                    self.bits & (1 << $bit) != 0
+                }
+            )+
+        }
+
+        /// Returns `true` if the running CPU supports the target feature
+        /// `name`, spelled as in `#[target_feature]` but without the
+        /// leading `+`, e.g. `"sse4.2"` or `"avx2"`.
+        ///
+        /// The result of the detection is cached, so this is cheap to call
+        /// repeatedly.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `name` is not a feature known to this function. Use
+        /// `feature_detected` to handle unknown names.
+        pub fn is_feature_detected(name: &str) -> bool {
+            match feature_detected(name) {
+                Some(detected) => detected,
+                None => panic!("unknown target feature: {}", name),
+            }
+        }
+
+        /// Like `is_feature_detected`, but returns `None` instead of
+        /// panicking if `name` is not a feature known to this function.
+        pub fn feature_detected(name: &str) -> Option<bool> {
+            let features = cpu_features();
+            match name {
+                $($name => Some(features.$method()),)+
+                _ => None,
+            }
+        }
+    }
+}
+
+/// The set of features supported by the running CPU.
+///
+/// Features that need operating system support to save and restore extended
+/// register state (AVX, AVX2, FMA and F16C) are only reported if the
+/// operating system has enabled it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CpuFeatures {
+    bits: usize,
+}
+
+cpu_features! {
+    /// `SSE`.
+    sse = 0, "sse";
+    /// `SSE2`.
+    sse2 = 1, "sse2";
+    /// `SSE3`.
+    sse3 = 2, "sse3";
+    /// `SSSE3`.
+    ssse3 = 3, "ssse3";
+    /// `SSE4.1`.
+    sse41 = 4, "sse4.1";
+    /// `SSE4.2`.
+    sse42 = 5, "sse4.2";
+    /// `AVX`.
+    avx = 6, "avx";
+    /// `AVX2`.
+    avx2 = 7, "avx2";
+    /// Fused multiply-add (`FMA3`).
+    fma = 8, "fma";
+    /// Bit manipulation instruction set 1 (`BMI1`).
+    bmi1 = 9, "bmi";
+    /// Bit manipulation instruction set 2 (`BMI2`).
+    bmi2 = 10, "bmi2";
+    /// `POPCNT`.
+    popcnt = 11, "popcnt";
+    /// `LZCNT`.
+    lzcnt = 12, "lzcnt";
+    /// AES instructions (`AES-NI`).
+    aes = 13, "aes";
+    /// Carry-less multiplication (`PCLMULQDQ`).
+    pclmulqdq = 14, "pclmul";
+    /// Half precision conversions (`F16C`).
+    f16c = 15, "f16c";
+}
+
+impl CpuFeatures {
+    /// Queries the running CPU for its features.
+    ///
+    /// Prefer `cpu_features`, which caches the result.
+    pub fn detect() -> CpuFeatures {
+        fn bit(x: u32, n: u32) -> bool {
+            x & (1 << n) != 0
+        }
+
+        let mut bits = 0usize;
+        if !has_cpuid() {
+            return CpuFeatures { bits: bits };
+        }
+        {
+            let mut set = |feature: usize, enabled: bool| {
+                if enabled {
+                    bits |= 1 << feature;
+                }
+            };
+
+            let max_leaf = __cpuid(0).eax;
+            let leaf1 = __cpuid(1);
+            let (ecx, edx) = (leaf1.ecx, leaf1.edx);
+
+            set(0, bit(edx, 25));
+            set(1, bit(edx, 26));
+            set(2, bit(ecx, 0));
+            set(3, bit(ecx, 9));
+            set(4, bit(ecx, 19));
+            set(5, bit(ecx, 20));
+            set(11, bit(ecx, 23));
+            set(13, bit(ecx, 25));
+            set(14, bit(ecx, 1));
+
+            // AVX state (the XMM and YMM registers) must be saved by the
+            // operating system, which it signals through XCR0.
+            let os_avx =
+                bit(ecx, 27) && unsafe { _xgetbv(0) } & 0b110 == 0b110;
+            if os_avx {
+                set(6, bit(ecx, 28));
+                set(8, bit(ecx, 12));
+                set(15, bit(ecx, 29));
+            }
+
+            if max_leaf >= 7 {
+                let ebx = __cpuid_count(7, 0).ebx;
+                set(7, os_avx && bit(ebx, 5));
+                set(9, bit(ebx, 3));
+                set(10, bit(ebx, 8));
+            }
+
+            if __cpuid(0x8000_0000).eax >= 0x8000_0001 {
+                set(12, bit(__cpuid(0x8000_0001).ecx, 5));
+            }
+        }
+        CpuFeatures { bits: bits }
+    }
+}
+
+/// Set once detection has run, so that a CPU without any of the features
+/// is not detected again on every call.
+const INITIALIZED: usize = 1 << (::std::mem::size_of::<usize>() * 8 - 1);
+
+static CACHE: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Returns the features of the running CPU.
+///
+/// Detection runs on the first call; later calls return a cached result.
+#[inline]
+pub fn cpu_features() -> CpuFeatures {
+    let mut bits = CACHE.load(Ordering::Relaxed);
+    if bits == 0 {
+        bits = CpuFeatures::detect().bits | INITIALIZED;
+        CACHE.store(bits, Ordering::Relaxed);
+    }
+    CpuFeatures { bits: bits & !INITIALIZED }
+}
+
+#[cfg(test)]
+mod tests {
+    use x86::cpuid;
+
+    #[test]
+    fn cached_matches_detect() {
+        assert_eq!(cpuid::cpu_features(), cpuid::CpuFeatures::detect());
+        assert_eq!(cpuid::cpu_features(), cpuid::cpu_features());
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn x86_64_baseline() {
+        assert!(cpuid::is_feature_detected("sse"));
+        assert!(cpuid::is_feature_detected("sse2"));
+    }
+
+    #[test]
+    fn has_cpuid() {
+        // Every CPU that runs the test suite is at least an i586.
+        assert!(cpuid::has_cpuid());
+    }
+
+    #[test]
+    fn implied_features() {
+        let f = cpuid::cpu_features();
+        if f.avx2() {
+            assert!(f.avx());
+        }
+        if f.sse42() {
+            assert!(f.sse41());
+        }
+    }
+
+    #[test]
+    fn feature_detected() {
+        assert_eq!(cpuid::feature_detected("sse5"), None);
+        assert_eq!(cpuid::feature_detected("+sse2"), None);
+        assert_eq!(
+            cpuid::feature_detected("avx2"),
+            Some(cpuid::cpu_features().avx2()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unknown_feature() {
+        cpuid::is_feature_detected("sse5");
+    }
+}
@@ -0,0 +1,743 @@
+use v128::*;
+use x86::{__m128, __m256, __m256d};
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and add the intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmaddpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and add the intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfmaddpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and add the intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmaddps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and add the intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfmaddps256(a, b, c) }
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`. Store
+/// the result in the lower element of the returned value, and copy the upper
+/// element from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmadd_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmaddsd(a, b, c) }
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`. Store
+/// the result in the lower element of the returned value, and copy the upper
+/// elements from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmaddss(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and alternatively add and subtract packed elements in `c` to/from the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are subtracted, odd-indexed elements are added.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmaddsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmaddsubpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and alternatively add and subtract packed elements in `c` to/from the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are subtracted, odd-indexed elements are added.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmaddsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfmaddsubpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and alternatively add and subtract packed elements in `c` to/from the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are subtracted, odd-indexed elements are added.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmaddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmaddsubps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and alternatively add and subtract packed elements in `c` to/from the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are subtracted, odd-indexed elements are added.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmaddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfmaddsubps256(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmsubpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfmsubpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmsubps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfmsubps256(a, b, c) }
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper element from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsub_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmsubsd(a, b, c) }
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmsubss(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and alternatively subtract and add packed elements in `c` from/to the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are added, odd-indexed elements are subtracted.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsubadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfmsubaddpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and alternatively subtract and add packed elements in `c` from/to the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are added, odd-indexed elements are subtracted.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmsubadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfmsubaddpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and alternatively subtract and add packed elements in `c` from/to the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are added, odd-indexed elements are subtracted.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fmsubadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfmsubaddps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and alternatively subtract and add packed elements in `c` from/to the
+/// intermediate result.
+///
+/// Even-indexed elements of `c` are added, odd-indexed elements are subtracted.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fmsubadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfmsubaddps256(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfnmaddpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fnmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfnmaddpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfnmaddps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fnmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfnmaddps256(a, b, c) }
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`. Store the result in the lower element of the returned value, and copy
+/// the upper element from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmadd_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfnmaddsd(a, b, c) }
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`. Store the result in the lower element of the returned value, and copy
+/// the upper elements from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfnmaddss(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfnmsubpd(a, b, c) }
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fnmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    unsafe { vfnmsubpd256(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfnmsubps(a, b, c) }
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm256_fnmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    unsafe { vfnmsubps256(a, b, c) }
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated intermediate
+/// result. Store the result in the lower element of the returned value, and
+/// copy the upper element from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmsub_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 {
+    unsafe { vfnmsubsd(a, b, c) }
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated intermediate
+/// result. Store the result in the lower element of the returned value, and
+/// copy the upper elements from `a`.
+#[inline(always)]
+#[target_feature = "+fma"]
+pub fn _mm_fnmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    unsafe { vfnmsubss(a, b, c) }
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.fma.vfmadd.pd"]
+    fn vfmaddpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmadd.pd.256"]
+    fn vfmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmadd.ps"]
+    fn vfmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmadd.ps.256"]
+    fn vfmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmadd.sd"]
+    fn vfmaddsd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmadd.ss"]
+    fn vfmaddss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd"]
+    fn vfmaddsubpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd.256"]
+    fn vfmaddsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps"]
+    fn vfmaddsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps.256"]
+    fn vfmaddsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsub.pd"]
+    fn vfmsubpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmsub.pd.256"]
+    fn vfmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsub.ps"]
+    fn vfmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsub.ps.256"]
+    fn vfmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsub.sd"]
+    fn vfmsubsd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmsub.ss"]
+    fn vfmsubss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd"]
+    fn vfmsubaddpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd.256"]
+    fn vfmsubaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps"]
+    fn vfmsubaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps.256"]
+    fn vfmsubaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd"]
+    fn vfnmaddpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd.256"]
+    fn vfnmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps"]
+    fn vfnmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps.256"]
+    fn vfnmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmadd.sd"]
+    fn vfnmaddsd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfnmadd.ss"]
+    fn vfnmaddss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd"]
+    fn vfnmsubpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd.256"]
+    fn vfnmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps"]
+    fn vfnmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps.256"]
+    fn vfnmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmsub.sd"]
+    fn vfnmsubsd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfnmsub.ss"]
+    fn vfnmsubss(a: __m128, b: __m128, c: __m128) -> __m128;
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use v256::*;
+    use x86::fma;
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmadd_pd() {
+        // `(1 + e) * (1 + e)` is `1 + 2 * e + e * e`, whose last term is
+        // lost if the product is rounded before `c` is added.
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(-1.0 - 2.0 * e, 1.0);
+        let r = fma::_mm_fmadd_pd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 7.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmadd_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fmadd_pd(a, b, c);
+        assert_eq!(r, f64x4::new(e * e, 7.0, 14.0, 23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fmadd_ps(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 7.0, 14.0, 23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fmadd_ps(a, b, c);
+        let expected = f32x8::new(
+            e * e, 7.0, 14.0, 23.0, 34.0, 47.0, 62.0, 79.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmadd_sd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 10.0);
+        let c = f64x2::new(-1.0 - 2.0 * e, 100.0);
+        let r = fma::_mm_fmadd_sd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmadd_ss() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 10.0, 20.0, 30.0);
+        let c = f32x4::new(-1.0 - 2.0 * e, 100.0, 200.0, 300.0);
+        let r = fma::_mm_fmadd_ss(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmaddsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(1.0 + 2.0 * e, 1.0);
+        let r = fma::_mm_fmaddsub_pd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 7.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmaddsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fmaddsub_pd(a, b, c);
+        assert_eq!(r, f64x4::new(e * e, 7.0, 10.0, 23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmaddsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fmaddsub_ps(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 7.0, 10.0, 23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmaddsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fmaddsub_ps(a, b, c);
+        let expected = f32x8::new(
+            e * e, 7.0, 10.0, 23.0, 26.0, 47.0, 50.0, 79.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(1.0 + 2.0 * e, 1.0);
+        let r = fma::_mm_fmsub_pd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fmsub_pd(a, b, c);
+        assert_eq!(r, f64x4::new(e * e, 5.0, 10.0, 17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fmsub_ps(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 5.0, 10.0, 17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fmsub_ps(a, b, c);
+        let expected = f32x8::new(
+            e * e, 5.0, 10.0, 17.0, 26.0, 37.0, 50.0, 65.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsub_sd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 10.0);
+        let c = f64x2::new(1.0 + 2.0 * e, 100.0);
+        let r = fma::_mm_fmsub_sd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsub_ss() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 10.0, 20.0, 30.0);
+        let c = f32x4::new(1.0 + 2.0 * e, 100.0, 200.0, 300.0);
+        let r = fma::_mm_fmsub_ss(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsubadd_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(-1.0 - 2.0 * e, 1.0);
+        let r = fma::_mm_fmsubadd_pd(a, b, c);
+        assert_eq!(r, f64x2::new(e * e, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmsubadd_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fmsubadd_pd(a, b, c);
+        assert_eq!(r, f64x4::new(e * e, 5.0, 14.0, 17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fmsubadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fmsubadd_ps(a, b, c);
+        assert_eq!(r, f32x4::new(e * e, 5.0, 14.0, 17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fmsubadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fmsubadd_ps(a, b, c);
+        let expected = f32x8::new(
+            e * e, 5.0, 14.0, 17.0, 34.0, 37.0, 62.0, 65.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmadd_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(1.0 + 2.0 * e, 1.0);
+        let r = fma::_mm_fnmadd_pd(a, b, c);
+        assert_eq!(r, f64x2::new(-e * e, -5.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fnmadd_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fnmadd_pd(a, b, c);
+        assert_eq!(r, f64x4::new(-e * e, -5.0, -10.0, -17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fnmadd_ps(a, b, c);
+        assert_eq!(r, f32x4::new(-e * e, -5.0, -10.0, -17.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fnmadd_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(1.0 + 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fnmadd_ps(a, b, c);
+        let expected = f32x8::new(
+            -e * e, -5.0, -10.0, -17.0, -26.0, -37.0, -50.0, -65.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmadd_sd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 10.0);
+        let c = f64x2::new(1.0 + 2.0 * e, 100.0);
+        let r = fma::_mm_fnmadd_sd(a, b, c);
+        assert_eq!(r, f64x2::new(-e * e, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmadd_ss() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 10.0, 20.0, 30.0);
+        let c = f32x4::new(1.0 + 2.0 * e, 100.0, 200.0, 300.0);
+        let r = fma::_mm_fnmadd_ss(a, b, c);
+        assert_eq!(r, f32x4::new(-e * e, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 3.0);
+        let c = f64x2::new(-1.0 - 2.0 * e, 1.0);
+        let r = fma::_mm_fnmsub_pd(a, b, c);
+        assert_eq!(r, f64x2::new(-e * e, -7.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fnmsub_pd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f64x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f64x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm256_fnmsub_pd(a, b, c);
+        assert_eq!(r, f64x4::new(-e * e, -7.0, -14.0, -23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 3.0, 4.0, 5.0);
+        let c = f32x4::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0);
+        let r = fma::_mm_fnmsub_ps(a, b, c);
+        assert_eq!(r, f32x4::new(-e * e, -7.0, -14.0, -23.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm256_fnmsub_ps() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x8::new(1.0 + e, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0);
+        let b = f32x8::new(1.0 + e, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let c = f32x8::new(-1.0 - 2.0 * e, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0);
+        let r = fma::_mm256_fnmsub_ps(a, b, c);
+        let expected = f32x8::new(
+            -e * e, -7.0, -14.0, -23.0, -34.0, -47.0, -62.0, -79.0);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmsub_sd() {
+        let e = 1.0 / (1u64 << 30) as f64;
+        let a = f64x2::new(1.0 + e, 2.0);
+        let b = f64x2::new(1.0 + e, 10.0);
+        let c = f64x2::new(-1.0 - 2.0 * e, 100.0);
+        let r = fma::_mm_fnmsub_sd(a, b, c);
+        assert_eq!(r, f64x2::new(-e * e, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+fma"]
+    fn _mm_fnmsub_ss() {
+        let e = 1.0 / (1u32 << 13) as f32;
+        let a = f32x4::new(1.0 + e, 2.0, 3.0, 4.0);
+        let b = f32x4::new(1.0 + e, 10.0, 20.0, 30.0);
+        let c = f32x4::new(-1.0 - 2.0 * e, 100.0, 200.0, 300.0);
+        let r = fma::_mm_fnmsub_ss(a, b, c);
+        assert_eq!(r, f32x4::new(-e * e, 2.0, 3.0, 4.0));
+    }
+}
@@ -1,20 +1,49 @@
 pub use self::sse::*;
 pub use self::sse2::*;
+pub use self::sse3::*;
 pub use self::ssse3::*;
 pub use self::sse41::*;
 pub use self::sse42::*;
 pub use self::avx::*;
 pub use self::avx2::*;
+pub use self::fma::*;
+pub use self::abm::*;
+pub use self::bmi::*;
+pub use self::bmi2::*;
+pub use self::aes::*;
+pub use self::pclmulqdq::*;
 
+pub use self::cpuid::*;
+pub use self::token::*;
+
+#[allow(non_camel_case_types)]
+pub type __m128 = ::v128::f32x4;
 #[allow(non_camel_case_types)]
 pub type __m128i = ::v128::i8x16;
 #[allow(non_camel_case_types)]
+pub type __m256 = ::v256::f32x8;
+#[allow(non_camel_case_types)]
+pub type __m256d = ::v256::f64x4;
+#[allow(non_camel_case_types)]
 pub type __m256i = ::v256::i8x32;
 
+#[macro_use]
+mod macros;
+
 mod sse;
 mod sse2;
+mod sse3;
 mod ssse3;
 mod sse41;
 mod sse42;
 mod avx;
 mod avx2;
+mod fma;
+mod abm;
+mod bmi;
+mod bmi2;
+mod aes;
+mod pclmulqdq;
+
+mod cpuid;
+mod token;
@@ -0,0 +1,66 @@
+//! Carry-less multiplication (`PCLMULQDQ`).
+//!
+//! The CPUID feature is called `PCLMULQDQ`, while LLVM calls the target
+//! feature `pclmul`.
+
+use v128::*;
+use x86::__m128i;
+
+/// Perform a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2).
+///
+/// Bit 0 of `imm8` selects the low (0) or high (1) 64 bits of `a`, and bit 4
+/// the 64 bits of `b`. The other bits of `imm8` are ignored. The result is
+/// the 128-bit product.
+#[inline(always)]
+#[target_feature = "+pclmul"]
+pub fn _mm_clmulepi64_si128(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (i64x2::from(a), i64x2::from(b));
+    macro_rules! call {
+        ($imm8:expr) => {
+            __m128i::from(unsafe { pclmulqdq(a, b, $imm8) })
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.pclmulqdq"]
+    fn pclmulqdq(a: i64x2, b: i64x2, imm8: u8) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use x86::{__m128i, pclmulqdq};
+
+    #[test]
+    #[target_feature = "+pclmul"]
+    fn _mm_clmulepi64_si128() {
+        let a = u64x2::new(0x0123456789abcdef, 0xfedcba9876543210);
+        let b = u64x2::new(0xfedcba9876540545, 0x0f1e2d3c4b5a6978);
+        let (a, b) = (__m128i::from(a), __m128i::from(b));
+
+        let r = pclmulqdq::_mm_clmulepi64_si128(a, b, 0x00);
+        let e = u64x2::new(0xabcb98e8e181d293, 0x00e038d868885081);
+        assert_eq!(r, __m128i::from(e));
+
+        let r = pclmulqdq::_mm_clmulepi64_si128(a, b, 0x01);
+        let e = u64x2::new(0x01800e9f33b22e50, 0x55545150454453bd);
+        assert_eq!(r, __m128i::from(e));
+
+        let r = pclmulqdq::_mm_clmulepi64_si128(a, b, 0x10);
+        let e = u64x2::new(0x202ecf1c2f9ac0a8, 0x000eef3c0fbae088);
+        assert_eq!(r, __m128i::from(e));
+
+        let r = pclmulqdq::_mm_clmulepi64_si128(a, b, 0x11);
+        let e = u64x2::new(0x2524d40816ace780, 0x0504f428368cc7a0);
+        assert_eq!(r, __m128i::from(e));
+
+        // (x + 1) * (x + 1) = x^2 + 1
+        let c = __m128i::from(u64x2::new(0b11, 0));
+        let r = pclmulqdq::_mm_clmulepi64_si128(c, c, 0x00);
+        assert_eq!(r, __m128i::from(u64x2::new(0b101, 0)));
+    }
+}
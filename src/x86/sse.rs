@@ -1,128 +1,2392 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr;
+
+use simd_llvm::simd_shuffle4;
 use v128::*;
+use x86::__m128;
+
+/// Add the first component of `a` and `b`, the other components are copied from
+/// `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_add_ss(a: __m128, b: __m128) -> __m128 {
+    a.replace(0, a.extract(0) + b.extract(0))
+}
+
+/// Add packed single-precision (32-bit) floating-point elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_add_ps(a: __m128, b: __m128) -> __m128 {
+    a + b
+}
+
+/// Subtract the first component of `b` from `a`, the other components are
+/// copied from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_sub_ss(a: __m128, b: __m128) -> __m128 {
+    a.replace(0, a.extract(0) - b.extract(0))
+}
+
+/// Subtract packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_sub_ps(a: __m128, b: __m128) -> __m128 {
+    a - b
+}
+
+/// Multiply the first component of `a` and `b`, the other components are copied
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_mul_ss(a: __m128, b: __m128) -> __m128 {
+    a.replace(0, a.extract(0) * b.extract(0))
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_mul_ps(a: __m128, b: __m128) -> __m128 {
+    a * b
+}
+
+/// Divide the first component of `a` by `b`, the other components are copied
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_div_ss(a: __m128, b: __m128) -> __m128 {
+    a.replace(0, a.extract(0) / b.extract(0))
+}
+
+/// Divide packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_div_ps(a: __m128, b: __m128) -> __m128 {
+    a / b
+}
+
+/// Return the square root of the first single-precision (32-bit) floating-point
+/// element in `a`, the other elements are unchanged.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_sqrt_ss(a: __m128) -> __m128 {
+    unsafe { sqrtss(a) }
+}
 
 /// Return the square root of packed single-precision (32-bit) floating-point
 /// elements in `a`.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_sqrt_ps(a: f32x4) -> f32x4 {
+pub fn _mm_sqrt_ps(a: __m128) -> __m128 {
     unsafe { sqrtps(a) }
 }
 
+/// Return the approximate reciprocal of the first single-precision (32-bit)
+/// floating-point element in `a`, the other elements are unchanged.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_rcp_ss(a: __m128) -> __m128 {
+    unsafe { rcpss(a) }
+}
+
 /// Return the approximate reciprocal of packed single-precision (32-bit)
 /// floating-point elements in `a`.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_rcp_ps(a: f32x4) -> f32x4 {
+pub fn _mm_rcp_ps(a: __m128) -> __m128 {
     unsafe { rcpps(a) }
 }
 
+/// Return the approximate reciprocal square root of the first single-precision
+/// (32-bit) floating-point elements in `a`, the other elements are unchanged.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_rsqrt_ss(a: __m128) -> __m128 {
+    unsafe { rsqrtss(a) }
+}
+
 /// Return the approximate reciprocal square root of packed single-precision
 /// (32-bit) floating-point elements in `a`.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_rsqrt_ps(a: f32x4) -> f32x4 {
+pub fn _mm_rsqrt_ps(a: __m128) -> __m128 {
     unsafe { rsqrtps(a) }
 }
 
+/// Compare the first single-precision (32-bit) floating-point element of `a`
+/// and `b`, and return the minimum value in the first element of the return
+/// value, the other elements are copied from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_min_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { minss(a, b) }
+}
+
 /// Compare packed single-precision (32-bit) floating-point elements in `a` and
 /// `b`, and return the corresponding minimum values.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_min_ps(a: f32x4, b: f32x4) -> f32x4 {
+pub fn _mm_min_ps(a: __m128, b: __m128) -> __m128 {
     unsafe { minps(a, b) }
 }
 
+/// Compare the first single-precision (32-bit) floating-point element of `a`
+/// and `b`, and return the maximum value in the first element of the return
+/// value, the other elements are copied from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_max_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { maxss(a, b) }
+}
+
 /// Compare packed single-precision (32-bit) floating-point elements in `a` and
 /// `b`, and return the corresponding maximum values.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_max_ps(a: f32x4, b: f32x4) -> f32x4 {
+pub fn _mm_max_ps(a: __m128, b: __m128) -> __m128 {
     unsafe { maxps(a, b) }
 }
 
-/// Return a mask of the most significant bit of each element in `a`.
-///
-/// The mask is stored in the 4 least significant bits of the return value.
-/// All other bits are set to `0`.
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
 #[inline(always)]
 #[target_feature = "+sse"]
-pub fn _mm_movemask_ps(a: f32x4) -> i32 {
-    unsafe { movmskps(a) }
+pub fn _mm_and_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe {
+        let a: i32x4 = mem::transmute(a);
+        let b: i32x4 = mem::transmute(b);
+        mem::transmute(a & b)
+    }
 }
 
-#[allow(improper_ctypes)]
-extern {
-    #[link_name = "llvm.x86.sse.sqrt.ps"]
-    fn sqrtps(a: f32x4) -> f32x4;
-    #[link_name = "llvm.x86.sse.rcp.ps"]
-    fn rcpps(a: f32x4) -> f32x4;
-    #[link_name = "llvm.x86.sse.rsqrt.ps"]
-    fn rsqrtps(a: f32x4) -> f32x4;
-    #[link_name = "llvm.x86.sse.min.ps"]
-    fn minps(a: f32x4, b: f32x4) -> f32x4;
-    #[link_name = "llvm.x86.sse.max.ps"]
-    fn maxps(a: f32x4, b: f32x4) -> f32x4;
-    #[link_name = "llvm.x86.sse.movmsk.ps"]
-    fn movmskps(a: f32x4) -> i32;
+/// Compute the bitwise NOT of `a` and then AND with `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_andnot_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe {
+        let a: i32x4 = mem::transmute(a);
+        let b: i32x4 = mem::transmute(b);
+        mem::transmute((!a) & b)
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use v128::*;
-    use x86::sse;
-
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_sqrt_ps() {
-        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
-        let r = sse::_mm_sqrt_ps(a);
-        let e = f32x4::new(2.0, 3.6055512, 4.0, 10.0);
-        assert_eq!(r, e);
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_or_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe {
+        let a: i32x4 = mem::transmute(a);
+        let b: i32x4 = mem::transmute(b);
+        mem::transmute(a | b)
     }
+}
 
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_rcp_ps() {
-        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
-        let r = sse::_mm_rcp_ps(a);
-        let e = f32x4::new(0.24993896, 0.0769043, 0.06248474, 0.0099983215);
-        assert_eq!(r, e);
+/// Compute the bitwise exclusive OR of packed single-precision (32-bit)
+/// floating-point elements in `a` and `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_xor_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe {
+        let a: i32x4 = mem::transmute(a);
+        let b: i32x4 = mem::transmute(b);
+        mem::transmute(a ^ b)
     }
+}
 
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_rsqrt_ps() {
-        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
-        let r = sse::_mm_rsqrt_ps(a);
-        let e = f32x4::new(0.49987793, 0.2772827, 0.24993896, 0.099990845);
-        assert_eq!(r, e);
-    }
+/// Compare the lowest `f32` of both inputs for equality. The lowest 32 bits of
+/// the result will be `0xffffffff` if the comparison is true, or `0` otherwise.
+/// The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpeq_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 0) }
+}
 
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_min_ps() {
-        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
-        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
-        let r = sse::_mm_min_ps(a, b);
-        assert_eq!(r, f32x4::new(-100.0, 5.0, 0.0, -10.0));
-    }
+/// Compare the lowest `f32` of both inputs for less-than. The lowest 32 bits of
+/// the result will be `0xffffffff` if the comparison is true, or `0` otherwise.
+/// The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmplt_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 1) }
+}
 
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_max_ps() {
-        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
-        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
-        let r = sse::_mm_max_ps(a, b);
-        assert_eq!(r, f32x4::new(-1.0, 20.0, 0.0, -5.0));
-    }
+/// Compare the lowest `f32` of both inputs for less-than-or-equal. The lowest
+/// 32 bits of the result will be `0xffffffff` if the comparison is true, or `0`
+/// otherwise. The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmple_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 2) }
+}
 
-    #[test]
-    #[target_feature = "+sse"]
-    fn _mm_movemask_ps() {
-        let r = sse::_mm_movemask_ps(f32x4::new(-1.0, 5.0, -5.0, 0.0));
-        assert_eq!(r, 0b0101);
+/// Compare the lowest `f32` of both inputs for greater-than. The lowest 32 bits
+/// of the result will be `0xffffffff` if the comparison is true, or `0`
+/// otherwise. The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpgt_ss(a: __m128, b: __m128) -> __m128 {
+    // `cmpss` has no greater-than predicate, so swap the operands and take
+    // the upper elements from `a`.
+    unsafe { simd_shuffle4(a, _mm_cmplt_ss(b, a), [4, 1, 2, 3]) }
+}
 
-        let r = sse::_mm_movemask_ps(f32x4::new(-1.0, -5.0, -5.0, 0.0));
-        assert_eq!(r, 0b0111);
+/// Compare the lowest `f32` of both inputs for greater-than-or-equal. The
+/// lowest 32 bits of the result will be `0xffffffff` if the comparison is true,
+/// or `0` otherwise. The upper 96 bits of the result are the upper 96 bits of
+/// `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpge_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, _mm_cmple_ss(b, a), [4, 1, 2, 3]) }
+}
+
+/// Compare the lowest `f32` of both inputs for not-equal. The lowest 32 bits of
+/// the result will be `0xffffffff` if the comparison is true, or `0` otherwise.
+/// The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpneq_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 4) }
+}
+
+/// Compare the lowest `f32` of both inputs for not-less-than. The lowest 32
+/// bits of the result will be `0xffffffff` if the comparison is true, or `0`
+/// otherwise. The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnlt_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 5) }
+}
+
+/// Compare the lowest `f32` of both inputs for not-less-than-or-equal. The
+/// lowest 32 bits of the result will be `0xffffffff` if the comparison is true,
+/// or `0` otherwise. The upper 96 bits of the result are the upper 96 bits of
+/// `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnle_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 6) }
+}
+
+/// Compare the lowest `f32` of both inputs for not-greater-than. The lowest 32
+/// bits of the result will be `0xffffffff` if the comparison is true, or `0`
+/// otherwise. The upper 96 bits of the result are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpngt_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, _mm_cmpnlt_ss(b, a), [4, 1, 2, 3]) }
+}
+
+/// Compare the lowest `f32` of both inputs for not-greater-than-or-equal. The
+/// lowest 32 bits of the result will be `0xffffffff` if the comparison is true,
+/// or `0` otherwise. The upper 96 bits of the result are the upper 96 bits of
+/// `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnge_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, _mm_cmpnle_ss(b, a), [4, 1, 2, 3]) }
+}
+
+/// Check if the lowest `f32` of both inputs are ordered. The lowest 32 bits of
+/// the result will be `0xffffffff` if neither of `a.extract(0)` or
+/// `b.extract(0)` is a NaN, or `0` otherwise. The upper 96 bits of the result
+/// are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpord_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 7) }
+}
+
+/// Check if the lowest `f32` of both inputs are unordered. The lowest 32 bits
+/// of the result will be `0xffffffff` if any of `a.extract(0)` or
+/// `b.extract(0)` is a NaN, or `0` otherwise. The upper 96 bits of the result
+/// are the upper 96 bits of `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpunord_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpss(a, b, 3) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for equality. The element in the output vector will be `0xffffffff` if the
+/// comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpeq_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 0) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for less-than. The element in the output vector will be `0xffffffff` if the
+/// comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmplt_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 1) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for less-than-or-equal. The element in the output vector will be
+/// `0xffffffff` if the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmple_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 2) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for greater-than. The element in the output vector will be `0xffffffff` if
+/// the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpgt_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_cmplt_ps(b, a)
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for greater-than-or-equal. The element in the output vector will be
+/// `0xffffffff` if the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpge_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_cmple_ps(b, a)
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for not-equal. The element in the output vector will be `0xffffffff` if the
+/// comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpneq_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 4) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for not-less-than. The element in the output vector will be `0xffffffff` if
+/// the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnlt_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 5) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for not-less-than-or-equal. The element in the output vector will be
+/// `0xffffffff` if the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnle_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 6) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for not-greater-than. The element in the output vector will be `0xffffffff`
+/// if the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpngt_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_cmpnlt_ps(b, a)
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`
+/// for not-greater-than-or-equal. The element in the output vector will be
+/// `0xffffffff` if the comparison is true, or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpnge_ps(a: __m128, b: __m128) -> __m128 {
+    _mm_cmpnle_ps(b, a)
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`.
+/// Returns four floats that have one of two possible bit patterns. The element
+/// in the output vector will be `0xffffffff` if the input elements in `a` and
+/// `b` are ordered (i.e., neither of them is a NaN), or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpord_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 7) }
+}
+
+/// Compare each of the four floats in `a` to the corresponding element in `b`.
+/// Returns four floats that have one of two possible bit patterns. The element
+/// in the output vector will be `0xffffffff` if the input elements in `a` and
+/// `b` are unordered (i.e., at least one of them is a NaN), or `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cmpunord_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { cmpps(a, b, 3) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for equality.
+///
+/// Raises an invalid floating-point exception if either operand is a NaN,
+/// including a quiet NaN.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comieq_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comieqss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for less-than.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comilt_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comiltss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for less-than-or-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comile_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comiless(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for greater-than.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comigt_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comigtss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for greater-than-or-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comige_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comigess(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for not-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_comineq_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(comineqss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for equality.
+///
+/// Unlike `_mm_comieq_ss`, this only raises an invalid floating-point exception
+/// if either operand is a signaling NaN.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomieq_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomieqss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for less-than.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomilt_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomiltss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for less-than-or-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomile_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomiless(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for greater-than.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomigt_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomigtss(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for greater-than-or-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomige_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomigess(a, b) as u8) }
+}
+
+/// Compare the lowest `f32` of `a` and `b` for not-equal.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_ucomineq_ss(a: __m128, b: __m128) -> bool {
+    unsafe { mem::transmute(ucomineqss(a, b) as u8) }
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 32 bit integer,
+/// rounding according to the current rounding mode.
+///
+/// The result is `i32::MIN` if the input is out of range or NaN.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtss_si32(a: __m128) -> i32 {
+    unsafe { cvtss2si(a) }
+}
+
+/// Alias for `_mm_cvtss_si32`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvt_ss2si(a: __m128) -> i32 {
+    _mm_cvtss_si32(a)
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 32 bit integer with
+/// truncation.
+///
+/// The result is `i32::MIN` if the input is out of range or NaN.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvttss_si32(a: __m128) -> i32 {
+    unsafe { cvttss2si(a) }
+}
+
+/// Alias for `_mm_cvttss_si32`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtt_ss2si(a: __m128) -> i32 {
+    _mm_cvttss_si32(a)
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 64 bit integer,
+/// rounding according to the current rounding mode.
+///
+/// The result is `i64::MIN` if the input is out of range or NaN.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtss_si64(a: __m128) -> i64 {
+    unsafe { cvtss2si64(a) }
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 64 bit integer with
+/// truncation.
+///
+/// The result is `i64::MIN` if the input is out of range or NaN.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvttss_si64(a: __m128) -> i64 {
+    unsafe { cvttss2si64(a) }
+}
+
+/// Extract the lowest 32 bit float from the input vector.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtss_f32(a: __m128) -> f32 {
+    a.extract(0)
+}
+
+/// Convert a 32 bit integer to a 32 bit float. The result vector is the input
+/// vector `a` with the lowest 32 bit float replaced by the converted integer.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtsi32_ss(a: __m128, b: i32) -> __m128 {
+    a.replace(0, b as f32)
+}
+
+/// Alias for `_mm_cvtsi32_ss`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvt_si2ss(a: __m128, b: i32) -> __m128 {
+    _mm_cvtsi32_ss(a, b)
+}
+
+/// Convert a 64 bit integer to a 32 bit float. The result vector is the input
+/// vector `a` with the lowest 32 bit float replaced by the converted integer.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_cvtsi64_ss(a: __m128, b: i64) -> __m128 {
+    a.replace(0, b as f32)
+}
+
+/// Construct a `__m128` with the lowest element set to `a` and the rest set to
+/// zero.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_set_ss(a: f32) -> __m128 {
+    __m128::new(a, 0.0, 0.0, 0.0)
+}
+
+/// Construct a `__m128` with all element set to `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_set1_ps(a: f32) -> __m128 {
+    __m128::splat(a)
+}
+
+/// Alias for `_mm_set1_ps`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_set_ps1(a: f32) -> __m128 {
+    _mm_set1_ps(a)
+}
+
+/// Construct a `__m128` from four floating point values highest to lowest.
+///
+/// Note that `a` will be the highest 32 bits of the result, and `d` the lowest.
+/// This matches the standard way of writing bit patterns on x86:
+///
+/// ```text
+///  bit    127 .. 96  95 .. 64  63 .. 32  31 .. 0
+///        +---------+---------+---------+---------+
+///        |    a    |    b    |    c    |    d    |   result
+///        +---------+---------+---------+---------+
+/// ```
+///
+/// Alternatively:
+///
+/// ```text
+/// assert_eq!(__m128::new(a, b, c, d), _mm_set_ps(d, c, b, a));
+/// ```
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+    __m128::new(d, c, b, a)
+}
+
+/// Construct a `__m128` from four floating point values lowest to highest.
+///
+/// This matches the memory order of `__m128`, i.e., `a` will be the lowest 32
+/// bits of the result, and `d` the highest.
+///
+/// ```text
+/// assert_eq!(__m128::new(a, b, c, d), _mm_setr_ps(a, b, c, d));
+/// ```
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 {
+    __m128::new(a, b, c, d)
+}
+
+/// Construct a `__m128` with all elements initialized to zero.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_setzero_ps() -> __m128 {
+    __m128::splat(0.0)
+}
+
+/// Return a `__m128` with undefined elements.
+///
+/// This implementation returns a zeroed vector.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_undefined_ps() -> __m128 {
+    _mm_setzero_ps()
+}
+
+/// Shuffle packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` using `mask`.
+///
+/// The lower half of result takes values from `a` and the higher half from `b`.
+/// Mask is split to 2 control bits each to index the element from inputs.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_shuffle_ps(a: __m128, b: __m128, mask: i32) -> __m128 {
+    let mask = (mask & 0xFF) as u8;
+
+    macro_rules! shuffle_done {
+        ($x01:expr, $x23:expr, $x45:expr, $x67:expr) => {
+            unsafe {
+                simd_shuffle4(a, b, [$x01, $x23, $x45, $x67])
+            }
+        }
+    }
+    macro_rules! shuffle_x67 {
+        ($x01:expr, $x23:expr, $x45:expr) => {
+            match (mask >> 6) & 0b11 {
+                0b00 => shuffle_done!($x01, $x23, $x45, 4),
+                0b01 => shuffle_done!($x01, $x23, $x45, 5),
+                0b10 => shuffle_done!($x01, $x23, $x45, 6),
+                _ => shuffle_done!($x01, $x23, $x45, 7),
+            }
+        }
+    }
+    macro_rules! shuffle_x45 {
+        ($x01:expr, $x23:expr) => {
+            match (mask >> 4) & 0b11 {
+                0b00 => shuffle_x67!($x01, $x23, 4),
+                0b01 => shuffle_x67!($x01, $x23, 5),
+                0b10 => shuffle_x67!($x01, $x23, 6),
+                _ => shuffle_x67!($x01, $x23, 7),
+            }
+        }
+    }
+    macro_rules! shuffle_x23 {
+        ($x01:expr) => {
+            match (mask >> 2) & 0b11 {
+                0b00 => shuffle_x45!($x01, 0),
+                0b01 => shuffle_x45!($x01, 1),
+                0b10 => shuffle_x45!($x01, 2),
+                _ => shuffle_x45!($x01, 3),
+            }
+        }
+    }
+    match mask & 0b11 {
+        0b00 => shuffle_x23!(0),
+        0b01 => shuffle_x23!(1),
+        0b10 => shuffle_x23!(2),
+        _ => shuffle_x23!(3),
+    }
+}
+
+/// Unpack and interleave single-precision (32-bit) floating-point elements from
+/// the higher half of `a` and `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_unpackhi_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, b, [2, 6, 3, 7]) }
+}
+
+/// Unpack and interleave single-precision (32-bit) floating-point elements from
+/// the lower half of `a` and `b`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_unpacklo_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, b, [0, 4, 1, 5]) }
+}
+
+/// Combine higher half of `a` and `b`. The higher half of `b` occupies the
+/// lower half of result.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_movehl_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, b, [6, 7, 2, 3]) }
+}
+
+/// Combine lower half of `a` and `b`. The lower half of `b` occupies the higher
+/// half of result.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_movelh_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, b, [0, 1, 4, 5]) }
+}
+
+/// Return a `__m128` with the first component from `b` and the remaining
+/// components from `a`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_move_ss(a: __m128, b: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, b, [4, 1, 2, 3]) }
+}
+
+/// Return a mask of the most significant bit of each element in `a`.
+///
+/// The mask is stored in the 4 least significant bits of the return value. All
+/// other bits are set to `0`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_movemask_ps(a: __m128) -> i32 {
+    unsafe { movmskps(a) }
+}
+
+/// Set the upper two single-precision floating-point values with 64 bits of
+/// data loaded from the address `mem_addr`, the lower half is copied from `a`.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_loadh_pi(a: __m128, mem_addr: *const f32) -> __m128 {
+    let b = __m128::new(*mem_addr, *mem_addr.offset(1), 0.0, 0.0);
+    simd_shuffle4(a, b, [0, 1, 4, 5])
+}
+
+/// Load two floats from `mem_addr` into the lower half of a `__m128`, the upper
+/// half is copied from `a`.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_loadl_pi(a: __m128, mem_addr: *const f32) -> __m128 {
+    let b = __m128::new(*mem_addr, *mem_addr.offset(1), 0.0, 0.0);
+    simd_shuffle4(a, b, [4, 5, 2, 3])
+}
+
+/// Construct a `__m128` with the lowest element read from `mem_addr` and the
+/// other elements set to zero.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_load_ss(mem_addr: *const f32) -> __m128 {
+    __m128::new(*mem_addr, 0.0, 0.0, 0.0)
+}
+
+/// Construct a `__m128` by duplicating the value read from `mem_addr` into all
+/// elements.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_load1_ps(mem_addr: *const f32) -> __m128 {
+    __m128::splat(*mem_addr)
+}
+
+/// Alias for `_mm_load1_ps`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_load_ps1(mem_addr: *const f32) -> __m128 {
+    _mm_load1_ps(mem_addr)
+}
+
+/// Load four `f32` values from *aligned* memory into a `__m128`. If the pointer
+/// is not aligned to a 128-bit boundary (16 bytes) a general protection fault
+/// will be triggered (fatal program crash).
+///
+/// Use `_mm_loadu_ps` for potentially unaligned memory.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_load_ps(mem_addr: *const f32) -> __m128 {
+    *(mem_addr as *const __m128)
+}
+
+/// Load four `f32` values from memory into a `__m128`. There are no
+/// restrictions on memory alignment.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_loadu_ps(mem_addr: *const f32) -> __m128 {
+    let mut dst = mem::uninitialized();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m128 as *mut u8,
+        mem::size_of::<__m128>());
+    dst
+}
+
+/// Load four `f32` values from aligned memory into a `__m128` in reverse order.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_loadr_ps(mem_addr: *const f32) -> __m128 {
+    let a = _mm_load_ps(mem_addr);
+    simd_shuffle4(a, a, [3, 2, 1, 0])
+}
+
+/// Store the upper half of `a` (64 bits) into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_storeh_pi(mem_addr: *mut f32, a: __m128) {
+    *mem_addr = a.extract(2);
+    *mem_addr.offset(1) = a.extract(3);
+}
+
+/// Store the lower half of `a` (64 bits) into memory.
+///
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_storel_pi(mem_addr: *mut f32, a: __m128) {
+    *mem_addr = a.extract(0);
+    *mem_addr.offset(1) = a.extract(1);
+}
+
+/// Store the lowest 32 bit float of `a` into memory.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_store_ss(mem_addr: *mut f32, a: __m128) {
+    *mem_addr = a.extract(0);
+}
+
+/// Store the lowest 32 bit float of `a` repeated four times into *aligned*
+/// memory.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_store1_ps(mem_addr: *mut f32, a: __m128) {
+    let b: __m128 = simd_shuffle4(a, a, [0, 0, 0, 0]);
+    *(mem_addr as *mut __m128) = b;
+}
+
+/// Alias for `_mm_store1_ps`.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_store_ps1(mem_addr: *mut f32, a: __m128) {
+    _mm_store1_ps(mem_addr, a)
+}
+
+/// Store four 32-bit floats into *aligned* memory.
+///
+/// If the pointer is not aligned to a 128-bit boundary (16 bytes) a general
+/// protection fault will be triggered (fatal program crash).
+///
+/// Use `_mm_storeu_ps` for potentially unaligned memory.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_store_ps(mem_addr: *mut f32, a: __m128) {
+    *(mem_addr as *mut __m128) = a;
+}
+
+/// Store four 32-bit floats into memory. There are no restrictions on memory
+/// alignment.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_storeu_ps(mem_addr: *mut f32, a: __m128) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m128 as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m128>());
+}
+
+/// Store four 32-bit floats into *aligned* memory in reverse order.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_storer_ps(mem_addr: *mut f32, a: __m128) {
+    let b: __m128 = simd_shuffle4(a, a, [3, 2, 1, 0]);
+    *(mem_addr as *mut __m128) = b;
+}
+
+/// Perform a serializing operation on all store-to-memory instructions that
+/// were issued prior to this instruction.
+///
+/// Guarantees that every store instruction that precedes, in program order, is
+/// globally visible before any store instruction which follows the fence in
+/// program order.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_sfence() {
+    unsafe { sfence() }
+}
+
+/// Get the unsigned 32-bit value of the MXCSR control and status register.
+///
+/// The register holds the exception flags (`_MM_EXCEPT_*`), the exception
+/// masks (`_MM_MASK_*`), the rounding mode (`_MM_ROUND_*`) and the
+/// flush-to-zero (`_MM_FLUSH_ZERO_*`) and denormals-are-zero
+/// (`_MM_DENORMALS_ZERO_*`) flags. Prefer the `_MM_GET_*` helpers, which
+/// extract one of these fields.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub fn _mm_getcsr() -> u32 {
+    let mut result = 0u32;
+    unsafe {
+        stmxcsr(&mut result as *mut u32 as *mut i8);
+    }
+    result
+}
+
+/// Set the MXCSR control and status register to `value`.
+///
+/// This is unsafe because unmasking floating-point exceptions makes
+/// subsequent floating-point operations trap, and because the compiler
+/// assumes the default floating-point environment when optimizing. Prefer
+/// `MxcsrGuard` to change the environment for a limited scope.
+#[inline(always)]
+#[target_feature = "+sse"]
+pub unsafe fn _mm_setcsr(value: u32) {
+    ldmxcsr(&value as *const u32 as *mut i8);
+}
+
+/// Invalid operation exception flag.
+pub const _MM_EXCEPT_INVALID: u32 = 0x0001;
+/// Denormal operand exception flag.
+pub const _MM_EXCEPT_DENORM: u32 = 0x0002;
+/// Divide-by-zero exception flag.
+pub const _MM_EXCEPT_DIV_ZERO: u32 = 0x0004;
+/// Overflow exception flag.
+pub const _MM_EXCEPT_OVERFLOW: u32 = 0x0008;
+/// Underflow exception flag.
+pub const _MM_EXCEPT_UNDERFLOW: u32 = 0x0010;
+/// Precision (inexact result) exception flag.
+pub const _MM_EXCEPT_INEXACT: u32 = 0x0020;
+/// All exception flags.
+pub const _MM_EXCEPT_MASK: u32 = 0x003f;
+
+/// Mask for the invalid operation exception.
+pub const _MM_MASK_INVALID: u32 = 0x0080;
+/// Mask for the denormal operand exception.
+pub const _MM_MASK_DENORM: u32 = 0x0100;
+/// Mask for the divide-by-zero exception.
+pub const _MM_MASK_DIV_ZERO: u32 = 0x0200;
+/// Mask for the overflow exception.
+pub const _MM_MASK_OVERFLOW: u32 = 0x0400;
+/// Mask for the underflow exception.
+pub const _MM_MASK_UNDERFLOW: u32 = 0x0800;
+/// Mask for the precision exception.
+pub const _MM_MASK_INEXACT: u32 = 0x1000;
+/// All exception masks.
+pub const _MM_MASK_MASK: u32 = 0x1f80;
+
+/// Round to nearest, ties to even (the default).
+pub const _MM_ROUND_NEAREST: u32 = 0x0000;
+/// Round toward negative infinity.
+pub const _MM_ROUND_DOWN: u32 = 0x2000;
+/// Round toward positive infinity.
+pub const _MM_ROUND_UP: u32 = 0x4000;
+/// Round toward zero.
+pub const _MM_ROUND_TOWARD_ZERO: u32 = 0x6000;
+/// The bits of the rounding mode.
+pub const _MM_ROUND_MASK: u32 = 0x6000;
+
+/// The flush-to-zero bit.
+pub const _MM_FLUSH_ZERO_MASK: u32 = 0x8000;
+/// Flush denormal results to zero.
+pub const _MM_FLUSH_ZERO_ON: u32 = 0x8000;
+/// Return denormal results as is (the default).
+pub const _MM_FLUSH_ZERO_OFF: u32 = 0x0000;
+
+/// The denormals-are-zero bit.
+pub const _MM_DENORMALS_ZERO_MASK: u32 = 0x0040;
+/// Treat denormal inputs as zero.
+pub const _MM_DENORMALS_ZERO_ON: u32 = 0x0040;
+/// Use denormal inputs as is (the default).
+pub const _MM_DENORMALS_ZERO_OFF: u32 = 0x0000;
+
+/// Return the exception masks of MXCSR, a combination of `_MM_MASK_*`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_GET_EXCEPTION_MASK() -> u32 {
+    _mm_getcsr() & _MM_MASK_MASK
+}
+
+/// Return the exception flags of MXCSR, a combination of `_MM_EXCEPT_*`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_GET_EXCEPTION_STATE() -> u32 {
+    _mm_getcsr() & _MM_EXCEPT_MASK
+}
+
+/// Return the flush-to-zero mode of MXCSR, `_MM_FLUSH_ZERO_ON` or
+/// `_MM_FLUSH_ZERO_OFF`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_GET_FLUSH_ZERO_MODE() -> u32 {
+    _mm_getcsr() & _MM_FLUSH_ZERO_MASK
+}
+
+/// Return the denormals-are-zero mode of MXCSR, `_MM_DENORMALS_ZERO_ON` or
+/// `_MM_DENORMALS_ZERO_OFF`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_GET_DENORMALS_ZERO_MODE() -> u32 {
+    _mm_getcsr() & _MM_DENORMALS_ZERO_MASK
+}
+
+/// Return the rounding mode of MXCSR, one of `_MM_ROUND_*`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_GET_ROUNDING_MODE() -> u32 {
+    _mm_getcsr() & _MM_ROUND_MASK
+}
+
+/// Set the exception masks of MXCSR to `x`, a combination of `_MM_MASK_*`.
+///
+/// A cleared mask makes the corresponding exception trap. See `_mm_setcsr`
+/// for why this is unsafe.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub unsafe fn _MM_SET_EXCEPTION_MASK(x: u32) {
+    _mm_setcsr((_mm_getcsr() & !_MM_MASK_MASK) | (x & _MM_MASK_MASK))
+}
+
+/// Set the exception flags of MXCSR to `x`, a combination of
+/// `_MM_EXCEPT_*`. Use `0` to clear all flags.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub unsafe fn _MM_SET_EXCEPTION_STATE(x: u32) {
+    _mm_setcsr((_mm_getcsr() & !_MM_EXCEPT_MASK) | (x & _MM_EXCEPT_MASK))
+}
+
+/// Set the flush-to-zero mode of MXCSR to `x`, `_MM_FLUSH_ZERO_ON` or
+/// `_MM_FLUSH_ZERO_OFF`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub unsafe fn _MM_SET_FLUSH_ZERO_MODE(x: u32) {
+    _mm_setcsr(
+        (_mm_getcsr() & !_MM_FLUSH_ZERO_MASK) | (x & _MM_FLUSH_ZERO_MASK))
+}
+
+/// Set the denormals-are-zero mode of MXCSR to `x`, `_MM_DENORMALS_ZERO_ON`
+/// or `_MM_DENORMALS_ZERO_OFF`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub unsafe fn _MM_SET_DENORMALS_ZERO_MODE(x: u32) {
+    _mm_setcsr(
+        (_mm_getcsr() & !_MM_DENORMALS_ZERO_MASK)
+        | (x & _MM_DENORMALS_ZERO_MASK))
+}
+
+/// Set the rounding mode of MXCSR to `x`, one of `_MM_ROUND_*`.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub unsafe fn _MM_SET_ROUNDING_MODE(x: u32) {
+    _mm_setcsr((_mm_getcsr() & !_MM_ROUND_MASK) | (x & _MM_ROUND_MASK))
+}
+
+/// Changes the SSE floating-point environment for a scope.
+///
+/// Creating a guard saves MXCSR and clears its exception flags. The
+/// builder methods then change the rounding mode, flush-to-zero and
+/// denormals-are-zero. Dropping the guard restores the saved MXCSR, keeping
+/// the flags of any exceptions raised in the meantime, as those flags are
+/// sticky.
+///
+/// Exceptions stay masked, so no floating-point operation traps while the
+/// guard is alive. MXCSR is a per-thread register, so the guard cannot be
+/// sent to or shared with another thread, where dropping it would restore
+/// the wrong thread's environment.
+///
+/// ```rust
+/// use stdsimd::vendor::{MxcsrGuard, _MM_EXCEPT_DIV_ZERO};
+///
+/// let guard = unsafe {
+///     MxcsrGuard::new().flush_to_zero(true).denormals_are_zero(true)
+/// };
+/// // ... denormal heavy computation ...
+/// let divided_by_zero = guard.exceptions() & _MM_EXCEPT_DIV_ZERO != 0;
+/// # let _ = divided_by_zero;
+/// ```
+///
+/// Note that the compiler assumes the default environment and may move or
+/// constant fold floating-point operations, so only operations whose inputs
+/// are not known at compile time are reliably affected.
+#[derive(Debug)]
+pub struct MxcsrGuard {
+    saved: u32,
+    /// Keeps the guard on the thread whose MXCSR it saved.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl MxcsrGuard {
+    /// Save MXCSR and clear its exception flags.
+    #[inline]
+    #[target_feature = "+sse"]
+    pub fn new() -> MxcsrGuard {
+        let saved = _mm_getcsr();
+        unsafe { _mm_setcsr(saved & !_MM_EXCEPT_MASK) };
+        MxcsrGuard { saved: saved, _not_send: PhantomData }
+    }
+
+    /// Flush denormal results to zero if `on` is `true`.
+    ///
+    /// This is unsafe for the same reason as `_mm_setcsr`: the compiler
+    /// assumes the default floating-point environment, so code relying on
+    /// its results, such as bounds computed with floats, may misbehave.
+    #[inline]
+    #[target_feature = "+sse"]
+    pub unsafe fn flush_to_zero(self, on: bool) -> MxcsrGuard {
+        let mode = if on { _MM_FLUSH_ZERO_ON } else { _MM_FLUSH_ZERO_OFF };
+        _MM_SET_FLUSH_ZERO_MODE(mode);
+        self
+    }
+
+    /// Treat denormal inputs as zero if `on` is `true`.
+    ///
+    /// Denormals-are-zero is not available on the earliest SSE processors,
+    /// where setting it raises a general protection fault. This is unsafe
+    /// for that reason, and for the same reason as `flush_to_zero`.
+    #[inline]
+    #[target_feature = "+sse"]
+    pub unsafe fn denormals_are_zero(self, on: bool) -> MxcsrGuard {
+        let mode =
+            if on { _MM_DENORMALS_ZERO_ON } else { _MM_DENORMALS_ZERO_OFF };
+        _MM_SET_DENORMALS_ZERO_MODE(mode);
+        self
+    }
+
+    /// Use the rounding `mode`, one of `_MM_ROUND_*`.
+    ///
+    /// This is unsafe for the same reason as `flush_to_zero`.
+    #[inline]
+    #[target_feature = "+sse"]
+    pub unsafe fn rounding_mode(self, mode: u32) -> MxcsrGuard {
+        _MM_SET_ROUNDING_MODE(mode);
+        self
+    }
+
+    /// Return the exceptions raised since the guard was created, a
+    /// combination of `_MM_EXCEPT_*`.
+    #[inline]
+    #[target_feature = "+sse"]
+    pub fn exceptions(&self) -> u32 {
+        _MM_GET_EXCEPTION_STATE()
+    }
+}
+
+impl Drop for MxcsrGuard {
+    #[inline]
+    fn drop(&mut self) {
+        let raised = _MM_GET_EXCEPTION_STATE();
+        unsafe { _mm_setcsr(self.saved | raised) };
+    }
+}
+
+/// Transpose the 4x4 matrix formed by 4 rows of `__m128` in place.
+#[inline(always)]
+#[allow(non_snake_case)]
+#[target_feature = "+sse"]
+pub fn _MM_TRANSPOSE4_PS(
+    row0: &mut __m128,
+    row1: &mut __m128,
+    row2: &mut __m128,
+    row3: &mut __m128,
+) {
+    let tmp0 = _mm_unpacklo_ps(*row0, *row1);
+    let tmp2 = _mm_unpacklo_ps(*row2, *row3);
+    let tmp1 = _mm_unpackhi_ps(*row0, *row1);
+    let tmp3 = _mm_unpackhi_ps(*row2, *row3);
+
+    *row0 = _mm_movelh_ps(tmp0, tmp2);
+    *row1 = _mm_movehl_ps(tmp2, tmp0);
+    *row2 = _mm_movelh_ps(tmp1, tmp3);
+    *row3 = _mm_movehl_ps(tmp3, tmp1);
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.sse.sqrt.ss"]
+    fn sqrtss(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.sqrt.ps"]
+    fn sqrtps(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.rcp.ss"]
+    fn rcpss(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.rcp.ps"]
+    fn rcpps(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.rsqrt.ss"]
+    fn rsqrtss(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.rsqrt.ps"]
+    fn rsqrtps(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.min.ss"]
+    fn minss(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.min.ps"]
+    fn minps(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.max.ss"]
+    fn maxss(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.max.ps"]
+    fn maxps(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse.movmsk.ps"]
+    fn movmskps(a: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.cmp.ss"]
+    fn cmpss(a: __m128, b: __m128, imm8: i8) -> __m128;
+    #[link_name = "llvm.x86.sse.cmp.ps"]
+    fn cmpps(a: __m128, b: __m128, imm8: i8) -> __m128;
+    #[link_name = "llvm.x86.sse.comieq.ss"]
+    fn comieqss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.comilt.ss"]
+    fn comiltss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.comile.ss"]
+    fn comiless(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.comigt.ss"]
+    fn comigtss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.comige.ss"]
+    fn comigess(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.comineq.ss"]
+    fn comineqss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomieq.ss"]
+    fn ucomieqss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomilt.ss"]
+    fn ucomiltss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomile.ss"]
+    fn ucomiless(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomigt.ss"]
+    fn ucomigtss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomige.ss"]
+    fn ucomigess(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.ucomineq.ss"]
+    fn ucomineqss(a: __m128, b: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.cvtss2si"]
+    fn cvtss2si(a: __m128) -> i32;
+    #[link_name = "llvm.x86.sse.cvttss2si"]
+    fn cvttss2si(a: __m128) -> i32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.sse.cvtss2si64"]
+    fn cvtss2si64(a: __m128) -> i64;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.sse.cvttss2si64"]
+    fn cvttss2si64(a: __m128) -> i64;
+    #[link_name = "llvm.x86.sse.sfence"]
+    fn sfence();
+    #[link_name = "llvm.x86.sse.stmxcsr"]
+    fn stmxcsr(p: *mut i8);
+    #[link_name = "llvm.x86.sse.ldmxcsr"]
+    fn ldmxcsr(p: *mut i8);
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use x86::sse;
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_sqrt_ps() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_sqrt_ps(a);
+        let e = f32x4::new(2.0, 3.6055512, 4.0, 10.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_rcp_ps() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_rcp_ps(a);
+        let e = f32x4::new(0.24993896, 0.0769043, 0.06248474, 0.0099983215);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_rsqrt_ps() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_rsqrt_ps(a);
+        let e = f32x4::new(0.49987793, 0.2772827, 0.24993896, 0.099990845);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_min_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_min_ps(a, b);
+        assert_eq!(r, f32x4::new(-100.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_max_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_max_ps(a, b);
+        assert_eq!(r, f32x4::new(-1.0, 20.0, 0.0, -5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_movemask_ps() {
+        let r = sse::_mm_movemask_ps(f32x4::new(-1.0, 5.0, -5.0, 0.0));
+        assert_eq!(r, 0b0101);
+
+        let r = sse::_mm_movemask_ps(f32x4::new(-1.0, -5.0, -5.0, 0.0));
+        assert_eq!(r, 0b0111);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_add_ss() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_add_ss(a, b);
+        assert_eq!(r, f32x4::new(-101.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_add_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_add_ps(a, b);
+        assert_eq!(r, f32x4::new(-101.0, 25.0, 0.0, -15.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_sub_ss() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_sub_ss(a, b);
+        assert_eq!(r, f32x4::new(99.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_sub_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_sub_ps(a, b);
+        assert_eq!(r, f32x4::new(99.0, -15.0, 0.0, -5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_mul_ss() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_mul_ss(a, b);
+        assert_eq!(r, f32x4::new(100.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_mul_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_mul_ps(a, b);
+        assert_eq!(r, f32x4::new(100.0, 100.0, 0.0, 50.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_div_ss() {
+        let a = f32x4::new(-1.0, 5.0, 2.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.2, -5.0);
+        let r = sse::_mm_div_ss(a, b);
+        assert_eq!(r, f32x4::new(0.01, 5.0, 2.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_div_ps() {
+        let a = f32x4::new(-1.0, 5.0, 2.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.2, -5.0);
+        let r = sse::_mm_div_ps(a, b);
+        assert_eq!(r, f32x4::new(0.01, 0.25, 10.0, 2.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_sqrt_ss() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_sqrt_ss(a);
+        let e = f32x4::new(2.0, 13.0, 16.0, 100.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_rcp_ss() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_rcp_ss(a);
+        let e = f32x4::new(0.24993896, 13.0, 16.0, 100.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_rsqrt_ss() {
+        let a = f32x4::new(4.0, 13.0, 16.0, 100.0);
+        let r = sse::_mm_rsqrt_ss(a);
+        let e = f32x4::new(0.49987793, 13.0, 16.0, 100.0);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_min_ss() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_min_ss(a, b);
+        assert_eq!(r, f32x4::new(-100.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_max_ss() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse::_mm_max_ss(a, b);
+        assert_eq!(r, f32x4::new(-1.0, 5.0, 0.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_and_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a: f32x4 = transmute(u32x4::splat(0b0011));
+            let b: f32x4 = transmute(u32x4::splat(0b0101));
+            let r: u32x4 = transmute(sse::_mm_and_ps(a, b));
+            assert_eq!(r, u32x4::splat(0b0001));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_andnot_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a: f32x4 = transmute(u32x4::splat(0b0011));
+            let b: f32x4 = transmute(u32x4::splat(0b0101));
+            let r: u32x4 = transmute(sse::_mm_andnot_ps(a, b));
+            assert_eq!(r, u32x4::splat(0b0100));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_or_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a: f32x4 = transmute(u32x4::splat(0b0011));
+            let b: f32x4 = transmute(u32x4::splat(0b0101));
+            let r: u32x4 = transmute(sse::_mm_or_ps(a, b));
+            assert_eq!(r, u32x4::splat(0b0111));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_xor_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a: f32x4 = transmute(u32x4::splat(0b0011));
+            let b: f32x4 = transmute(u32x4::splat(0b0101));
+            let r: u32x4 = transmute(sse::_mm_xor_ps(a, b));
+            assert_eq!(r, u32x4::splat(0b0110));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpeq_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpeq_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmplt_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(2.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmplt_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmple_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmple_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpgt_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(0.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpgt_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpge_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpge_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpneq_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpneq_ss(a, b));
+            let e = u32x4::new(0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnlt_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(2.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnlt_ss(a, b));
+            let e = u32x4::new(0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnle_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(0.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnle_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpngt_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(0.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpngt_ss(a, b));
+            let e = u32x4::new(0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnge_ss() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(2.0, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnge_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpord_ss() {
+        use std::f32::NAN;
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(NAN, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpord_ss(a, b));
+            let e = u32x4::new(0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpunord_ss() {
+        use std::f32::NAN;
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(NAN, 5.0, 6.0, 7.0);
+            let r: u32x4 = transmute(sse::_mm_cmpunord_ss(a, b));
+            let e = u32x4::new(!0, transmute(2.0f32), transmute(3.0f32),
+                               transmute(4.0f32));
+            assert_eq!(r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpeq_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpeq_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, 0, 0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmplt_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmplt_ps(a, b));
+            assert_eq!(r, u32x4::new(0, !0, 0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmple_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmple_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, !0, 0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpgt_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpgt_ps(a, b));
+            assert_eq!(r, u32x4::new(0, 0, !0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpge_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpge_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, 0, !0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpneq_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpneq_ps(a, b));
+            assert_eq!(r, u32x4::new(0, !0, !0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnlt_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnlt_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, 0, !0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnle_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnle_ps(a, b));
+            assert_eq!(r, u32x4::new(0, 0, !0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpngt_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpngt_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, !0, 0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpnge_ps() {
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+            let b = f32x4::new(1.0, 3.0, 2.0, 4.0);
+            let r: u32x4 = transmute(sse::_mm_cmpnge_ps(a, b));
+            assert_eq!(r, u32x4::new(0, !0, 0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpord_ps() {
+        use std::f32::NAN;
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, NAN, 3.0, NAN);
+            let b = f32x4::new(1.0, 2.0, NAN, NAN);
+            let r: u32x4 = transmute(sse::_mm_cmpord_ps(a, b));
+            assert_eq!(r, u32x4::new(!0, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cmpunord_ps() {
+        use std::f32::NAN;
+        use std::mem::transmute;
+
+        unsafe {
+            let a = f32x4::new(1.0, NAN, 3.0, NAN);
+            let b = f32x4::new(1.0, 2.0, NAN, NAN);
+            let r: u32x4 = transmute(sse::_mm_cmpunord_ps(a, b));
+            assert_eq!(r, u32x4::new(0, !0, !0, !0));
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comieq_ss() {
+        use std::f32::NAN;
+
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_comieq_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comieq_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comieq_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comieq_ss(a, f32x4::new(NAN, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comilt_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_comilt_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comilt_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comilt_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comile_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_comile_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comile_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comile_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comigt_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_comigt_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comigt_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comigt_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comige_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_comige_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_comige_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comige_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_comineq_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_comineq_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comineq_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_comineq_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomieq_ss() {
+        use std::f32::NAN;
+
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_ucomieq_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomieq_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomieq_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomieq_ss(a, f32x4::new(NAN, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomilt_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_ucomilt_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomilt_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomilt_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomile_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_ucomile_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomile_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomile_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomigt_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_ucomigt_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomigt_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomigt_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomige_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(sse::_mm_ucomige_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(!sse::_mm_ucomige_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomige_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_ucomineq_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        assert!(!sse::_mm_ucomineq_ss(a, f32x4::new(1.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomineq_ss(a, f32x4::new(2.0, 0.0, 0.0, 0.0)));
+        assert!(sse::_mm_ucomineq_ss(a, f32x4::new(0.0, 0.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cvtss_si32() {
+        use std::i32;
+
+        let inputs = &[
+            42.0f32, -3.1, 4.0e10, 4.0e-20, i32::MAX as f32, -0.5, 1.5];
+        let result = &[42i32, -3, i32::MIN, 0, i32::MIN, 0, 2];
+        for i in 0..inputs.len() {
+            let x = f32x4::new(inputs[i], 1.0, 3.0, 4.0);
+            let e = result[i];
+            let r = sse::_mm_cvtss_si32(x);
+            assert_eq!(
+                e, r,
+                "TestCase #{} _mm_cvtss_si32({:?}) = {}, expected: {}",
+                i, x, r, e);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cvttss_si32() {
+        use std::i32;
+
+        let inputs = &[42.0f32, -3.1, 4.0e10, 4.0e-20, -0.5, 1.5, -1.9];
+        let result = &[42i32, -3, i32::MIN, 0, 0, 1, -1];
+        for i in 0..inputs.len() {
+            let x = f32x4::new(inputs[i], 1.0, 3.0, 4.0);
+            assert_eq!(sse::_mm_cvttss_si32(x), result[i]);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    #[cfg(target_arch = "x86_64")]
+    fn _mm_cvtss_si64() {
+        use std::i64;
+
+        let inputs = &[42.0f32, -3.1, 4.0e20, -0.5, 1.5];
+        let result = &[42i64, -3, i64::MIN, 0, 2];
+        for i in 0..inputs.len() {
+            let x = f32x4::new(inputs[i], 1.0, 3.0, 4.0);
+            assert_eq!(sse::_mm_cvtss_si64(x), result[i]);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cvtss_f32() {
+        let a = f32x4::new(312.0134, 5.0, 6.0, 7.0);
+        assert_eq!(sse::_mm_cvtss_f32(a), 312.0134);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_cvtsi32_ss() {
+        let a = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(sse::_mm_cvtsi32_ss(a, 4555),
+                   f32x4::new(4555.0, 6.0, 7.0, 8.0));
+        assert_eq!(sse::_mm_cvtsi32_ss(a, 322223333),
+                   f32x4::new(322223333.0, 6.0, 7.0, 8.0));
+        assert_eq!(sse::_mm_cvtsi32_ss(a, -432),
+                   f32x4::new(-432.0, 6.0, 7.0, 8.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_set_ss() {
+        let r = sse::_mm_set_ss(4.25);
+        assert_eq!(r, f32x4::new(4.25, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_set1_ps() {
+        let r1 = sse::_mm_set1_ps(4.25);
+        let r2 = sse::_mm_set_ps1(4.25);
+        assert_eq!(r1, f32x4::splat(4.25));
+        assert_eq!(r2, f32x4::splat(4.25));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_set_ps() {
+        let r = sse::_mm_set_ps(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, f32x4::new(4.0, 3.0, 2.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_setr_ps() {
+        let r = sse::_mm_setr_ps(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r, f32x4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_setzero_ps() {
+        let r = sse::_mm_setzero_ps();
+        assert_eq!(r, f32x4::splat(0.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_shuffle_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let mask = 0b00_01_01_11;
+        let r = sse::_mm_shuffle_ps(a, b, mask);
+        assert_eq!(r, f32x4::new(4.0, 2.0, 6.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_unpackhi_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = sse::_mm_unpackhi_ps(a, b);
+        assert_eq!(r, f32x4::new(3.0, 7.0, 4.0, 8.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_unpacklo_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = sse::_mm_unpacklo_ps(a, b);
+        assert_eq!(r, f32x4::new(1.0, 5.0, 2.0, 6.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_movehl_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = sse::_mm_movehl_ps(a, b);
+        assert_eq!(r, f32x4::new(7.0, 8.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_movelh_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = sse::_mm_movelh_ps(a, b);
+        assert_eq!(r, f32x4::new(1.0, 2.0, 5.0, 6.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_move_ss() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let r = sse::_mm_move_ss(a, b);
+        assert_eq!(r, f32x4::new(5.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_loadh_pi() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let x: [f32; 4] = [5.0, 6.0, 7.0, 8.0];
+        let r = unsafe { sse::_mm_loadh_pi(a, x[..].as_ptr()) };
+        assert_eq!(r, f32x4::new(1.0, 2.0, 5.0, 6.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_loadl_pi() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let x: [f32; 4] = [5.0, 6.0, 7.0, 8.0];
+        let r = unsafe { sse::_mm_loadl_pi(a, x[..].as_ptr()) };
+        assert_eq!(r, f32x4::new(5.0, 6.0, 3.0, 4.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_load_ss() {
+        let a = 42.0f32;
+        let r = unsafe { sse::_mm_load_ss(&a as *const f32) };
+        assert_eq!(r, f32x4::new(42.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_load1_ps() {
+        let a = 42.0f32;
+        let r1 = unsafe { sse::_mm_load1_ps(&a as *const f32) };
+        let r2 = unsafe { sse::_mm_load_ps1(&a as *const f32) };
+        assert_eq!(r1, f32x4::splat(42.0));
+        assert_eq!(r2, f32x4::splat(42.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_load_ps() {
+        let vals = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let p = &vals as *const f32x4 as *const f32;
+        let r = unsafe { sse::_mm_load_ps(p) };
+        assert_eq!(r, vals);
+        let r = unsafe { sse::_mm_loadr_ps(p) };
+        assert_eq!(r, f32x4::new(4.0, 3.0, 2.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_loadu_ps() {
+        let vals = &[1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let p = vals[1..].as_ptr();
+        let r = unsafe { sse::_mm_loadu_ps(p) };
+        assert_eq!(r, f32x4::new(2.0, 3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_storeh_pi() {
+        let mut vals = [0.0f32; 8];
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            sse::_mm_storeh_pi(vals[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(&vals[..4], &[0.0, 3.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_storel_pi() {
+        let mut vals = [0.0f32; 8];
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            sse::_mm_storel_pi(vals[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(&vals[..4], &[0.0, 1.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_store_ss() {
+        let mut vals = [0.0f32; 8];
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            sse::_mm_store_ss(vals[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(&vals[..3], &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_store_ps() {
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let mut dst = f32x4::splat(0.0);
+        let p = &mut dst as *mut f32x4 as *mut f32;
+        unsafe {
+            sse::_mm_store_ps(p, a);
+        }
+        assert_eq!(dst, a);
+        unsafe {
+            sse::_mm_storer_ps(p, a);
+        }
+        assert_eq!(dst, f32x4::new(4.0, 3.0, 2.0, 1.0));
+        unsafe {
+            sse::_mm_store1_ps(p, a);
+        }
+        assert_eq!(dst, f32x4::splat(1.0));
+        unsafe {
+            sse::_mm_store_ps1(p, f32x4::splat(7.0));
+        }
+        assert_eq!(dst, f32x4::splat(7.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_storeu_ps() {
+        let mut vals = [0.0f32; 6];
+        let a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        unsafe {
+            sse::_mm_storeu_ps(vals[1..].as_mut_ptr(), a);
+        }
+        assert_eq!(vals, [0.0, 1.0, 2.0, 3.0, 4.0, 0.0]);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    #[target_feature = "+sse"]
+    fn _MM_TRANSPOSE4_PS() {
+        let mut a = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let mut b = f32x4::new(5.0, 6.0, 7.0, 8.0);
+        let mut c = f32x4::new(9.0, 10.0, 11.0, 12.0);
+        let mut d = f32x4::new(13.0, 14.0, 15.0, 16.0);
+
+        sse::_MM_TRANSPOSE4_PS(&mut a, &mut b, &mut c, &mut d);
+
+        assert_eq!(a, f32x4::new(1.0, 5.0, 9.0, 13.0));
+        assert_eq!(b, f32x4::new(2.0, 6.0, 10.0, 14.0));
+        assert_eq!(c, f32x4::new(3.0, 7.0, 11.0, 15.0));
+        assert_eq!(d, f32x4::new(4.0, 8.0, 12.0, 16.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_sfence() {
+        sse::_mm_sfence();
+    }
+
+    /// Hides `x` from the optimizer, so that floating-point operations on it
+    /// are not constant folded under the default environment.
+    fn black_box<T: Copy>(x: T) -> T {
+        unsafe { ::std::ptr::read_volatile(&x) }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn _mm_getcsr_setcsr() {
+        let saved = sse::_mm_getcsr();
+        unsafe {
+            sse::_mm_setcsr(saved | sse::_MM_FLUSH_ZERO_ON);
+        }
+        assert_eq!(sse::_MM_GET_FLUSH_ZERO_MODE(), sse::_MM_FLUSH_ZERO_ON);
+        unsafe {
+            sse::_mm_setcsr(saved);
+        }
+        assert_eq!(sse::_mm_getcsr(), saved);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    #[target_feature = "+sse"]
+    fn _MM_SET_ROUNDING_MODE() {
+        let saved = sse::_mm_getcsr();
+        let one = black_box(f32x4::splat(1.0));
+        let three = black_box(f32x4::splat(3.0));
+
+        unsafe {
+            sse::_MM_SET_ROUNDING_MODE(sse::_MM_ROUND_UP);
+        }
+        assert_eq!(sse::_MM_GET_ROUNDING_MODE(), sse::_MM_ROUND_UP);
+        let up = sse::_mm_div_ps(one, three).extract(0);
+
+        unsafe {
+            sse::_MM_SET_ROUNDING_MODE(sse::_MM_ROUND_DOWN);
+        }
+        assert_eq!(sse::_MM_GET_ROUNDING_MODE(), sse::_MM_ROUND_DOWN);
+        let down = sse::_mm_div_ps(one, three).extract(0);
+
+        unsafe {
+            sse::_mm_setcsr(saved);
+        }
+        assert!(down < up);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    #[target_feature = "+sse"]
+    fn _MM_GET_EXCEPTION_STATE() {
+        let saved = sse::_mm_getcsr();
+        unsafe {
+            sse::_MM_SET_EXCEPTION_STATE(0);
+        }
+        let r = sse::_mm_div_ps(
+            black_box(f32x4::splat(1.0)), black_box(f32x4::splat(0.0)));
+        assert_eq!(r, f32x4::splat(::std::f32::INFINITY));
+        assert_eq!(
+            sse::_MM_GET_EXCEPTION_STATE() & sse::_MM_EXCEPT_DIV_ZERO,
+            sse::_MM_EXCEPT_DIV_ZERO);
+        unsafe {
+            sse::_mm_setcsr(saved);
+        }
+    }
+
+    #[test]
+    #[target_feature = "+sse"]
+    fn mxcsr_guard() {
+        let saved = sse::_mm_getcsr();
+        let tiny = black_box(f32x4::splat(1.0e-38));
+        let tenth = black_box(f32x4::splat(0.1));
+        {
+            let guard = unsafe {
+                sse::MxcsrGuard::new()
+                    .flush_to_zero(true)
+                    .rounding_mode(sse::_MM_ROUND_TOWARD_ZERO)
+            };
+            assert_eq!(guard.exceptions(), 0);
+            assert_eq!(sse::_mm_mul_ps(tiny, tenth), f32x4::splat(0.0));
+            assert!(guard.exceptions() & sse::_MM_EXCEPT_UNDERFLOW != 0);
+        }
+        assert_eq!(
+            sse::_mm_getcsr() & !sse::_MM_EXCEPT_MASK,
+            saved & !sse::_MM_EXCEPT_MASK);
+        assert!(sse::_mm_mul_ps(tiny, tenth).extract(0) > 0.0);
+        unsafe {
+            sse::_mm_setcsr(saved);
+        }
     }
 }
@@ -521,63 +521,63 @@ pub fn _mm_xor_si128(a: __m128i, b: __m128i) -> __m128i {
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpeq_epi8(a: i8x16, b: i8x16) -> i8x16 {
-    a.eq(b)
+    a.eq(b).into()
 }
 
 /// Compare packed 16-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpeq_epi16(a: i16x8, b: i16x8) -> i16x8 {
-    a.eq(b)
+    a.eq(b).into()
 }
 
 /// Compare packed 32-bit integers in `a` and `b` for equality.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpeq_epi32(a: i32x4, b: i32x4) -> i32x4 {
-    a.eq(b)
+    a.eq(b).into()
 }
 
 /// Compare packed 8-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpgt_epi8(a: i8x16, b: i8x16) -> i8x16 {
-    a.gt(b)
+    a.gt(b).into()
 }
 
 /// Compare packed 16-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpgt_epi16(a: i16x8, b: i16x8) -> i16x8 {
-    a.gt(b)
+    a.gt(b).into()
 }
 
 /// Compare packed 32-bit integers in `a` and `b` for greater-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmpgt_epi32(a: i32x4, b: i32x4) -> i32x4 {
-    a.gt(b)
+    a.gt(b).into()
 }
 
 /// Compare packed 8-bit integers in `a` and `b` for less-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmplt_epi8(a: i8x16, b: i8x16) -> i8x16 {
-    a.lt(b)
+    a.lt(b).into()
 }
 
 /// Compare packed 16-bit integers in `a` and `b` for less-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmplt_epi16(a: i16x8, b: i16x8) -> i16x8 {
-    a.lt(b)
+    a.lt(b).into()
 }
 
 /// Compare packed 32-bit integers in `a` and `b` for less-than.
 #[inline(always)]
 #[target_feature = "+sse2"]
 pub fn _mm_cmplt_epi32(a: i32x4, b: i32x4) -> i32x4 {
-    a.lt(b)
+    a.lt(b).into()
 }
 
 /// Convert the lower two packed 32-bit integers in `a` to packed
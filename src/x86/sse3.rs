@@ -0,0 +1,220 @@
+use simd_llvm::{simd_shuffle2, simd_shuffle4};
+use v128::*;
+use x86::{__m128, __m128i};
+
+/// Alternatively add and subtract packed single-precision (32-bit)
+/// floating-point elements in `a` to/from packed elements in `b`.
+///
+/// Even-indexed elements are subtracted, odd-indexed elements are added.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_addsub_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { addsubps(a, b) }
+}
+
+/// Alternatively add and subtract packed double-precision (64-bit)
+/// floating-point elements in `a` to/from packed elements in `b`.
+///
+/// The low element is subtracted, the high element is added.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_addsub_pd(a: f64x2, b: f64x2) -> f64x2 {
+    unsafe { addsubpd(a, b) }
+}
+
+/// Horizontally add adjacent pairs of double-precision (64-bit)
+/// floating-point elements in `a` and `b`, and pack the results.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_hadd_pd(a: f64x2, b: f64x2) -> f64x2 {
+    unsafe { haddpd(a, b) }
+}
+
+/// Horizontally add adjacent pairs of single-precision (32-bit)
+/// floating-point elements in `a` and `b`, and pack the results.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_hadd_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { haddps(a, b) }
+}
+
+/// Horizontally subtract adjacent pairs of double-precision (64-bit)
+/// floating-point elements in `a` and `b`, and pack the results.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_hsub_pd(a: f64x2, b: f64x2) -> f64x2 {
+    unsafe { hsubpd(a, b) }
+}
+
+/// Horizontally subtract adjacent pairs of single-precision (32-bit)
+/// floating-point elements in `a` and `b`, and pack the results.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_hsub_ps(a: __m128, b: __m128) -> __m128 {
+    unsafe { hsubps(a, b) }
+}
+
+/// Load 128-bits of integer data from unaligned memory.
+///
+/// This intrinsic may perform better than `_mm_loadu_si128` when the data
+/// crosses a cache line boundary.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub unsafe fn _mm_lddqu_si128(mem_addr: *const __m128i) -> __m128i {
+    lddqu(mem_addr as *const i8)
+}
+
+/// Duplicate the low double-precision (64-bit) floating-point element
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_movedup_pd(a: f64x2) -> f64x2 {
+    unsafe { simd_shuffle2(a, a, [0, 0]) }
+}
+
+/// Load a double-precision (64-bit) floating-point element from memory
+/// into both elements of return vector.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub unsafe fn _mm_loaddup_pd(mem_addr: *const f64) -> f64x2 {
+    f64x2::splat(*mem_addr)
+}
+
+/// Duplicate odd-indexed single-precision (32-bit) floating-point elements
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_movehdup_ps(a: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, a, [1, 1, 3, 3]) }
+}
+
+/// Duplicate even-indexed single-precision (32-bit) floating-point elements
+/// from `a`.
+#[inline(always)]
+#[target_feature = "+sse3"]
+pub fn _mm_moveldup_ps(a: __m128) -> __m128 {
+    unsafe { simd_shuffle4(a, a, [0, 0, 2, 2]) }
+}
+
+#[allow(improper_ctypes)]
+extern {
+    #[link_name = "llvm.x86.sse3.addsub.ps"]
+    fn addsubps(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse3.addsub.pd"]
+    fn addsubpd(a: f64x2, b: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.sse3.hadd.pd"]
+    fn haddpd(a: f64x2, b: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.sse3.hadd.ps"]
+    fn haddps(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse3.hsub.pd"]
+    fn hsubpd(a: f64x2, b: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.sse3.hsub.ps"]
+    fn hsubps(a: __m128, b: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse3.ldu.dq"]
+    fn lddqu(mem_addr: *const i8) -> __m128i;
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use x86::sse3;
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_addsub_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse3::_mm_addsub_ps(a, b);
+        assert_eq!(r, f32x4::new(99.0, 25.0, 0.0, -15.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_addsub_pd() {
+        let a = f64x2::new(-1.0, 5.0);
+        let b = f64x2::new(-100.0, 20.0);
+        let r = sse3::_mm_addsub_pd(a, b);
+        assert_eq!(r, f64x2::new(99.0, 25.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_hadd_pd() {
+        let a = f64x2::new(-1.0, 5.0);
+        let b = f64x2::new(-100.0, 20.0);
+        let r = sse3::_mm_hadd_pd(a, b);
+        assert_eq!(r, f64x2::new(4.0, -80.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_hadd_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse3::_mm_hadd_ps(a, b);
+        assert_eq!(r, f32x4::new(4.0, -10.0, -80.0, -5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_hsub_pd() {
+        let a = f64x2::new(-1.0, 5.0);
+        let b = f64x2::new(-100.0, 20.0);
+        let r = sse3::_mm_hsub_pd(a, b);
+        assert_eq!(r, f64x2::new(-6.0, -120.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_hsub_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let b = f32x4::new(-100.0, 20.0, 0.0, -5.0);
+        let r = sse3::_mm_hsub_ps(a, b);
+        assert_eq!(r, f32x4::new(-6.0, 10.0, -120.0, 5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_lddqu_si128() {
+        let data = [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17i8];
+        let r = unsafe {
+            sse3::_mm_lddqu_si128(data[1..].as_ptr() as *const i8x16)
+        };
+        let e = i8x16::new(
+            2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_movedup_pd() {
+        let a = f64x2::new(-1.0, 5.0);
+        let r = sse3::_mm_movedup_pd(a);
+        assert_eq!(r, f64x2::new(-1.0, -1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_loaddup_pd() {
+        let d = -5.0;
+        let r = unsafe { sse3::_mm_loaddup_pd(&d) };
+        assert_eq!(r, f64x2::new(d, d));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_movehdup_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let r = sse3::_mm_movehdup_ps(a);
+        assert_eq!(r, f32x4::new(5.0, 5.0, -10.0, -10.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse3"]
+    fn _mm_moveldup_ps() {
+        let a = f32x4::new(-1.0, 5.0, 0.0, -10.0);
+        let r = sse3::_mm_moveldup_ps(a);
+        assert_eq!(r, f32x4::new(-1.0, -1.0, 0.0, 0.0));
+    }
+}
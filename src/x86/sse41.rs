@@ -1,5 +1,46 @@
-use x86::__m128i;
+use std::mem;
 
+use simd_llvm::{simd_cast, simd_shuffle2, simd_shuffle4, simd_shuffle8};
+use v128::*;
+use v64::*;
+use x86::{__m128, __m128i};
+
+/// Round to the nearest integer.
+pub const _MM_FROUND_TO_NEAREST_INT: i32 = 0x00;
+/// Round down, toward negative infinity.
+pub const _MM_FROUND_TO_NEG_INF: i32 = 0x01;
+/// Round up, toward positive infinity.
+pub const _MM_FROUND_TO_POS_INF: i32 = 0x02;
+/// Truncate, round toward zero.
+pub const _MM_FROUND_TO_ZERO: i32 = 0x03;
+/// Use the rounding mode of `MXCSR.RC`; see `_MM_SET_ROUNDING_MODE`.
+pub const _MM_FROUND_CUR_DIRECTION: i32 = 0x04;
+/// Do not suppress exceptions.
+pub const _MM_FROUND_RAISE_EXC: i32 = 0x00;
+/// Suppress exceptions.
+pub const _MM_FROUND_NO_EXC: i32 = 0x08;
+/// Round to the nearest integer and do not suppress exceptions.
+pub const _MM_FROUND_NINT: i32 = 0x00;
+/// Round down and do not suppress exceptions.
+pub const _MM_FROUND_FLOOR: i32 =
+    _MM_FROUND_RAISE_EXC | _MM_FROUND_TO_NEG_INF;
+/// Round up and do not suppress exceptions.
+pub const _MM_FROUND_CEIL: i32 =
+    _MM_FROUND_RAISE_EXC | _MM_FROUND_TO_POS_INF;
+/// Truncate and do not suppress exceptions.
+pub const _MM_FROUND_TRUNC: i32 = _MM_FROUND_RAISE_EXC | _MM_FROUND_TO_ZERO;
+/// Use the rounding mode of `MXCSR.RC` and do not suppress exceptions.
+pub const _MM_FROUND_RINT: i32 =
+    _MM_FROUND_RAISE_EXC | _MM_FROUND_CUR_DIRECTION;
+/// Use the rounding mode of `MXCSR.RC` and suppress exceptions.
+pub const _MM_FROUND_NEARBYINT: i32 =
+    _MM_FROUND_NO_EXC | _MM_FROUND_CUR_DIRECTION;
+
+/// Blend packed 8-bit integers from `a` and `b` using `mask`.
+///
+/// The high bit of each corresponding mask byte determines the selection.
+/// If the high bit is set the element of `b` is selected. The element of
+/// `a` is selected otherwise.
 #[inline(always)]
 #[target_feature = "+sse4.1"]
 pub fn _mm_blendv_epi8(
@@ -10,19 +51,711 @@ pub fn _mm_blendv_epi8(
     unsafe { pblendvb(a, b, mask) }
 }
 
+/// Blend packed 16-bit integers from `a` and `b` using the mask `imm8`.
+///
+/// The mask bits determine the selection. A clear bit selects the
+/// corresponding element of `a`, and a set bit the corresponding
+/// element of `b`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_blend_epi16(a: i16x8, b: i16x8, imm8: i32) -> i16x8 {
+    macro_rules! blend {
+        ($imm8:expr) => {
+            unsafe {
+                simd_shuffle8(a, b, [
+                    blend_index!($imm8, 0, 8), blend_index!($imm8, 1, 8),
+                    blend_index!($imm8, 2, 8), blend_index!($imm8, 3, 8),
+                    blend_index!($imm8, 4, 8), blend_index!($imm8, 5, 8),
+                    blend_index!($imm8, 6, 8), blend_index!($imm8, 7, 8),
+                ])
+            }
+        }
+    }
+    constify_imm8!(imm8, blend)
+}
+
+/// Blend packed double-precision (64-bit) floating-point elements from `a`
+/// and `b` using `mask`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_blendv_pd(a: f64x2, b: f64x2, mask: f64x2) -> f64x2 {
+    unsafe { blendvpd(a, b, mask) }
+}
+
+/// Blend packed single-precision (32-bit) floating-point elements from `a`
+/// and `b` using `mask`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_blendv_ps(a: __m128, b: __m128, mask: __m128) -> __m128 {
+    unsafe { blendvps(a, b, mask) }
+}
+
+/// Blend packed double-precision (64-bit) floating-point elements from `a`
+/// and `b` using control mask `imm2`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_blend_pd(a: f64x2, b: f64x2, imm2: i32) -> f64x2 {
+    macro_rules! blend {
+        ($imm2:expr) => {
+            unsafe {
+                simd_shuffle2(a, b, [
+                    blend_index!($imm2, 0, 2), blend_index!($imm2, 1, 2),
+                ])
+            }
+        }
+    }
+    constify_imm2!(imm2, blend)
+}
+
+/// Blend packed single-precision (32-bit) floating-point elements from `a`
+/// and `b` using control mask `imm4`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_blend_ps(a: __m128, b: __m128, imm4: i32) -> __m128 {
+    macro_rules! blend {
+        ($imm4:expr) => {
+            unsafe {
+                simd_shuffle4(a, b, [
+                    blend_index!($imm4, 0, 4), blend_index!($imm4, 1, 4),
+                    blend_index!($imm4, 2, 4), blend_index!($imm4, 3, 4),
+                ])
+            }
+        }
+    }
+    constify_imm4!(imm4, blend)
+}
+
+/// Extract a single-precision (32-bit) floating-point element from `a`,
+/// selected with `imm8`, and return its bit pattern.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_extract_ps(a: __m128, imm8: i32) -> i32 {
+    unsafe { mem::transmute(a.extract(imm8 as u32 & 0b11)) }
+}
+
+/// Extract an 8-bit integer from `a`, selected with `imm8`, and return it
+/// zero-extended.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_extract_epi8(a: i8x16, imm8: i32) -> i32 {
+    a.extract(imm8 as u32 & 0b1111) as u8 as i32
+}
+
+/// Extract a 32-bit integer from `a` selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_extract_epi32(a: i32x4, imm8: i32) -> i32 {
+    a.extract(imm8 as u32 & 0b11)
+}
+
+/// Extract a 64-bit integer from `a` selected with `imm8`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_extract_epi64(a: i64x2, imm8: i32) -> i64 {
+    a.extract(imm8 as u32 & 0b1)
+}
+
+/// Select a single value in `b` to store at some position in `a`,
+/// then zero elements according to `imm8`.
+///
+/// `imm8` specifies which bits from operand `b` will be copied, which bits
+/// in the result they will be copied to, and which bits in the result will
+/// be cleared. The following assignments are made:
+///
+/// * Bits `[7:6]` specify the bits to copy from operand `b`:
+///     - `00`: Selects bits `[31:0]` from operand `b`.
+///     - `01`: Selects bits `[63:32]` from operand `b`.
+///     - `10`: Selects bits `[95:64]` from operand `b`.
+///     - `11`: Selects bits `[127:96]` from operand `b`.
+///
+/// * Bits `[5:4]` specify the bits in the result to which the selected bits
+/// from operand `b` are copied:
+///     - `00`: Copies the selected bits from `b` to result bits `[31:0]`.
+///     - `01`: Copies the selected bits from `b` to result bits `[63:32]`.
+///     - `10`: Copies the selected bits from `b` to result bits `[95:64]`.
+///     - `11`: Copies the selected bits from `b` to result bits `[127:96]`.
+///
+/// * Bits `[3:0]`: If any of these bits are set, the corresponding result
+/// element is cleared.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_insert_ps(a: __m128, b: __m128, imm8: i32) -> __m128 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { insertps(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Return a copy of `a` with the 8-bit integer from `i` inserted at a
+/// location specified by `imm8`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_insert_epi8(a: i8x16, i: i8, imm8: i32) -> i8x16 {
+    a.replace(imm8 as u32 & 0b1111, i)
+}
+
+/// Return a copy of `a` with the 32-bit integer from `i` inserted at a
+/// location specified by `imm8`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_insert_epi32(a: i32x4, i: i32, imm8: i32) -> i32x4 {
+    a.replace(imm8 as u32 & 0b11, i)
+}
+
+/// Return a copy of `a` with the 64-bit integer from `i` inserted at a
+/// location specified by `imm8`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_insert_epi64(a: i64x2, i: i64, imm8: i32) -> i64x2 {
+    a.replace(imm8 as u32 & 0b1, i)
+}
+
+/// Compare packed 8-bit integers in `a` and `b` and return packed maximum
+/// values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_max_epi8(a: i8x16, b: i8x16) -> i8x16 {
+    unsafe { pmaxsb(a, b) }
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
+/// packed maximum.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_max_epu16(a: u16x8, b: u16x8) -> u16x8 {
+    unsafe { pmaxuw(a, b) }
+}
+
+/// Compare packed 32-bit integers in `a` and `b`, and return packed maximum
+/// values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_max_epi32(a: i32x4, b: i32x4) -> i32x4 {
+    unsafe { pmaxsd(a, b) }
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b`, and return packed
+/// maximum values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_max_epu32(a: u32x4, b: u32x4) -> u32x4 {
+    unsafe { pmaxud(a, b) }
+}
+
+/// Compare packed 8-bit integers in `a` and `b` and return packed minimum
+/// values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_min_epi8(a: i8x16, b: i8x16) -> i8x16 {
+    unsafe { pminsb(a, b) }
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b`, and return
+/// packed minimum.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_min_epu16(a: u16x8, b: u16x8) -> u16x8 {
+    unsafe { pminuw(a, b) }
+}
+
+/// Compare packed 32-bit integers in `a` and `b`, and return packed minimum
+/// values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_min_epi32(a: i32x4, b: i32x4) -> i32x4 {
+    unsafe { pminsd(a, b) }
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b`, and return packed
+/// minimum values.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_min_epu32(a: u32x4, b: u32x4) -> u32x4 {
+    unsafe { pminud(a, b) }
+}
+
+/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using unsigned saturation.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_packus_epi32(a: i32x4, b: i32x4) -> u16x8 {
+    unsafe { packusdw(a, b) }
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for equality.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cmpeq_epi64(a: i64x2, b: i64x2) -> i64x2 {
+    a.eq(b).into()
+}
+
+/// Sign extend packed 8-bit integers in `a` to packed 16-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi8_epi16(a: i8x16) -> i16x8 {
+    unsafe {
+        simd_cast::<i8x8, _>(simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]))
+    }
+}
+
+/// Sign extend packed 8-bit integers in `a` to packed 32-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi8_epi32(a: i8x16) -> i32x4 {
+    _mm_cvtepi16_epi32(_mm_cvtepi8_epi16(a))
+}
+
+/// Sign extend packed 8-bit integers in the low 8 bytes of `a` to packed
+/// 64-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi8_epi64(a: i8x16) -> i64x2 {
+    _mm_cvtepi32_epi64(_mm_cvtepi8_epi32(a))
+}
+
+/// Sign extend packed 16-bit integers in `a` to packed 32-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi16_epi32(a: i16x8) -> i32x4 {
+    unsafe { simd_cast::<i16x4, _>(simd_shuffle4(a, a, [0, 1, 2, 3])) }
+}
+
+/// Sign extend packed 16-bit integers in `a` to packed 64-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi16_epi64(a: i16x8) -> i64x2 {
+    _mm_cvtepi32_epi64(_mm_cvtepi16_epi32(a))
+}
+
+/// Sign extend packed 32-bit integers in `a` to packed 64-bit integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepi32_epi64(a: i32x4) -> i64x2 {
+    unsafe { simd_cast::<i32x2, _>(simd_shuffle2(a, a, [0, 1])) }
+}
+
+/// Zero extend packed unsigned 8-bit integers in `a` to packed 16-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu8_epi16(a: u8x16) -> i16x8 {
+    unsafe {
+        simd_cast::<u8x8, _>(simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]))
+    }
+}
+
+/// Zero extend packed unsigned 8-bit integers in `a` to packed 32-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu8_epi32(a: u8x16) -> i32x4 {
+    // The 16-bit intermediates are at most 255, so sign extending them
+    // further is a zero extension.
+    _mm_cvtepi16_epi32(_mm_cvtepu8_epi16(a))
+}
+
+/// Zero extend packed unsigned 8-bit integers in `a` to packed 64-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu8_epi64(a: u8x16) -> i64x2 {
+    _mm_cvtepi32_epi64(_mm_cvtepu8_epi32(a))
+}
+
+/// Zero extend packed unsigned 16-bit integers in `a` to packed 32-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu16_epi32(a: u16x8) -> i32x4 {
+    unsafe { simd_cast::<u16x4, _>(simd_shuffle4(a, a, [0, 1, 2, 3])) }
+}
+
+/// Zero extend packed unsigned 16-bit integers in `a` to packed 64-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu16_epi64(a: u16x8) -> i64x2 {
+    _mm_cvtepi32_epi64(_mm_cvtepu16_epi32(a))
+}
+
+/// Zero extend packed unsigned 32-bit integers in `a` to packed 64-bit
+/// integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_cvtepu32_epi64(a: u32x4) -> i64x2 {
+    unsafe { simd_cast::<u32x2, _>(simd_shuffle2(a, a, [0, 1])) }
+}
+
+/// Return the dot product of two f64x2 vectors.
+///
+/// `imm8[1:0]` is the broadcast mask, and `imm8[5:4]` is the condition mask.
+/// If a condition mask bit is zero, the corresponding multiplication is
+/// replaced by a value of `0.0`. If a broadcast mask bit is one, the result
+/// of the dot product will be stored in the return value component.
+/// Otherwise if the broadcast mask bit is zero then the return component
+/// will be zero.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_dp_pd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { dppd(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Return the dot product of two f32x4 vectors.
+///
+/// `imm8[3:0]` is the broadcast mask, and `imm8[7:4]` is the condition mask.
+/// If a condition mask bit is zero, the corresponding multiplication is
+/// replaced by a value of `0.0`. If a broadcast mask bit is one, the result
+/// of the dot product will be stored in the return value component.
+/// Otherwise if the broadcast mask bit is zero then the return component
+/// will be zero.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_dp_ps(a: __m128, b: __m128, imm8: i32) -> __m128 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { dpps(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// down to an integer value, and return the results as packed
+/// double-precision floating-point elements.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_floor_pd(a: f64x2) -> f64x2 {
+    _mm_round_pd(a, _MM_FROUND_FLOOR)
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// down to an integer value, and return the results as packed
+/// single-precision floating-point elements.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_floor_ps(a: __m128) -> __m128 {
+    _mm_round_ps(a, _MM_FROUND_FLOOR)
+}
+
+/// Round the lower double-precision (64-bit) floating-point element in `b`
+/// down to an integer value, store the result as a double-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper element from `a` to the upper element of the return
+/// value.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_floor_sd(a: f64x2, b: f64x2) -> f64x2 {
+    _mm_round_sd(a, b, _MM_FROUND_FLOOR)
+}
+
+/// Round the lower single-precision (32-bit) floating-point element in `b`
+/// down to an integer value, store the result as a single-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper 3 packed elements from `a` to the upper elements of the
+/// return value.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_floor_ss(a: __m128, b: __m128) -> __m128 {
+    _mm_round_ss(a, b, _MM_FROUND_FLOOR)
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// up to an integer value, and return the results as packed
+/// double-precision floating-point elements.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_ceil_pd(a: f64x2) -> f64x2 {
+    _mm_round_pd(a, _MM_FROUND_CEIL)
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// up to an integer value, and return the results as packed
+/// single-precision floating-point elements.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_ceil_ps(a: __m128) -> __m128 {
+    _mm_round_ps(a, _MM_FROUND_CEIL)
+}
+
+/// Round the lower double-precision (64-bit) floating-point element in `b`
+/// up to an integer value, store the result as a double-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper element from `a` to the upper element of the return
+/// value.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_ceil_sd(a: f64x2, b: f64x2) -> f64x2 {
+    _mm_round_sd(a, b, _MM_FROUND_CEIL)
+}
+
+/// Round the lower single-precision (32-bit) floating-point element in `b`
+/// up to an integer value, store the result as a single-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper 3 packed elements from `a` to the upper elements of the
+/// return value.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_ceil_ss(a: __m128, b: __m128) -> __m128 {
+    _mm_round_ss(a, b, _MM_FROUND_CEIL)
+}
+
+/// Round the packed double-precision (64-bit) floating-point elements in `a`
+/// using the `rounding` parameter, and return the results as packed
+/// double-precision floating-point elements.
+///
+/// `rounding` is one of the `_MM_FROUND_TO_*` modes or
+/// `_MM_FROUND_CUR_DIRECTION`, optionally combined with
+/// `_MM_FROUND_NO_EXC`, or one of the shorthands `_MM_FROUND_NINT`,
+/// `_MM_FROUND_FLOOR`, `_MM_FROUND_CEIL`, `_MM_FROUND_TRUNC`,
+/// `_MM_FROUND_RINT` and `_MM_FROUND_NEARBYINT`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_round_pd(a: f64x2, rounding: i32) -> f64x2 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            unsafe { roundpd(a, $imm4) }
+        }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Round the packed single-precision (32-bit) floating-point elements in `a`
+/// using the `rounding` parameter, and return the results as packed
+/// single-precision floating-point elements.
+///
+/// See `_mm_round_pd` for the values of `rounding`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_round_ps(a: __m128, rounding: i32) -> __m128 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            unsafe { roundps(a, $imm4) }
+        }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Round the lower double-precision (64-bit) floating-point element in `b`
+/// using the `rounding` parameter, store the result as a double-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper element from `a` to the upper element of the return
+/// value.
+///
+/// See `_mm_round_pd` for the values of `rounding`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_round_sd(a: f64x2, b: f64x2, rounding: i32) -> f64x2 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            unsafe { roundsd(a, b, $imm4) }
+        }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Round the lower single-precision (32-bit) floating-point element in `b`
+/// using the `rounding` parameter, store the result as a single-precision
+/// floating-point element in the lower element of the return value, and
+/// copy the upper 3 packed elements from `a` to the upper elements of the
+/// return value.
+///
+/// See `_mm_round_pd` for the values of `rounding`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_round_ss(a: __m128, b: __m128, rounding: i32) -> __m128 {
+    macro_rules! call {
+        ($imm4:expr) => {
+            unsafe { roundss(a, b, $imm4) }
+        }
+    }
+    constify_imm4!(rounding, call)
+}
+
+/// Find the minimum unsigned 16-bit element in the 128-bit vector `a`.
+///
+/// Return a vector whose lowest element is the minimum value, whose second
+/// lowest element is the index of that value (the lowest index if the
+/// minimum occurs several times) and whose other elements are zero.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_minpos_epu16(a: u16x8) -> u16x8 {
+    unsafe { phminposuw(a) }
+}
+
+/// Multiply the low 32-bit integers from each packed 64-bit element
+/// in `a` and `b`, and return the signed 64-bit results.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_mul_epi32(a: i32x4, b: i32x4) -> i64x2 {
+    unsafe { pmuldq(a, b) }
+}
+
+/// Multiply the packed 32-bit integers in `a` and `b`, producing
+/// intermediate 64-bit integers, and return the low 32 bits of the
+/// intermediate integers.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_mullo_epi32(a: i32x4, b: i32x4) -> i32x4 {
+    a * b
+}
+
+/// Compute the sum of absolute differences (SADs) of quadruplets of
+/// unsigned 8-bit integers in `a` compared to those in `b`, and return the
+/// 16-bit results.
+///
+/// Eight SADs are performed using one quadruplet from `b` and eight
+/// quadruplets from `a`. The quadruplet of `b` starts at byte
+/// `4 * imm8[1:0]`. The quadruplets of `a` are formed from the sequential
+/// bytes starting at byte `4 * imm8[2]`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_mpsadbw_epu8(a: u8x16, b: u8x16, imm8: i32) -> u16x8 {
+    macro_rules! call {
+        ($imm3:expr) => {
+            unsafe { mpsadbw(a, b, $imm3) }
+        }
+    }
+    constify_imm3!(imm8, call)
+}
+
+/// Test whether the bits of `a` selected by `mask` are all zeros.
+///
+/// Return `1` if `a & mask` is all zeros, `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_testz_si128(a: __m128i, mask: __m128i) -> i32 {
+    unsafe { ptestz(i64x2::from(a), i64x2::from(mask)) }
+}
+
+/// Test whether the bits of `a` selected by `mask` are all ones.
+///
+/// Return `1` if `!a & mask` is all zeros, `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_testc_si128(a: __m128i, mask: __m128i) -> i32 {
+    unsafe { ptestc(i64x2::from(a), i64x2::from(mask)) }
+}
+
+/// Test whether the bits of `a` selected by `mask` are a mix of zeros and
+/// ones.
+///
+/// Return `1` if neither `a & mask` nor `!a & mask` is all zeros, `0`
+/// otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_testnzc_si128(a: __m128i, mask: __m128i) -> i32 {
+    unsafe { ptestnzc(i64x2::from(a), i64x2::from(mask)) }
+}
+
+/// Test whether the bits of `a` selected by `mask` are all zeros.
+///
+/// This is the same as `_mm_testz_si128`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_test_all_zeros(a: __m128i, mask: __m128i) -> i32 {
+    _mm_testz_si128(a, mask)
+}
+
+/// Test whether all the bits of `a` are ones.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_test_all_ones(a: __m128i) -> i32 {
+    _mm_testc_si128(a, __m128i::splat(-1))
+}
+
+/// Test whether the bits of `a` selected by `mask` are a mix of zeros and
+/// ones.
+///
+/// This is the same as `_mm_testnzc_si128`.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub fn _mm_test_mix_ones_zeros(a: __m128i, mask: __m128i) -> i32 {
+    _mm_testnzc_si128(a, mask)
+}
+
+/// Load 128-bits of integer data from memory using a non-temporal memory
+/// hint.
+///
+/// `mem_addr` must be aligned on a 16-byte boundary or a general-protection
+/// exception may be generated.
+#[inline(always)]
+#[target_feature = "+sse4.1"]
+pub unsafe fn _mm_stream_load_si128(mem_addr: *const __m128i) -> __m128i {
+    __m128i::from(movntdqa(mem_addr as *const i8))
+}
+
 #[allow(improper_ctypes)]
 extern {
     #[link_name = "llvm.x86.sse41.pblendvb"]
     fn pblendvb(a: __m128i, b: __m128i, mask: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.sse41.blendvpd"]
+    fn blendvpd(a: f64x2, b: f64x2, mask: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.sse41.blendvps"]
+    fn blendvps(a: __m128, b: __m128, mask: __m128) -> __m128;
+    #[link_name = "llvm.x86.sse41.insertps"]
+    fn insertps(a: __m128, b: __m128, imm8: u8) -> __m128;
+    #[link_name = "llvm.x86.sse41.pmaxsb"]
+    fn pmaxsb(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.sse41.pmaxuw"]
+    fn pmaxuw(a: u16x8, b: u16x8) -> u16x8;
+    #[link_name = "llvm.x86.sse41.pmaxsd"]
+    fn pmaxsd(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sse41.pmaxud"]
+    fn pmaxud(a: u32x4, b: u32x4) -> u32x4;
+    #[link_name = "llvm.x86.sse41.pminsb"]
+    fn pminsb(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.sse41.pminuw"]
+    fn pminuw(a: u16x8, b: u16x8) -> u16x8;
+    #[link_name = "llvm.x86.sse41.pminsd"]
+    fn pminsd(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sse41.pminud"]
+    fn pminud(a: u32x4, b: u32x4) -> u32x4;
+    #[link_name = "llvm.x86.sse41.packusdw"]
+    fn packusdw(a: i32x4, b: i32x4) -> u16x8;
+    #[link_name = "llvm.x86.sse41.dppd"]
+    fn dppd(a: f64x2, b: f64x2, imm8: u8) -> f64x2;
+    #[link_name = "llvm.x86.sse41.dpps"]
+    fn dpps(a: __m128, b: __m128, imm8: u8) -> __m128;
+    #[link_name = "llvm.x86.sse41.round.pd"]
+    fn roundpd(a: f64x2, rounding: i32) -> f64x2;
+    #[link_name = "llvm.x86.sse41.round.ps"]
+    fn roundps(a: __m128, rounding: i32) -> __m128;
+    #[link_name = "llvm.x86.sse41.round.sd"]
+    fn roundsd(a: f64x2, b: f64x2, rounding: i32) -> f64x2;
+    #[link_name = "llvm.x86.sse41.round.ss"]
+    fn roundss(a: __m128, b: __m128, rounding: i32) -> __m128;
+    #[link_name = "llvm.x86.sse41.phminposuw"]
+    fn phminposuw(a: u16x8) -> u16x8;
+    #[link_name = "llvm.x86.sse41.pmuldq"]
+    fn pmuldq(a: i32x4, b: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.sse41.mpsadbw"]
+    fn mpsadbw(a: u8x16, b: u8x16, imm8: u8) -> u16x8;
+    #[link_name = "llvm.x86.sse41.ptestz"]
+    fn ptestz(a: i64x2, mask: i64x2) -> i32;
+    #[link_name = "llvm.x86.sse41.ptestc"]
+    fn ptestc(a: i64x2, mask: i64x2) -> i32;
+    #[link_name = "llvm.x86.sse41.ptestnzc"]
+    fn ptestnzc(a: i64x2, mask: i64x2) -> i32;
+    #[link_name = "llvm.x86.sse41.movntdqa"]
+    fn movntdqa(mem_addr: *const i8) -> i64x2;
 }
 
 #[cfg(test)]
 mod tests {
+    use std::mem;
+
     use v128::*;
-    use x86::sse41;
+    use x86::{__m128i, sse41};
 
     #[test]
-    #[target_feature = "+sse4.2"]
+    #[target_feature = "+sse4.1"]
     fn _mm_blendv_epi8() {
         let a = i8x16::new(
             0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
@@ -34,4 +767,583 @@ mod tests {
             0, 17, 2, 19, 4, 21, 6, 23, 8, 25, 10, 27, 12, 29, 14, 31);
         assert_eq!(sse41::_mm_blendv_epi8(a, b, mask), e);
     }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_blendv_pd() {
+        let a = f64x2::splat(0.0);
+        let b = f64x2::splat(1.0);
+        let mask = f64x2::new(0.0, -0.0);
+        let r = sse41::_mm_blendv_pd(a, b, mask);
+        assert_eq!(r, f64x2::new(0.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_blendv_ps() {
+        let a = f32x4::splat(0.0);
+        let b = f32x4::splat(1.0);
+        let mask = f32x4::new(-0.0, 0.0, -1.0, 1.0);
+        let r = sse41::_mm_blendv_ps(a, b, mask);
+        assert_eq!(r, f32x4::new(1.0, 0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_blend_pd() {
+        let a = f64x2::splat(0.0);
+        let b = f64x2::splat(1.0);
+        assert_eq!(sse41::_mm_blend_pd(a, b, 0b10), f64x2::new(0.0, 1.0));
+        assert_eq!(sse41::_mm_blend_pd(a, b, 0b11), b);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_blend_ps() {
+        let a = f32x4::splat(0.0);
+        let b = f32x4::splat(1.0);
+        let r = sse41::_mm_blend_ps(a, b, 0b1010);
+        assert_eq!(r, f32x4::new(0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_blend_epi16() {
+        let a = i16x8::splat(0);
+        let b = i16x8::splat(1);
+        let r = sse41::_mm_blend_epi16(a, b, 0b1010_1100);
+        assert_eq!(r, i16x8::new(0, 0, 1, 1, 0, 1, 0, 1));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_extract_ps() {
+        let a = f32x4::new(0.0, 1.0, 2.0, 3.0);
+        let r: f32 = unsafe { mem::transmute(sse41::_mm_extract_ps(a, 1)) };
+        assert_eq!(r, 1.0);
+        let r: f32 = unsafe { mem::transmute(sse41::_mm_extract_ps(a, 5)) };
+        assert_eq!(r, 1.0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_extract_epi8() {
+        let a = i8x16::new(
+            -1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        assert_eq!(sse41::_mm_extract_epi8(a, 0), 0xFF);
+        assert_eq!(sse41::_mm_extract_epi8(a, 19), 3);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_extract_epi32() {
+        let a = i32x4::new(0, 1, 2, 3);
+        assert_eq!(sse41::_mm_extract_epi32(a, 1), 1);
+        assert_eq!(sse41::_mm_extract_epi32(a, 7), 3);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_extract_epi64() {
+        let a = i64x2::new(0, 1);
+        assert_eq!(sse41::_mm_extract_epi64(a, 1), 1);
+        assert_eq!(sse41::_mm_extract_epi64(a, 2), 0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_insert_ps() {
+        let a = f32x4::splat(1.0);
+        let b = f32x4::new(1.0, 2.0, 3.0, 4.0);
+        let r = sse41::_mm_insert_ps(a, b, 0b11_00_1100);
+        assert_eq!(r, f32x4::new(4.0, 1.0, 0.0, 0.0));
+        let r = sse41::_mm_insert_ps(a, b, 0b01_10_0001);
+        assert_eq!(r, f32x4::new(0.0, 1.0, 2.0, 1.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_insert_epi8() {
+        let a = i8x16::splat(0);
+        let e = i8x16::splat(0).replace(1, 32);
+        assert_eq!(sse41::_mm_insert_epi8(a, 32, 1), e);
+        let e = i8x16::splat(0).replace(14, 32);
+        assert_eq!(sse41::_mm_insert_epi8(a, 32, 30), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_insert_epi32() {
+        let a = i32x4::splat(0);
+        let e = i32x4::splat(0).replace(1, 32);
+        assert_eq!(sse41::_mm_insert_epi32(a, 32, 1), e);
+        let e = i32x4::splat(0).replace(3, 32);
+        assert_eq!(sse41::_mm_insert_epi32(a, 32, 7), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_insert_epi64() {
+        let a = i64x2::splat(0);
+        let e = i64x2::splat(0).replace(1, 32);
+        assert_eq!(sse41::_mm_insert_epi64(a, 32, 1), e);
+        let e = i64x2::splat(0).replace(1, 32);
+        assert_eq!(sse41::_mm_insert_epi64(a, 32, 3), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_max_epi8() {
+        let a = i8x16::new(
+            1, 4, 5, 8, 9, 12, 13, 16, 17, 20, 21, 24, 25, 28, 29, -32);
+        let b = i8x16::new(
+            2, 3, 6, 7, 10, 11, 14, 15, 18, 19, 22, 23, 26, 27, 30, -31);
+        let e = i8x16::new(
+            2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30, -31);
+        assert_eq!(sse41::_mm_max_epi8(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_max_epu16() {
+        let a = u16x8::new(1, 4, 5, 8, 9, 12, 13, 65535);
+        let b = u16x8::new(2, 3, 6, 7, 10, 11, 14, 15);
+        let e = u16x8::new(2, 4, 6, 8, 10, 12, 14, 65535);
+        assert_eq!(sse41::_mm_max_epu16(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_max_epi32() {
+        let a = i32x4::new(1, 4, 5, -8);
+        let b = i32x4::new(2, 3, 6, -7);
+        assert_eq!(sse41::_mm_max_epi32(a, b), i32x4::new(2, 4, 6, -7));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_max_epu32() {
+        let a = u32x4::new(1, 4, 5, 4294967295);
+        let b = u32x4::new(2, 3, 6, 7);
+        let e = u32x4::new(2, 4, 6, 4294967295);
+        assert_eq!(sse41::_mm_max_epu32(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_min_epi8() {
+        let a = i8x16::new(
+            1, 4, 5, 8, 9, 12, 13, 16, 17, 20, 21, 24, 25, 28, 29, -32);
+        let b = i8x16::new(
+            2, 3, 6, 7, 10, 11, 14, 15, 18, 19, 22, 23, 26, 27, 30, -31);
+        let e = i8x16::new(
+            1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 23, 25, 27, 29, -32);
+        assert_eq!(sse41::_mm_min_epi8(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_min_epu16() {
+        let a = u16x8::new(1, 4, 5, 8, 9, 12, 13, 65535);
+        let b = u16x8::new(2, 3, 6, 7, 10, 11, 14, 15);
+        let e = u16x8::new(1, 3, 5, 7, 9, 11, 13, 15);
+        assert_eq!(sse41::_mm_min_epu16(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_min_epi32() {
+        let a = i32x4::new(1, 4, 5, -8);
+        let b = i32x4::new(2, 3, 6, -7);
+        assert_eq!(sse41::_mm_min_epi32(a, b), i32x4::new(1, 3, 5, -8));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_min_epu32() {
+        let a = u32x4::new(1, 4, 5, 4294967295);
+        let b = u32x4::new(2, 3, 6, 7);
+        assert_eq!(sse41::_mm_min_epu32(a, b), u32x4::new(1, 3, 5, 7));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_packus_epi32() {
+        let a = i32x4::new(1, 2, 3, 4);
+        let b = i32x4::new(-1, -2, -3, 70000);
+        let e = u16x8::new(1, 2, 3, 4, 0, 0, 0, 65535);
+        assert_eq!(sse41::_mm_packus_epi32(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cmpeq_epi64() {
+        let a = i64x2::new(0, 1);
+        let b = i64x2::new(0, 0);
+        assert_eq!(sse41::_mm_cmpeq_epi64(a, b), i64x2::new(-1, 0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi8_epi16() {
+        let a = i8x16::splat(10);
+        assert_eq!(sse41::_mm_cvtepi8_epi16(a), i16x8::splat(10));
+        let a = i8x16::splat(-10);
+        assert_eq!(sse41::_mm_cvtepi8_epi16(a), i16x8::splat(-10));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi8_epi32() {
+        let a = i8x16::splat(10);
+        assert_eq!(sse41::_mm_cvtepi8_epi32(a), i32x4::splat(10));
+        let a = i8x16::splat(-10);
+        assert_eq!(sse41::_mm_cvtepi8_epi32(a), i32x4::splat(-10));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi8_epi64() {
+        let a = i8x16::splat(0).replace(0, -10).replace(1, 10);
+        assert_eq!(sse41::_mm_cvtepi8_epi64(a), i64x2::new(-10, 10));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi16_epi32() {
+        let a = i16x8::new(-1, 2, -3, 4, 5, 6, 7, 8);
+        let e = i32x4::new(-1, 2, -3, 4);
+        assert_eq!(sse41::_mm_cvtepi16_epi32(a), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi16_epi64() {
+        let a = i16x8::new(-1, 2, -3, 4, 5, 6, 7, 8);
+        assert_eq!(sse41::_mm_cvtepi16_epi64(a), i64x2::new(-1, 2));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepi32_epi64() {
+        let a = i32x4::new(-1, 2, -3, 4);
+        assert_eq!(sse41::_mm_cvtepi32_epi64(a), i64x2::new(-1, 2));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu8_epi16() {
+        let a = u8x16::splat(255);
+        assert_eq!(sse41::_mm_cvtepu8_epi16(a), i16x8::splat(255));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu8_epi32() {
+        let a = u8x16::splat(255);
+        assert_eq!(sse41::_mm_cvtepu8_epi32(a), i32x4::splat(255));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu8_epi64() {
+        let a = u8x16::splat(255);
+        assert_eq!(sse41::_mm_cvtepu8_epi64(a), i64x2::splat(255));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu16_epi32() {
+        let a = u16x8::splat(65535);
+        assert_eq!(sse41::_mm_cvtepu16_epi32(a), i32x4::splat(65535));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu16_epi64() {
+        let a = u16x8::splat(65535);
+        assert_eq!(sse41::_mm_cvtepu16_epi64(a), i64x2::splat(65535));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_cvtepu32_epi64() {
+        let a = u32x4::splat(4294967295);
+        let e = i64x2::splat(4294967295);
+        assert_eq!(sse41::_mm_cvtepu32_epi64(a), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_dp_pd() {
+        let a = f64x2::new(2.0, 3.0);
+        let b = f64x2::new(1.0, 4.0);
+        let r = sse41::_mm_dp_pd(a, b, 0b0011_0001);
+        assert_eq!(r, f64x2::new(14.0, 0.0));
+        let r = sse41::_mm_dp_pd(a, b, 0b0010_0011);
+        assert_eq!(r, f64x2::new(12.0, 12.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_dp_ps() {
+        let a = f32x4::new(2.0, 3.0, 1.0, 10.0);
+        let b = f32x4::new(1.0, 4.0, 0.5, 10.0);
+        let r = sse41::_mm_dp_ps(a, b, 0b0111_0101);
+        assert_eq!(r, f32x4::new(14.5, 0.0, 14.5, 0.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_floor_pd() {
+        let a = f64x2::new(2.5, -4.5);
+        assert_eq!(sse41::_mm_floor_pd(a), f64x2::new(2.0, -5.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_floor_ps() {
+        let a = f32x4::new(2.5, -4.5, 8.7, -0.5);
+        let e = f32x4::new(2.0, -5.0, 8.0, -1.0);
+        assert_eq!(sse41::_mm_floor_ps(a), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_floor_sd() {
+        let a = f64x2::new(2.5, 4.5);
+        let b = f64x2::new(-1.5, -3.5);
+        assert_eq!(sse41::_mm_floor_sd(a, b), f64x2::new(-2.0, 4.5));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_floor_ss() {
+        let a = f32x4::new(2.5, 4.5, 8.5, 16.5);
+        let b = f32x4::new(-1.5, -3.5, -7.5, -15.5);
+        let e = f32x4::new(-2.0, 4.5, 8.5, 16.5);
+        assert_eq!(sse41::_mm_floor_ss(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_ceil_pd() {
+        let a = f64x2::new(1.5, -3.5);
+        assert_eq!(sse41::_mm_ceil_pd(a), f64x2::new(2.0, -3.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_ceil_ps() {
+        let a = f32x4::new(1.5, -3.5, 8.2, -0.5);
+        let e = f32x4::new(2.0, -3.0, 9.0, -0.0);
+        assert_eq!(sse41::_mm_ceil_ps(a), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_ceil_sd() {
+        let a = f64x2::new(1.5, 3.5);
+        let b = f64x2::new(-2.5, -4.5);
+        assert_eq!(sse41::_mm_ceil_sd(a, b), f64x2::new(-2.0, 3.5));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_ceil_ss() {
+        let a = f32x4::new(1.5, 3.5, 7.5, 15.5);
+        let b = f32x4::new(-2.5, -4.5, -8.5, -16.5);
+        let e = f32x4::new(-2.0, 3.5, 7.5, 15.5);
+        assert_eq!(sse41::_mm_ceil_ss(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_round_pd() {
+        let a = f64x2::new(1.25, 2.5);
+        let r = sse41::_mm_round_pd(a, sse41::_MM_FROUND_TO_NEAREST_INT);
+        assert_eq!(r, f64x2::new(1.0, 2.0));
+        let r = sse41::_mm_round_pd(a, sse41::_MM_FROUND_TRUNC);
+        assert_eq!(r, f64x2::new(1.0, 2.0));
+        let r = sse41::_mm_round_pd(a, sse41::_MM_FROUND_CEIL);
+        assert_eq!(r, f64x2::new(2.0, 3.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_round_ps() {
+        let a = f32x4::new(2.25, 4.75, -1.75, -4.5);
+        let r = sse41::_mm_round_ps(a, sse41::_MM_FROUND_TO_ZERO);
+        assert_eq!(r, f32x4::new(2.0, 4.0, -1.0, -4.0));
+        let r = sse41::_mm_round_ps(a, sse41::_MM_FROUND_TO_NEAREST_INT);
+        assert_eq!(r, f32x4::new(2.0, 5.0, -2.0, -4.0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_round_sd() {
+        let a = f64x2::new(1.5, 3.5);
+        let b = f64x2::new(-2.5, -4.5);
+        let r = sse41::_mm_round_sd(a, b, sse41::_MM_FROUND_TO_NEAREST_INT);
+        assert_eq!(r, f64x2::new(-2.0, 3.5));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_round_ss() {
+        let a = f32x4::new(1.5, 3.5, 7.5, 15.5);
+        let b = f32x4::new(-1.75, -4.5, -8.5, -16.5);
+        let r = sse41::_mm_round_ss(a, b, sse41::_MM_FROUND_TO_NEAREST_INT);
+        assert_eq!(r, f32x4::new(-2.0, 3.5, 7.5, 15.5));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_minpos_epu16() {
+        let a = u16x8::new(23, 18, 44, 97, 50, 13, 67, 66);
+        let r = sse41::_mm_minpos_epu16(a);
+        assert_eq!(r, u16x8::new(13, 5, 0, 0, 0, 0, 0, 0));
+
+        let a = u16x8::new(0, 18, 44, 97, 50, 13, 67, 0);
+        let r = sse41::_mm_minpos_epu16(a);
+        assert_eq!(r, u16x8::new(0, 0, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_mul_epi32() {
+        let a = i32x4::new(1, 1, 1, 1);
+        let b = i32x4::new(1, 2, 3, 4);
+        assert_eq!(sse41::_mm_mul_epi32(a, b), i64x2::new(1, 3));
+
+        let a = i32x4::new(15, 2, 1234567, 4);
+        let b = i32x4::new(-20, -256, 666666, 666666);
+        let e = i64x2::new(-300, 823043843622);
+        assert_eq!(sse41::_mm_mul_epi32(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_mullo_epi32() {
+        let a = i32x4::new(1, 1, 1, 1);
+        let b = i32x4::new(1, 2, 3, 4);
+        assert_eq!(sse41::_mm_mullo_epi32(a, b), i32x4::new(1, 2, 3, 4));
+
+        let a = i32x4::new(15, -2, 1234567, 99999);
+        let b = i32x4::new(-20, -256, 666666, -99999);
+        // 1234567 * 666666 = -1589877210 mod 2^32
+        // 99999 * -99999 = -1409865409 mod 2^32
+        let e = i32x4::new(-300, 512, -1589877210, -1409865409);
+        assert_eq!(sse41::_mm_mullo_epi32(a, b), e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_mpsadbw_epu8() {
+        let a = u8x16::new(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+        let b = a;
+
+        let r = sse41::_mm_mpsadbw_epu8(a, b, 0b000);
+        let e = u16x8::new(0, 4, 8, 12, 16, 20, 24, 28);
+        assert_eq!(r, e);
+
+        let r = sse41::_mm_mpsadbw_epu8(a, b, 0b001);
+        let e = u16x8::new(16, 12, 8, 4, 0, 4, 8, 12);
+        assert_eq!(r, e);
+
+        let r = sse41::_mm_mpsadbw_epu8(a, b, 0b100);
+        let e = u16x8::new(16, 20, 24, 28, 32, 36, 40, 44);
+        assert_eq!(r, e);
+
+        let r = sse41::_mm_mpsadbw_epu8(a, b, 0b111);
+        let e = u16x8::new(32, 28, 24, 20, 16, 12, 8, 4);
+        assert_eq!(r, e);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_testz_si128() {
+        let a = __m128i::splat(1);
+        let mask = __m128i::splat(0);
+        assert_eq!(sse41::_mm_testz_si128(a, mask), 1);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b110);
+        assert_eq!(sse41::_mm_testz_si128(a, mask), 0);
+        let a = __m128i::splat(0b011);
+        let mask = __m128i::splat(0b100);
+        assert_eq!(sse41::_mm_testz_si128(a, mask), 1);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_testc_si128() {
+        let a = __m128i::splat(-1);
+        let mask = __m128i::splat(0);
+        assert_eq!(sse41::_mm_testc_si128(a, mask), 1);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b110);
+        assert_eq!(sse41::_mm_testc_si128(a, mask), 0);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b100);
+        assert_eq!(sse41::_mm_testc_si128(a, mask), 1);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_testnzc_si128() {
+        let a = __m128i::splat(0);
+        let mask = __m128i::splat(1);
+        assert_eq!(sse41::_mm_testnzc_si128(a, mask), 0);
+        let a = __m128i::splat(-1);
+        let mask = __m128i::splat(0);
+        assert_eq!(sse41::_mm_testnzc_si128(a, mask), 0);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b110);
+        assert_eq!(sse41::_mm_testnzc_si128(a, mask), 1);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b101);
+        assert_eq!(sse41::_mm_testnzc_si128(a, mask), 0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_test_all_zeros() {
+        let a = __m128i::splat(1);
+        let mask = __m128i::splat(0);
+        assert_eq!(sse41::_mm_test_all_zeros(a, mask), 1);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b110);
+        assert_eq!(sse41::_mm_test_all_zeros(a, mask), 0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_test_all_ones() {
+        let a = __m128i::splat(-1);
+        assert_eq!(sse41::_mm_test_all_ones(a), 1);
+        let a = __m128i::splat(-1).replace(7, 0b0111_1111);
+        assert_eq!(sse41::_mm_test_all_ones(a), 0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_test_mix_ones_zeros() {
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b110);
+        assert_eq!(sse41::_mm_test_mix_ones_zeros(a, mask), 1);
+        let a = __m128i::splat(0b101);
+        let mask = __m128i::splat(0b101);
+        assert_eq!(sse41::_mm_test_mix_ones_zeros(a, mask), 0);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.1"]
+    fn _mm_stream_load_si128() {
+        let a = i64x2::new(5, 6);
+        let r = unsafe {
+            sse41::_mm_stream_load_si128(&a as *const i64x2 as *const _)
+        };
+        assert_eq!(i64x2::from(r), a);
+    }
 }
@@ -1,3 +1,4 @@
+use v128::*;
 use x86::__m128i;
 
 pub const _SIDD_UBYTE_OPS: i8 = 0b00000000;
@@ -17,270 +18,141 @@ pub const _SIDD_MASKED_NEGATIVE_POLARITY: i8 = 0b00110000;
 pub const _SIDD_LEAST_SIGNIFICANT: i8 = 0b00000000;
 pub const _SIDD_MOST_SIGNIFICANT: i8 = 0b01000000;
 
-macro_rules! constify_imm8 {
-    ($imm8:expr, $expand:ident) => {
-        #[allow(overflowing_literals)]
-        match $imm8 & 0b1111_1111 {
-            0 => $expand!(0),
-            1 => $expand!(1),
-            2 => $expand!(2),
-            3 => $expand!(3),
-            4 => $expand!(4),
-            5 => $expand!(5),
-            6 => $expand!(6),
-            7 => $expand!(7),
-            8 => $expand!(8),
-            9 => $expand!(9),
-            10 => $expand!(10),
-            11 => $expand!(11),
-            12 => $expand!(12),
-            13 => $expand!(13),
-            14 => $expand!(14),
-            15 => $expand!(15),
-            16 => $expand!(16),
-            17 => $expand!(17),
-            18 => $expand!(18),
-            19 => $expand!(19),
-            20 => $expand!(20),
-            21 => $expand!(21),
-            22 => $expand!(22),
-            23 => $expand!(23),
-            24 => $expand!(24),
-            25 => $expand!(25),
-            26 => $expand!(26),
-            27 => $expand!(27),
-            28 => $expand!(28),
-            29 => $expand!(29),
-            30 => $expand!(30),
-            31 => $expand!(31),
-            32 => $expand!(32),
-            33 => $expand!(33),
-            34 => $expand!(34),
-            35 => $expand!(35),
-            36 => $expand!(36),
-            37 => $expand!(37),
-            38 => $expand!(38),
-            39 => $expand!(39),
-            40 => $expand!(40),
-            41 => $expand!(41),
-            42 => $expand!(42),
-            43 => $expand!(43),
-            44 => $expand!(44),
-            45 => $expand!(45),
-            46 => $expand!(46),
-            47 => $expand!(47),
-            48 => $expand!(48),
-            49 => $expand!(49),
-            50 => $expand!(50),
-            51 => $expand!(51),
-            52 => $expand!(52),
-            53 => $expand!(53),
-            54 => $expand!(54),
-            55 => $expand!(55),
-            56 => $expand!(56),
-            57 => $expand!(57),
-            58 => $expand!(58),
-            59 => $expand!(59),
-            60 => $expand!(60),
-            61 => $expand!(61),
-            62 => $expand!(62),
-            63 => $expand!(63),
-            64 => $expand!(64),
-            65 => $expand!(65),
-            66 => $expand!(66),
-            67 => $expand!(67),
-            68 => $expand!(68),
-            69 => $expand!(69),
-            70 => $expand!(70),
-            71 => $expand!(71),
-            72 => $expand!(72),
-            73 => $expand!(73),
-            74 => $expand!(74),
-            75 => $expand!(75),
-            76 => $expand!(76),
-            77 => $expand!(77),
-            78 => $expand!(78),
-            79 => $expand!(79),
-            80 => $expand!(80),
-            81 => $expand!(81),
-            82 => $expand!(82),
-            83 => $expand!(83),
-            84 => $expand!(84),
-            85 => $expand!(85),
-            86 => $expand!(86),
-            87 => $expand!(87),
-            88 => $expand!(88),
-            89 => $expand!(89),
-            90 => $expand!(90),
-            91 => $expand!(91),
-            92 => $expand!(92),
-            93 => $expand!(93),
-            94 => $expand!(94),
-            95 => $expand!(95),
-            96 => $expand!(96),
-            97 => $expand!(97),
-            98 => $expand!(98),
-            99 => $expand!(99),
-            100 => $expand!(100),
-            101 => $expand!(101),
-            102 => $expand!(102),
-            103 => $expand!(103),
-            104 => $expand!(104),
-            105 => $expand!(105),
-            106 => $expand!(106),
-            107 => $expand!(107),
-            108 => $expand!(108),
-            109 => $expand!(109),
-            110 => $expand!(110),
-            111 => $expand!(111),
-            112 => $expand!(112),
-            113 => $expand!(113),
-            114 => $expand!(114),
-            115 => $expand!(115),
-            116 => $expand!(116),
-            117 => $expand!(117),
-            118 => $expand!(118),
-            119 => $expand!(119),
-            120 => $expand!(120),
-            121 => $expand!(121),
-            122 => $expand!(122),
-            123 => $expand!(123),
-            124 => $expand!(124),
-            125 => $expand!(125),
-            126 => $expand!(126),
-            127 => $expand!(127),
-            128 => $expand!(128),
-            129 => $expand!(129),
-            130 => $expand!(130),
-            131 => $expand!(131),
-            132 => $expand!(132),
-            133 => $expand!(133),
-            134 => $expand!(134),
-            135 => $expand!(135),
-            136 => $expand!(136),
-            137 => $expand!(137),
-            138 => $expand!(138),
-            139 => $expand!(139),
-            140 => $expand!(140),
-            141 => $expand!(141),
-            142 => $expand!(142),
-            143 => $expand!(143),
-            144 => $expand!(144),
-            145 => $expand!(145),
-            146 => $expand!(146),
-            147 => $expand!(147),
-            148 => $expand!(148),
-            149 => $expand!(149),
-            150 => $expand!(150),
-            151 => $expand!(151),
-            152 => $expand!(152),
-            153 => $expand!(153),
-            154 => $expand!(154),
-            155 => $expand!(155),
-            156 => $expand!(156),
-            157 => $expand!(157),
-            158 => $expand!(158),
-            159 => $expand!(159),
-            160 => $expand!(160),
-            161 => $expand!(161),
-            162 => $expand!(162),
-            163 => $expand!(163),
-            164 => $expand!(164),
-            165 => $expand!(165),
-            166 => $expand!(166),
-            167 => $expand!(167),
-            168 => $expand!(168),
-            169 => $expand!(169),
-            170 => $expand!(170),
-            171 => $expand!(171),
-            172 => $expand!(172),
-            173 => $expand!(173),
-            174 => $expand!(174),
-            175 => $expand!(175),
-            176 => $expand!(176),
-            177 => $expand!(177),
-            178 => $expand!(178),
-            179 => $expand!(179),
-            180 => $expand!(180),
-            181 => $expand!(181),
-            182 => $expand!(182),
-            183 => $expand!(183),
-            184 => $expand!(184),
-            185 => $expand!(185),
-            186 => $expand!(186),
-            187 => $expand!(187),
-            188 => $expand!(188),
-            189 => $expand!(189),
-            190 => $expand!(190),
-            191 => $expand!(191),
-            192 => $expand!(192),
-            193 => $expand!(193),
-            194 => $expand!(194),
-            195 => $expand!(195),
-            196 => $expand!(196),
-            197 => $expand!(197),
-            198 => $expand!(198),
-            199 => $expand!(199),
-            200 => $expand!(200),
-            201 => $expand!(201),
-            202 => $expand!(202),
-            203 => $expand!(203),
-            204 => $expand!(204),
-            205 => $expand!(205),
-            206 => $expand!(206),
-            207 => $expand!(207),
-            208 => $expand!(208),
-            209 => $expand!(209),
-            210 => $expand!(210),
-            211 => $expand!(211),
-            212 => $expand!(212),
-            213 => $expand!(213),
-            214 => $expand!(214),
-            215 => $expand!(215),
-            216 => $expand!(216),
-            217 => $expand!(217),
-            218 => $expand!(218),
-            219 => $expand!(219),
-            220 => $expand!(220),
-            221 => $expand!(221),
-            222 => $expand!(222),
-            223 => $expand!(223),
-            224 => $expand!(224),
-            225 => $expand!(225),
-            226 => $expand!(226),
-            227 => $expand!(227),
-            228 => $expand!(228),
-            229 => $expand!(229),
-            230 => $expand!(230),
-            231 => $expand!(231),
-            232 => $expand!(232),
-            233 => $expand!(233),
-            234 => $expand!(234),
-            235 => $expand!(235),
-            236 => $expand!(236),
-            237 => $expand!(237),
-            238 => $expand!(238),
-            239 => $expand!(239),
-            240 => $expand!(240),
-            241 => $expand!(241),
-            242 => $expand!(242),
-            243 => $expand!(243),
-            244 => $expand!(244),
-            245 => $expand!(245),
-            246 => $expand!(246),
-            247 => $expand!(247),
-            248 => $expand!(248),
-            249 => $expand!(249),
-            250 => $expand!(250),
-            251 => $expand!(251),
-            252 => $expand!(252),
-            253 => $expand!(253),
-            254 => $expand!(254),
-            _ => $expand!(255),
+pub const _SIDD_BIT_MASK: i8 = 0b00000000;
+pub const _SIDD_UNIT_MASK: i8 = 0b01000000;
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return the generated mask.
+///
+/// The strings end at their first null element, or after all 16 bytes (8
+/// words). With `_SIDD_BIT_MASK` the mask has one bit per element in the
+/// low bits of the result; with `_SIDD_UNIT_MASK` each element of the
+/// result is all ones or all zeros.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistrm(a: __m128i, b: __m128i, imm8: i8) -> __m128i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistrm128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return the generated index.
+///
+/// With `_SIDD_LEAST_SIGNIFICANT` the index of the first match is
+/// returned, with `_SIDD_MOST_SIGNIFICANT` the index of the last one. If
+/// there is no match, the number of elements in a vector (16 bytes or 8
+/// words) is returned.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistri(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistri128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return `1` if any element in `b` was null, and
+/// `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistrz(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistriz128(a, b, $imm8) }
         }
     }
+    constify_imm8!(imm8, call)
 }
 
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return `1` if the resulting mask was non-zero,
+/// and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistrc(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistric128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return `1` if any element in `a` was null, and
+/// `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistrs(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistris128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return bit `0` of the resulting bit mask.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistro(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistrio128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings with implicit lengths in `a` and `b` using the
+/// control in `imm8`, and return `1` if `b` did not contain a null
+/// element and the resulting mask was zero, and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpistra(a: __m128i, b: __m128i, imm8: i8) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpistria128(a, b, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return the generated mask.
+///
+/// The lengths count elements, bytes or words depending on `imm8`, and
+/// are saturated to the number of elements in a vector. See
+/// `_mm_cmpistrm` for the format of the mask.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestrm(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> __m128i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestrm128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return the generated index.
+///
+/// See `_mm_cmpistri` for how the index is chosen.
 #[inline(always)]
 #[target_feature = "+sse4.2"]
 pub fn _mm_cmpestri(
@@ -298,10 +170,196 @@ pub fn _mm_cmpestri(
     constify_imm8!(imm8, call)
 }
 
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return `1` if `b` is shorter than a full
+/// vector, and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestrz(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestriz128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return `1` if the resulting mask was
+/// non-zero, and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestrc(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestric128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return `1` if `a` is shorter than a full
+/// vector, and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestrs(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestris128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return bit `0` of the resulting bit mask.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestro(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestrio128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Compare packed strings in `a` and `b` with lengths `la` and `lb` using
+/// the control in `imm8`, and return `1` if `b` is a full vector and the
+/// resulting mask was zero, and `0` otherwise.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpestra(
+    a: __m128i,
+    la: i32,
+    b: __m128i,
+    lb: i32,
+    imm8: i8,
+) -> i32 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            unsafe { pcmpestria128(a, la, b, lb, $imm8) }
+        }
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Starting with the initial value in `crc`, return the accumulated
+/// CRC32-C value for the unsigned 8-bit integer `v`.
+///
+/// This is the CRC with the Castagnoli polynomial `0x1EDC6F41`, without
+/// the initial and final inversions of the checksum.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_crc32_u8(crc: u32, v: u8) -> u32 {
+    unsafe { crc32_32_8(crc, v) }
+}
+
+/// Starting with the initial value in `crc`, return the accumulated
+/// CRC32-C value for the unsigned 16-bit integer `v`.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_crc32_u16(crc: u32, v: u16) -> u32 {
+    unsafe { crc32_32_16(crc, v) }
+}
+
+/// Starting with the initial value in `crc`, return the accumulated
+/// CRC32-C value for the unsigned 32-bit integer `v`.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_crc32_u32(crc: u32, v: u32) -> u32 {
+    unsafe { crc32_32_32(crc, v) }
+}
+
+/// Starting with the initial value in `crc`, return the accumulated
+/// CRC32-C value for the unsigned 64-bit integer `v`.
+///
+/// The bytes of `v` are processed in little-endian order, as if they were
+/// read from memory.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_crc32_u64(crc: u64, v: u64) -> u64 {
+    unsafe { crc32_64_64(crc, v) }
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for greater-than.
+#[inline(always)]
+#[target_feature = "+sse4.2"]
+pub fn _mm_cmpgt_epi64(a: i64x2, b: i64x2) -> i64x2 {
+    a.gt(b).into()
+}
+
 #[allow(improper_ctypes)]
 extern {
+    #[link_name = "llvm.x86.sse42.pcmpestrm128"]
+    fn pcmpestrm128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> __m128i;
     #[link_name = "llvm.x86.sse42.pcmpestri128"]
     fn pcmpestri128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpestriz128"]
+    fn pcmpestriz128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpestric128"]
+    fn pcmpestric128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpestris128"]
+    fn pcmpestris128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpestrio128"]
+    fn pcmpestrio128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpestria128"]
+    fn pcmpestria128(a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8)
+        -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistrm128"]
+    fn pcmpistrm128(a: __m128i, b: __m128i, imm8: i8) -> __m128i;
+    #[link_name = "llvm.x86.sse42.pcmpistri128"]
+    fn pcmpistri128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistriz128"]
+    fn pcmpistriz128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistric128"]
+    fn pcmpistric128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistris128"]
+    fn pcmpistris128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistrio128"]
+    fn pcmpistrio128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.pcmpistria128"]
+    fn pcmpistria128(a: __m128i, b: __m128i, imm8: i8) -> i32;
+    #[link_name = "llvm.x86.sse42.crc32.32.8"]
+    fn crc32_32_8(crc: u32, v: u8) -> u32;
+    #[link_name = "llvm.x86.sse42.crc32.32.16"]
+    fn crc32_32_16(crc: u32, v: u16) -> u32;
+    #[link_name = "llvm.x86.sse42.crc32.32.32"]
+    fn crc32_32_32(crc: u32, v: u32) -> u32;
+    #[cfg(target_arch = "x86_64")]
+    #[link_name = "llvm.x86.sse42.crc32.64.64"]
+    fn crc32_64_64(crc: u64, v: u64) -> u64;
 }
 
 #[cfg(test)]
@@ -309,6 +367,108 @@ mod tests {
     use v128::*;
     use x86::{__m128i, sse42};
 
+    /// Loads the first 16 bytes of `s` into a vector, padding with nulls.
+    fn str_to_m128i(s: &[u8]) -> __m128i {
+        assert!(s.len() <= 16);
+        let mut bytes = [0u8; 16];
+        bytes[..s.len()].copy_from_slice(s);
+        __m128i::from(u8x16::load(&bytes, 0))
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistrm() {
+        let a = str_to_m128i(b"Hello! Good-Bye!");
+        let b = str_to_m128i(b"hello! good-bye!");
+        let r = sse42::_mm_cmpistrm(a, b, sse42::_SIDD_UNIT_MASK);
+        let e = i8x16::new(
+            0, -1, -1, -1, -1, -1, -1, 0, -1, -1, -1, -1, 0, -1, -1, -1);
+        assert_eq!(r, e);
+
+        let a = str_to_m128i(b"azAZ");
+        let b = str_to_m128i(b"Hello World! 123");
+        let r = sse42::_mm_cmpistrm(a, b, sse42::_SIDD_CMP_RANGES);
+        assert_eq!(i16x8::from(r), i16x8::new(0x07DF, 0, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistri() {
+        let a = str_to_m128i(b"Bye");
+        let b = str_to_m128i(b"Hello! Good-Bye!");
+        let i = sse42::_mm_cmpistri(a, b, sse42::_SIDD_CMP_EQUAL_ORDERED);
+        assert_eq!(12, i);
+
+        let a = str_to_m128i(b"l");
+        let i = sse42::_mm_cmpistri(a, b, sse42::_SIDD_MOST_SIGNIFICANT);
+        assert_eq!(3, i);
+
+        let a = str_to_m128i(b"z");
+        let i = sse42::_mm_cmpistri(a, b, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(16, i);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistrz() {
+        let a = str_to_m128i(b"Hello");
+        let b = str_to_m128i(b"Hello, World");
+        assert_eq!(1, sse42::_mm_cmpistrz(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        let b = str_to_m128i(b"Hello! Good-Bye!");
+        assert_eq!(0, sse42::_mm_cmpistrz(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistrc() {
+        let a = str_to_m128i(b"a");
+        let b = str_to_m128i(b"abc");
+        assert_eq!(1, sse42::_mm_cmpistrc(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        let b = str_to_m128i(b"xyz");
+        assert_eq!(0, sse42::_mm_cmpistrc(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistrs() {
+        let a = str_to_m128i(b"Hello");
+        let b = str_to_m128i(b"Hello! Good-Bye!");
+        assert_eq!(1, sse42::_mm_cmpistrs(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        assert_eq!(0, sse42::_mm_cmpistrs(b, a, sse42::_SIDD_CMP_EQUAL_ANY));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistro() {
+        let a = str_to_m128i(b"a");
+        let b = str_to_m128i(b"abc");
+        assert_eq!(1, sse42::_mm_cmpistro(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        let b = str_to_m128i(b"bac");
+        assert_eq!(0, sse42::_mm_cmpistro(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpistra() {
+        let a = str_to_m128i(b"a");
+        let b = str_to_m128i(b"xxxxxxxxxxxxxxxx");
+        assert_eq!(1, sse42::_mm_cmpistra(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        let b = str_to_m128i(b"xxxxxxxxxxxxxxxa");
+        assert_eq!(0, sse42::_mm_cmpistra(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+        let b = str_to_m128i(b"xxx");
+        assert_eq!(0, sse42::_mm_cmpistra(a, b, sse42::_SIDD_CMP_EQUAL_ANY));
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestrm() {
+        let a = str_to_m128i(b"abcdefgh");
+        let b = str_to_m128i(b"bxyaaaaaaaaaaaaa");
+        let r = sse42::_mm_cmpestrm(a, 2, b, 4, sse42::_SIDD_UNIT_MASK);
+        let e = i8x16::new(-1, 0, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(r, e);
+    }
+
     #[test]
     #[target_feature = "+sse4.2"]
     fn _mm_cmpestri() {
@@ -320,4 +480,114 @@ mod tests {
             va, 3, vb, 6, sse42::_SIDD_CMP_EQUAL_ORDERED);
         assert_eq!(3, i);
     }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestrz() {
+        let a = str_to_m128i(b"foo");
+        let b = str_to_m128i(b"foobar");
+        let r = sse42::_mm_cmpestrz(a, 3, b, 6, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(1, r);
+        let r = sse42::_mm_cmpestrz(a, 3, b, 16, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(0, r);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestrc() {
+        let a = str_to_m128i(b"foo");
+        let b = str_to_m128i(b"barfoo");
+        let r = sse42::_mm_cmpestrc(
+            a, 3, b, 6, sse42::_SIDD_CMP_EQUAL_ORDERED);
+        assert_eq!(1, r);
+        let r = sse42::_mm_cmpestrc(
+            a, 3, b, 3, sse42::_SIDD_CMP_EQUAL_ORDERED);
+        assert_eq!(0, r);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestrs() {
+        let a = str_to_m128i(b"foo");
+        let b = str_to_m128i(b"foobar");
+        let r = sse42::_mm_cmpestrs(a, 3, b, 6, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(1, r);
+        let r = sse42::_mm_cmpestrs(a, 16, b, 6, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(0, r);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestro() {
+        let a = str_to_m128i(b"a");
+        let b = str_to_m128i(b"abc");
+        let r = sse42::_mm_cmpestro(a, 1, b, 3, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(1, r);
+        let b = str_to_m128i(b"bac");
+        let r = sse42::_mm_cmpestro(a, 1, b, 3, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(0, r);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpestra() {
+        let a = str_to_m128i(b"a");
+        let b = str_to_m128i(b"xxxxxxxxxxxxxxxa");
+        let r = sse42::_mm_cmpestra(a, 1, b, 16, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(0, r);
+        let r = sse42::_mm_cmpestra(a, 1, b, 15, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(0, r);
+        let b = str_to_m128i(b"xxxxxxxxxxxxxxxx");
+        let r = sse42::_mm_cmpestra(a, 1, b, 16, sse42::_SIDD_CMP_EQUAL_ANY);
+        assert_eq!(1, r);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_crc32_u8() {
+        let mut crc = !0;
+        for &v in b"123456789" {
+            crc = sse42::_mm_crc32_u8(crc, v);
+        }
+        assert_eq!(!crc, 0xE3069283);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_crc32_u16() {
+        let mut crc = !0;
+        for &v in &[0x3231u16, 0x3433, 0x3635, 0x3837] {
+            crc = sse42::_mm_crc32_u16(crc, v);
+        }
+        assert_eq!(crc, 0x9F787F65);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_crc32_u32() {
+        let mut crc = !0;
+        for &v in &[0x34333231u32, 0x38373635] {
+            crc = sse42::_mm_crc32_u32(crc, v);
+        }
+        assert_eq!(crc, 0x9F787F65);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature = "+sse4.2"]
+    fn _mm_crc32_u64() {
+        let crc = sse42::_mm_crc32_u64(!0, 0x3837363534333231);
+        assert_eq!(crc, 0x9F787F65);
+        let crc = sse42::_mm_crc32_u64(0, 0x0807060504030201);
+        assert_eq!(crc, 0xCAA1AD0B);
+    }
+
+    #[test]
+    #[target_feature = "+sse4.2"]
+    fn _mm_cmpgt_epi64() {
+        let a = i64x2::new(5, -5);
+        let b = i64x2::new(-5, 5);
+        assert_eq!(sse42::_mm_cmpgt_epi64(a, b), i64x2::new(-1, 0));
+        assert_eq!(sse42::_mm_cmpgt_epi64(a, a), i64x2::new(0, 0));
+    }
 }
@@ -0,0 +1,1202 @@
+//! Zero-sized tokens proving that a CPU feature is available.
+//!
+//! The intrinsics in this module's siblings are declared as safe functions,
+//! but calling them on a CPU without the corresponding feature is undefined
+//! behaviour. A token can only be obtained by detecting the feature at run
+//! time or through an `unsafe` constructor, so holding one makes it safe to
+//! call the intrinsics it wraps.
+//!
+//! Each token has a method for every intrinsic of its feature, named after
+//! the intrinsic without its `_mm_` or `_mm256_` prefix: `avx2.add_epi8(a, b)`
+//! calls `_mm256_add_epi8(a, b)`. Where both a 128-bit and a 256-bit version
+//! exist, the 128-bit one keeps an `mm_` prefix, e.g. `avx2.mm_blend_epi32`.
+//!
+//! Tokens are `Copy`, so detection only needs to happen once, outside of any
+//! hot loop.
+
+use std::os::raw::c_void;
+
+use v128::*;
+use v256::*;
+use x86::{__m128, __m128i, __m256, __m256d, __m256i};
+use x86::cpuid::cpu_features;
+
+macro_rules! define_token {
+    ($(#[$doc:meta])* $name:ident, $detect:ident) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name(());
+
+        impl $name {
+            /// Returns a token if the running CPU supports the feature.
+            #[inline]
+            pub fn detect() -> Option<$name> {
+                if cpu_features().$detect() {
+                    Some($name(()))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns a token without checking that the running CPU
+            /// supports the feature.
+            ///
+            /// Calling any method of the token on a CPU without the
+            /// feature is undefined behaviour.
+            #[inline]
+            pub unsafe fn new_unchecked() -> $name {
+                $name(())
+            }
+        }
+    }
+}
+
+macro_rules! token_methods {
+    (
+        $token:ident, $feature:expr;
+        $(fn $name:ident($($arg:ident: $argty:ty),* $(,)*) -> $ret:ty
+          = $intrinsic:ident;)*
+        $(unsafe fn $uname:ident($($uarg:ident: $uargty:ty),* $(,)*)
+          -> $uret:ty = $uintrinsic:ident;)*
+    ) => {
+        impl $token {
+            $(
+                #[inline(always)]
+                #[target_feature = $feature]
+                pub fn $name(self, $($arg: $argty),*) -> $ret {
+                    ::x86::$intrinsic($($arg),*)
+                }
+            )*
+            $(
+                #[inline(always)]
+                #[target_feature = $feature]
+                pub unsafe fn $uname(self, $($uarg: $uargty),*) -> $uret {
+                    ::x86::$uintrinsic($($uarg),*)
+                }
+            )*
+        }
+    }
+}
+
+define_token! {
+    /// Proof that the running CPU supports SSE2.
+    Sse2, sse2
+}
+define_token! {
+    /// Proof that the running CPU supports SSE3.
+    Sse3, sse3
+}
+define_token! {
+    /// Proof that the running CPU supports SSSE3.
+    Ssse3, ssse3
+}
+define_token! {
+    /// Proof that the running CPU supports SSE4.1.
+    Sse41, sse41
+}
+define_token! {
+    /// Proof that the running CPU supports SSE4.2.
+    Sse42, sse42
+}
+define_token! {
+    /// Proof that the running CPU supports AVX.
+    Avx, avx
+}
+define_token! {
+    /// Proof that the running CPU supports AVX2.
+    Avx2, avx2
+}
+define_token! {
+    /// Proof that the running CPU supports FMA3.
+    Fma, fma
+}
+define_token! {
+    /// Proof that the running CPU supports BMI1.
+    Bmi1, bmi1
+}
+define_token! {
+    /// Proof that the running CPU supports BMI2.
+    Bmi2, bmi2
+}
+define_token! {
+    /// Proof that the running CPU supports LZCNT.
+    Lzcnt, lzcnt
+}
+define_token! {
+    /// Proof that the running CPU supports POPCNT.
+    Popcnt, popcnt
+}
+define_token! {
+    /// Proof that the running CPU supports AES-NI.
+    Aes, aes
+}
+define_token! {
+    /// Proof that the running CPU supports PCLMULQDQ.
+    Pclmulqdq, pclmulqdq
+}
+
+// Wrappers, generated from the signatures in the sibling modules.
+
+token_methods! {
+    Sse2, "+sse2";
+    fn pause() -> () = _mm_pause;
+    fn lfence() -> () = _mm_lfence;
+    fn mfence() -> () = _mm_mfence;
+    fn add_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_add_epi8;
+    fn add_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_add_epi16;
+    fn add_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_add_epi32;
+    fn add_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_add_epi64;
+    fn adds_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_adds_epi8;
+    fn adds_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_adds_epi16;
+    fn adds_epu8(a: u8x16, b: u8x16) -> u8x16 = _mm_adds_epu8;
+    fn adds_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_adds_epu16;
+    fn avg_epu8(a: u8x16, b: u8x16) -> u8x16 = _mm_avg_epu8;
+    fn avg_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_avg_epu16;
+    fn madd_epi16(a: i16x8, b: i16x8) -> i32x4 = _mm_madd_epi16;
+    fn max_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_max_epi16;
+    fn max_epu8(a: u8x16, b: u8x16) -> u8x16 = _mm_max_epu8;
+    fn min_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_min_epi16;
+    fn min_epu8(a: u8x16, b: u8x16) -> u8x16 = _mm_min_epu8;
+    fn mulhi_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_mulhi_epi16;
+    fn mulhi_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_mulhi_epu16;
+    fn mullo_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_mullo_epi16;
+    fn mul_epu32(a: u32x4, b: u32x4) -> u64x2 = _mm_mul_epu32;
+    fn sad_epu8(a: u8x16, b: u8x16) -> u64x2 = _mm_sad_epu8;
+    fn sub_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_sub_epi8;
+    fn sub_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_sub_epi16;
+    fn sub_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_sub_epi32;
+    fn sub_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_sub_epi64;
+    fn subs_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_subs_epi8;
+    fn subs_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_subs_epi16;
+    fn subs_epu8(a: u8x16, b: u8x16) -> u8x16 = _mm_subs_epu8;
+    fn subs_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_subs_epu16;
+    fn slli_si128(a: __m128i, imm8: i32) -> __m128i = _mm_slli_si128;
+    fn bslli_si128(a: __m128i, imm8: i32) -> __m128i = _mm_bslli_si128;
+    fn bsrli_si128(a: __m128i, imm8: i32) -> __m128i = _mm_bsrli_si128;
+    fn slli_epi16(a: i16x8, imm8: i32) -> i16x8 = _mm_slli_epi16;
+    fn sll_epi16(a: i16x8, count: i16x8) -> i16x8 = _mm_sll_epi16;
+    fn slli_epi32(a: i32x4, imm8: i32) -> i32x4 = _mm_slli_epi32;
+    fn sll_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_sll_epi32;
+    fn slli_epi64(a: i64x2, imm8: i32) -> i64x2 = _mm_slli_epi64;
+    fn sll_epi64(a: i64x2, count: i64x2) -> i64x2 = _mm_sll_epi64;
+    fn srai_epi16(a: i16x8, imm8: i32) -> i16x8 = _mm_srai_epi16;
+    fn sra_epi16(a: i16x8, count: i16x8) -> i16x8 = _mm_sra_epi16;
+    fn srai_epi32(a: i32x4, imm8: i32) -> i32x4 = _mm_srai_epi32;
+    fn sra_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_sra_epi32;
+    fn srli_si128(a: __m128i, imm8: i32) -> __m128i = _mm_srli_si128;
+    fn srli_epi16(a: i16x8, imm8: i32) -> i16x8 = _mm_srli_epi16;
+    fn srl_epi16(a: i16x8, count: i16x8) -> i16x8 = _mm_srl_epi16;
+    fn srli_epi32(a: i32x4, imm8: i32) -> i32x4 = _mm_srli_epi32;
+    fn srl_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_srl_epi32;
+    fn srli_epi64(a: i64x2, imm8: i32) -> i64x2 = _mm_srli_epi64;
+    fn srl_epi64(a: i64x2, count: i64x2) -> i64x2 = _mm_srl_epi64;
+    fn and_si128(a: __m128i, b: __m128i) -> __m128i = _mm_and_si128;
+    fn andnot_si128(a: __m128i, b: __m128i) -> __m128i = _mm_andnot_si128;
+    fn or_si128(a: __m128i, b: __m128i) -> __m128i = _mm_or_si128;
+    fn xor_si128(a: __m128i, b: __m128i) -> __m128i = _mm_xor_si128;
+    fn cmpeq_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_cmpeq_epi8;
+    fn cmpeq_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_cmpeq_epi16;
+    fn cmpeq_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_cmpeq_epi32;
+    fn cmpgt_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_cmpgt_epi8;
+    fn cmpgt_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_cmpgt_epi16;
+    fn cmpgt_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_cmpgt_epi32;
+    fn cmplt_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_cmplt_epi8;
+    fn cmplt_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_cmplt_epi16;
+    fn cmplt_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_cmplt_epi32;
+    fn cvtepi32_pd(a: i32x4) -> f64x2 = _mm_cvtepi32_pd;
+    fn cvtsi32_sd(a: f64x2, b: i32) -> f64x2 = _mm_cvtsi32_sd;
+    fn cvtsi64_sd(a: f64x2, b: i64) -> f64x2 = _mm_cvtsi64_sd;
+    fn cvtsi64x_sd(a: f64x2, b: i64) -> f64x2 = _mm_cvtsi64x_sd;
+    fn cvtepi32_ps(a: i32x4) -> f32x4 = _mm_cvtepi32_ps;
+    fn cvtsi32_si128(a: i32) -> i32x4 = _mm_cvtsi32_si128;
+    fn cvtsi64_si128(a: i64) -> i64x2 = _mm_cvtsi64_si128;
+    fn cvtsi64x_si128(a: i64) -> i64x2 = _mm_cvtsi64x_si128;
+    fn cvtsi128_si32(a: i32x4) -> i32 = _mm_cvtsi128_si32;
+    fn cvtsi128_si64(a: i64x2) -> i64 = _mm_cvtsi128_si64;
+    fn cvtsi128_si64x(a: i64x2) -> i64 = _mm_cvtsi128_si64x;
+    fn set_epi64x(e1: i64, e0: i64) -> i64x2 = _mm_set_epi64x;
+    fn set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> i32x4 = _mm_set_epi32;
+    fn set_epi16(
+        e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16,
+    ) -> i16x8 = _mm_set_epi16;
+    fn set_epi8(
+        e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+        e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+    ) -> i8x16 = _mm_set_epi8;
+    fn set1_epi64x(a: i64) -> i64x2 = _mm_set1_epi64x;
+    fn set1_epi32(a: i32) -> i32x4 = _mm_set1_epi32;
+    fn set1_epi16(a: i16) -> i16x8 = _mm_set1_epi16;
+    fn set1_epi8(a: i8) -> i8x16 = _mm_set1_epi8;
+    fn setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> i32x4 = _mm_setr_epi32;
+    fn setr_epi16(
+        e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16,
+    ) -> i16x8 = _mm_setr_epi16;
+    fn setr_epi8(
+        e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+        e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+    ) -> i8x16 = _mm_setr_epi8;
+    fn setzero_si128() -> __m128i = _mm_setzero_si128;
+    fn move_epi64(a: i64x2) -> i64x2 = _mm_move_epi64;
+    fn packs_epi16(a: i16x8, b: i16x8) -> i8x16 = _mm_packs_epi16;
+    fn packs_epi32(a: i32x4, b: i32x4) -> i16x8 = _mm_packs_epi32;
+    fn packus_epi16(a: i16x8, b: i16x8) -> u8x16 = _mm_packus_epi16;
+    fn extract_epi16(a: i16x8, imm8: i32) -> i32 = _mm_extract_epi16;
+    fn insert_epi16(a: i16x8, i: i32, imm8: i32) -> i16x8 = _mm_insert_epi16;
+    fn movemask_epi8(a: i8x16) -> i32 = _mm_movemask_epi8;
+    fn shuffle_epi32(a: i32x4, imm8: i32) -> i32x4 = _mm_shuffle_epi32;
+    fn shufflehi_epi16(a: i16x8, imm8: i32) -> i16x8 = _mm_shufflehi_epi16;
+    fn shufflelo_epi16(a: i16x8, imm8: i32) -> i16x8 = _mm_shufflelo_epi16;
+    fn unpackhi_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_unpackhi_epi8;
+    fn unpackhi_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_unpackhi_epi16;
+    fn unpackhi_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_unpackhi_epi32;
+    fn unpackhi_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_unpackhi_epi64;
+    fn unpacklo_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_unpacklo_epi8;
+    fn unpacklo_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_unpacklo_epi16;
+    fn unpacklo_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_unpacklo_epi32;
+    fn unpacklo_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_unpacklo_epi64;
+    fn add_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_add_sd;
+    fn add_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_add_pd;
+    fn div_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_div_sd;
+    fn div_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_div_pd;
+    fn max_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_max_sd;
+    fn max_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_max_pd;
+    fn min_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_min_sd;
+    fn min_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_min_pd;
+    fn mul_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_mul_sd;
+    fn mul_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_mul_pd;
+    fn sqrt_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_sqrt_sd;
+    fn sqrt_pd(a: f64x2) -> f64x2 = _mm_sqrt_pd;
+    fn sub_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_sub_sd;
+    fn sub_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_sub_pd;
+    fn and_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_and_pd;
+    fn andnot_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_andnot_pd;
+    fn or_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_or_pd;
+    fn xor_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_xor_pd;
+    fn cmpeq_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpeq_sd;
+    fn cmplt_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmplt_sd;
+    fn cmple_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmple_sd;
+    fn cmpgt_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpgt_sd;
+    fn cmpge_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpge_sd;
+    fn cmpord_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpord_sd;
+    fn cmpunord_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpunord_sd;
+    fn cmpneq_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpneq_sd;
+    fn cmpnlt_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnlt_sd;
+    fn cmpnle_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnle_sd;
+    fn cmpngt_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpngt_sd;
+    fn cmpnge_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnge_sd;
+    fn cmpeq_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpeq_pd;
+    fn cmplt_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmplt_pd;
+    fn cmple_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmple_pd;
+    fn cmpgt_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpgt_pd;
+    fn cmpge_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpge_pd;
+    fn cmpord_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpord_pd;
+    fn cmpunord_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpunord_pd;
+    fn cmpneq_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpneq_pd;
+    fn cmpnlt_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnlt_pd;
+    fn cmpnle_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnle_pd;
+    fn cmpngt_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpngt_pd;
+    fn cmpnge_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_cmpnge_pd;
+    fn comieq_sd(a: f64x2, b: f64x2) -> bool = _mm_comieq_sd;
+    fn comilt_sd(a: f64x2, b: f64x2) -> bool = _mm_comilt_sd;
+    fn comile_sd(a: f64x2, b: f64x2) -> bool = _mm_comile_sd;
+    fn comigt_sd(a: f64x2, b: f64x2) -> bool = _mm_comigt_sd;
+    fn comige_sd(a: f64x2, b: f64x2) -> bool = _mm_comige_sd;
+    fn comineq_sd(a: f64x2, b: f64x2) -> bool = _mm_comineq_sd;
+    fn ucomieq_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomieq_sd;
+    fn ucomilt_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomilt_sd;
+    fn ucomile_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomile_sd;
+    fn ucomigt_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomigt_sd;
+    fn ucomige_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomige_sd;
+    fn ucomineq_sd(a: f64x2, b: f64x2) -> bool = _mm_ucomineq_sd;
+    fn movemask_pd(a: f64x2) -> i32 = _mm_movemask_pd;
+    unsafe fn clflush(p: *mut c_void) -> () = _mm_clflush;
+    unsafe fn loadl_epi64(mem_addr: *const i64x2) -> i64x2 = _mm_loadl_epi64;
+    unsafe fn load_si128(mem_addr: *const __m128i) -> __m128i = _mm_load_si128;
+    unsafe fn loadu_si128(
+        mem_addr: *const __m128i,
+    ) -> __m128i = _mm_loadu_si128;
+    unsafe fn maskmoveu_si128(
+        a: i8x16, mask: i8x16, mem_addr: *mut i8,
+    ) -> () = _mm_maskmoveu_si128;
+    unsafe fn store_si128(
+        mem_addr: *mut __m128i, a: __m128i,
+    ) -> () = _mm_store_si128;
+    unsafe fn storeu_si128(
+        mem_addr: *mut __m128i, a: __m128i,
+    ) -> () = _mm_storeu_si128;
+    unsafe fn storel_epi64(
+        mem_addr: *mut __m128i, a: __m128i,
+    ) -> () = _mm_storel_epi64;
+    unsafe fn load_pd(mem_addr: *const f64) -> f64x2 = _mm_load_pd;
+    unsafe fn store_pd(mem_addr: *mut f64, a: f64x2) -> () = _mm_store_pd;
+}
+
+token_methods! {
+    Sse3, "+sse3";
+    fn addsub_ps(a: __m128, b: __m128) -> __m128 = _mm_addsub_ps;
+    fn addsub_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_addsub_pd;
+    fn hadd_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_hadd_pd;
+    fn hadd_ps(a: __m128, b: __m128) -> __m128 = _mm_hadd_ps;
+    fn hsub_pd(a: f64x2, b: f64x2) -> f64x2 = _mm_hsub_pd;
+    fn hsub_ps(a: __m128, b: __m128) -> __m128 = _mm_hsub_ps;
+    fn movedup_pd(a: f64x2) -> f64x2 = _mm_movedup_pd;
+    fn movehdup_ps(a: __m128) -> __m128 = _mm_movehdup_ps;
+    fn moveldup_ps(a: __m128) -> __m128 = _mm_moveldup_ps;
+    unsafe fn lddqu_si128(
+        mem_addr: *const __m128i,
+    ) -> __m128i = _mm_lddqu_si128;
+    unsafe fn loaddup_pd(mem_addr: *const f64) -> f64x2 = _mm_loaddup_pd;
+}
+
+token_methods! {
+    Ssse3, "+ssse3";
+    fn abs_epi8(a: i8x16) -> u8x16 = _mm_abs_epi8;
+    fn abs_epi16(a: i16x8) -> u16x8 = _mm_abs_epi16;
+    fn abs_epi32(a: i32x4) -> u32x4 = _mm_abs_epi32;
+    fn shuffle_epi8(a: u8x16, b: u8x16) -> u8x16 = _mm_shuffle_epi8;
+    fn alignr_epi8(a: i8x16, b: i8x16, n: i32) -> i8x16 = _mm_alignr_epi8;
+    fn hadd_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_hadd_epi16;
+    fn hadds_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_hadds_epi16;
+    fn hadd_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_hadd_epi32;
+    fn hsub_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_hsub_epi16;
+    fn hsubs_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_hsubs_epi16;
+    fn hsub_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_hsub_epi32;
+    fn maddubs_epi16(a: u8x16, b: i8x16) -> i16x8 = _mm_maddubs_epi16;
+    fn mulhrs_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_mulhrs_epi16;
+    fn sign_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_sign_epi8;
+    fn sign_epi16(a: i16x8, b: i16x8) -> i16x8 = _mm_sign_epi16;
+    fn sign_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_sign_epi32;
+}
+
+token_methods! {
+    Sse41, "+sse4.1";
+    fn blendv_epi8(
+        a: __m128i, b: __m128i, mask: __m128i,
+    ) -> __m128i = _mm_blendv_epi8;
+    fn blend_epi16(a: i16x8, b: i16x8, imm8: i32) -> i16x8 = _mm_blend_epi16;
+    fn blendv_pd(a: f64x2, b: f64x2, mask: f64x2) -> f64x2 = _mm_blendv_pd;
+    fn blendv_ps(a: __m128, b: __m128, mask: __m128) -> __m128 = _mm_blendv_ps;
+    fn blend_pd(a: f64x2, b: f64x2, imm2: i32) -> f64x2 = _mm_blend_pd;
+    fn blend_ps(a: __m128, b: __m128, imm4: i32) -> __m128 = _mm_blend_ps;
+    fn extract_ps(a: __m128, imm8: i32) -> i32 = _mm_extract_ps;
+    fn extract_epi8(a: i8x16, imm8: i32) -> i32 = _mm_extract_epi8;
+    fn extract_epi32(a: i32x4, imm8: i32) -> i32 = _mm_extract_epi32;
+    fn extract_epi64(a: i64x2, imm8: i32) -> i64 = _mm_extract_epi64;
+    fn insert_ps(a: __m128, b: __m128, imm8: i32) -> __m128 = _mm_insert_ps;
+    fn insert_epi8(a: i8x16, i: i8, imm8: i32) -> i8x16 = _mm_insert_epi8;
+    fn insert_epi32(a: i32x4, i: i32, imm8: i32) -> i32x4 = _mm_insert_epi32;
+    fn insert_epi64(a: i64x2, i: i64, imm8: i32) -> i64x2 = _mm_insert_epi64;
+    fn max_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_max_epi8;
+    fn max_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_max_epu16;
+    fn max_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_max_epi32;
+    fn max_epu32(a: u32x4, b: u32x4) -> u32x4 = _mm_max_epu32;
+    fn min_epi8(a: i8x16, b: i8x16) -> i8x16 = _mm_min_epi8;
+    fn min_epu16(a: u16x8, b: u16x8) -> u16x8 = _mm_min_epu16;
+    fn min_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_min_epi32;
+    fn min_epu32(a: u32x4, b: u32x4) -> u32x4 = _mm_min_epu32;
+    fn packus_epi32(a: i32x4, b: i32x4) -> u16x8 = _mm_packus_epi32;
+    fn cmpeq_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_cmpeq_epi64;
+    fn cvtepi8_epi16(a: i8x16) -> i16x8 = _mm_cvtepi8_epi16;
+    fn cvtepi8_epi32(a: i8x16) -> i32x4 = _mm_cvtepi8_epi32;
+    fn cvtepi8_epi64(a: i8x16) -> i64x2 = _mm_cvtepi8_epi64;
+    fn cvtepi16_epi32(a: i16x8) -> i32x4 = _mm_cvtepi16_epi32;
+    fn cvtepi16_epi64(a: i16x8) -> i64x2 = _mm_cvtepi16_epi64;
+    fn cvtepi32_epi64(a: i32x4) -> i64x2 = _mm_cvtepi32_epi64;
+    fn cvtepu8_epi16(a: u8x16) -> i16x8 = _mm_cvtepu8_epi16;
+    fn cvtepu8_epi32(a: u8x16) -> i32x4 = _mm_cvtepu8_epi32;
+    fn cvtepu8_epi64(a: u8x16) -> i64x2 = _mm_cvtepu8_epi64;
+    fn cvtepu16_epi32(a: u16x8) -> i32x4 = _mm_cvtepu16_epi32;
+    fn cvtepu16_epi64(a: u16x8) -> i64x2 = _mm_cvtepu16_epi64;
+    fn cvtepu32_epi64(a: u32x4) -> i64x2 = _mm_cvtepu32_epi64;
+    fn dp_pd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 = _mm_dp_pd;
+    fn dp_ps(a: __m128, b: __m128, imm8: i32) -> __m128 = _mm_dp_ps;
+    fn floor_pd(a: f64x2) -> f64x2 = _mm_floor_pd;
+    fn floor_ps(a: __m128) -> __m128 = _mm_floor_ps;
+    fn floor_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_floor_sd;
+    fn floor_ss(a: __m128, b: __m128) -> __m128 = _mm_floor_ss;
+    fn ceil_pd(a: f64x2) -> f64x2 = _mm_ceil_pd;
+    fn ceil_ps(a: __m128) -> __m128 = _mm_ceil_ps;
+    fn ceil_sd(a: f64x2, b: f64x2) -> f64x2 = _mm_ceil_sd;
+    fn ceil_ss(a: __m128, b: __m128) -> __m128 = _mm_ceil_ss;
+    fn round_pd(a: f64x2, rounding: i32) -> f64x2 = _mm_round_pd;
+    fn round_ps(a: __m128, rounding: i32) -> __m128 = _mm_round_ps;
+    fn round_sd(a: f64x2, b: f64x2, rounding: i32) -> f64x2 = _mm_round_sd;
+    fn round_ss(a: __m128, b: __m128, rounding: i32) -> __m128 = _mm_round_ss;
+    fn minpos_epu16(a: u16x8) -> u16x8 = _mm_minpos_epu16;
+    fn mul_epi32(a: i32x4, b: i32x4) -> i64x2 = _mm_mul_epi32;
+    fn mullo_epi32(a: i32x4, b: i32x4) -> i32x4 = _mm_mullo_epi32;
+    fn mpsadbw_epu8(a: u8x16, b: u8x16, imm8: i32) -> u16x8 = _mm_mpsadbw_epu8;
+    fn testz_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testz_si128;
+    fn testc_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testc_si128;
+    fn testnzc_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testnzc_si128;
+    fn test_all_zeros(a: __m128i, mask: __m128i) -> i32 = _mm_test_all_zeros;
+    fn test_all_ones(a: __m128i) -> i32 = _mm_test_all_ones;
+    fn test_mix_ones_zeros(
+        a: __m128i, mask: __m128i,
+    ) -> i32 = _mm_test_mix_ones_zeros;
+    unsafe fn stream_load_si128(
+        mem_addr: *const __m128i,
+    ) -> __m128i = _mm_stream_load_si128;
+}
+
+token_methods! {
+    Sse42, "+sse4.2";
+    fn cmpistrm(a: __m128i, b: __m128i, imm8: i8) -> __m128i = _mm_cmpistrm;
+    fn cmpistri(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistri;
+    fn cmpistrz(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistrz;
+    fn cmpistrc(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistrc;
+    fn cmpistrs(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistrs;
+    fn cmpistro(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistro;
+    fn cmpistra(a: __m128i, b: __m128i, imm8: i8) -> i32 = _mm_cmpistra;
+    fn cmpestrm(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> __m128i = _mm_cmpestrm;
+    fn cmpestri(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestri;
+    fn cmpestrz(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestrz;
+    fn cmpestrc(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestrc;
+    fn cmpestrs(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestrs;
+    fn cmpestro(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestro;
+    fn cmpestra(
+        a: __m128i, la: i32, b: __m128i, lb: i32, imm8: i8,
+    ) -> i32 = _mm_cmpestra;
+    fn crc32_u8(crc: u32, v: u8) -> u32 = _mm_crc32_u8;
+    fn crc32_u16(crc: u32, v: u16) -> u32 = _mm_crc32_u16;
+    fn crc32_u32(crc: u32, v: u32) -> u32 = _mm_crc32_u32;
+    fn cmpgt_epi64(a: i64x2, b: i64x2) -> i64x2 = _mm_cmpgt_epi64;
+}
+
+token_methods! {
+    Avx, "+avx";
+    fn add_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_add_pd;
+    fn add_ps(a: __m256, b: __m256) -> __m256 = _mm256_add_ps;
+    fn addsub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_addsub_pd;
+    fn addsub_ps(a: __m256, b: __m256) -> __m256 = _mm256_addsub_ps;
+    fn and_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_and_pd;
+    fn and_ps(a: __m256, b: __m256) -> __m256 = _mm256_and_ps;
+    fn andnot_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_andnot_pd;
+    fn andnot_ps(a: __m256, b: __m256) -> __m256 = _mm256_andnot_ps;
+    fn blend_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d = _mm256_blend_pd;
+    fn blend_ps(a: __m256, b: __m256, imm8: i32) -> __m256 = _mm256_blend_ps;
+    fn blendv_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_blendv_pd;
+    fn blendv_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_blendv_ps;
+    fn div_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_div_pd;
+    fn div_ps(a: __m256, b: __m256) -> __m256 = _mm256_div_ps;
+    fn dp_ps(a: __m256, b: __m256, imm8: i32) -> __m256 = _mm256_dp_ps;
+    fn hadd_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_hadd_pd;
+    fn hadd_ps(a: __m256, b: __m256) -> __m256 = _mm256_hadd_ps;
+    fn hsub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_hsub_pd;
+    fn hsub_ps(a: __m256, b: __m256) -> __m256 = _mm256_hsub_ps;
+    fn max_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_max_pd;
+    fn max_ps(a: __m256, b: __m256) -> __m256 = _mm256_max_ps;
+    fn min_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_min_pd;
+    fn min_ps(a: __m256, b: __m256) -> __m256 = _mm256_min_ps;
+    fn mul_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_mul_pd;
+    fn mul_ps(a: __m256, b: __m256) -> __m256 = _mm256_mul_ps;
+    fn or_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_or_pd;
+    fn or_ps(a: __m256, b: __m256) -> __m256 = _mm256_or_ps;
+    fn shuffle_pd(
+        a: __m256d, b: __m256d, imm8: i32,
+    ) -> __m256d = _mm256_shuffle_pd;
+    fn shuffle_ps(
+        a: __m256, b: __m256, imm8: i32,
+    ) -> __m256 = _mm256_shuffle_ps;
+    fn sub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_sub_pd;
+    fn sub_ps(a: __m256, b: __m256) -> __m256 = _mm256_sub_ps;
+    fn xor_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_xor_pd;
+    fn xor_ps(a: __m256, b: __m256) -> __m256 = _mm256_xor_ps;
+    fn mm_cmp_pd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 = _mm_cmp_pd;
+    fn cmp_pd(a: __m256d, b: __m256d, imm8: i32) -> __m256d = _mm256_cmp_pd;
+    fn mm_cmp_ps(a: __m128, b: __m128, imm8: i32) -> __m128 = _mm_cmp_ps;
+    fn cmp_ps(a: __m256, b: __m256, imm8: i32) -> __m256 = _mm256_cmp_ps;
+    fn cmp_sd(a: f64x2, b: f64x2, imm8: i32) -> f64x2 = _mm_cmp_sd;
+    fn cmp_ss(a: __m128, b: __m128, imm8: i32) -> __m128 = _mm_cmp_ss;
+    fn cvtepi32_pd(a: i32x4) -> __m256d = _mm256_cvtepi32_pd;
+    fn cvtepi32_ps(a: i32x8) -> __m256 = _mm256_cvtepi32_ps;
+    fn cvtpd_ps(a: __m256d) -> __m128 = _mm256_cvtpd_ps;
+    fn cvtps_epi32(a: __m256) -> i32x8 = _mm256_cvtps_epi32;
+    fn cvtps_pd(a: __m128) -> __m256d = _mm256_cvtps_pd;
+    fn cvttpd_epi32(a: __m256d) -> i32x4 = _mm256_cvttpd_epi32;
+    fn cvtpd_epi32(a: __m256d) -> i32x4 = _mm256_cvtpd_epi32;
+    fn cvttps_epi32(a: __m256) -> i32x8 = _mm256_cvttps_epi32;
+    fn extractf128_ps(a: __m256, imm8: i32) -> __m128 = _mm256_extractf128_ps;
+    fn extractf128_pd(a: __m256d, imm8: i32) -> f64x2 = _mm256_extractf128_pd;
+    fn extractf128_si256(
+        a: __m256i, imm8: i32,
+    ) -> __m128i = _mm256_extractf128_si256;
+    fn extract_epi8(a: i8x32, imm8: i32) -> i32 = _mm256_extract_epi8;
+    fn extract_epi16(a: i16x16, imm8: i32) -> i32 = _mm256_extract_epi16;
+    fn extract_epi32(a: i32x8, imm8: i32) -> i32 = _mm256_extract_epi32;
+    fn extract_epi64(a: i64x4, imm8: i32) -> i64 = _mm256_extract_epi64;
+    fn zeroall() -> () = _mm256_zeroall;
+    fn zeroupper() -> () = _mm256_zeroupper;
+    fn permutevar_ps(a: __m256, b: i32x8) -> __m256 = _mm256_permutevar_ps;
+    fn mm_permutevar_ps(a: __m128, b: i32x4) -> __m128 = _mm_permutevar_ps;
+    fn permute_ps(a: __m256, imm8: i32) -> __m256 = _mm256_permute_ps;
+    fn mm_permute_ps(a: __m128, imm8: i32) -> __m128 = _mm_permute_ps;
+    fn permutevar_pd(a: __m256d, b: i64x4) -> __m256d = _mm256_permutevar_pd;
+    fn mm_permutevar_pd(a: f64x2, b: i64x2) -> f64x2 = _mm_permutevar_pd;
+    fn permute_pd(a: __m256d, imm8: i32) -> __m256d = _mm256_permute_pd;
+    fn mm_permute_pd(a: f64x2, imm8: i32) -> f64x2 = _mm_permute_pd;
+    fn permute2f128_ps(
+        a: __m256, b: __m256, imm8: i32,
+    ) -> __m256 = _mm256_permute2f128_ps;
+    fn permute2f128_pd(
+        a: __m256d, b: __m256d, imm8: i32,
+    ) -> __m256d = _mm256_permute2f128_pd;
+    fn permute2f128_si256(
+        a: __m256i, b: __m256i, imm8: i32,
+    ) -> __m256i = _mm256_permute2f128_si256;
+    fn broadcast_ss(f: &f32) -> __m256 = _mm256_broadcast_ss;
+    fn mm_broadcast_ss(f: &f32) -> __m128 = _mm_broadcast_ss;
+    fn broadcast_sd(f: &f64) -> __m256d = _mm256_broadcast_sd;
+    fn broadcast_ps(a: &__m128) -> __m256 = _mm256_broadcast_ps;
+    fn broadcast_pd(a: &f64x2) -> __m256d = _mm256_broadcast_pd;
+    fn insertf128_ps(
+        a: __m256, b: __m128, imm8: i32,
+    ) -> __m256 = _mm256_insertf128_ps;
+    fn insertf128_pd(
+        a: __m256d, b: f64x2, imm8: i32,
+    ) -> __m256d = _mm256_insertf128_pd;
+    fn insertf128_si256(
+        a: __m256i, b: __m128i, imm8: i32,
+    ) -> __m256i = _mm256_insertf128_si256;
+    fn insert_epi8(a: i8x32, i: i8, index: i32) -> i8x32 = _mm256_insert_epi8;
+    fn insert_epi16(
+        a: i16x16, i: i16, index: i32,
+    ) -> i16x16 = _mm256_insert_epi16;
+    fn insert_epi32(
+        a: i32x8, i: i32, index: i32,
+    ) -> i32x8 = _mm256_insert_epi32;
+    fn insert_epi64(
+        a: i64x4, i: i64, index: i32,
+    ) -> i64x4 = _mm256_insert_epi64;
+    fn movehdup_ps(a: __m256) -> __m256 = _mm256_movehdup_ps;
+    fn moveldup_ps(a: __m256) -> __m256 = _mm256_moveldup_ps;
+    fn movedup_pd(a: __m256d) -> __m256d = _mm256_movedup_pd;
+    fn rcp_ps(a: __m256) -> __m256 = _mm256_rcp_ps;
+    fn rsqrt_ps(a: __m256) -> __m256 = _mm256_rsqrt_ps;
+    fn sqrt_pd(a: __m256d) -> __m256d = _mm256_sqrt_pd;
+    fn sqrt_ps(a: __m256) -> __m256 = _mm256_sqrt_ps;
+    fn round_pd(a: __m256d, rounding: i32) -> __m256d = _mm256_round_pd;
+    fn round_ps(a: __m256, rounding: i32) -> __m256 = _mm256_round_ps;
+    fn ceil_pd(a: __m256d) -> __m256d = _mm256_ceil_pd;
+    fn floor_pd(a: __m256d) -> __m256d = _mm256_floor_pd;
+    fn ceil_ps(a: __m256) -> __m256 = _mm256_ceil_ps;
+    fn floor_ps(a: __m256) -> __m256 = _mm256_floor_ps;
+    fn unpackhi_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_unpackhi_pd;
+    fn unpackhi_ps(a: __m256, b: __m256) -> __m256 = _mm256_unpackhi_ps;
+    fn unpacklo_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_unpacklo_pd;
+    fn unpacklo_ps(a: __m256, b: __m256) -> __m256 = _mm256_unpacklo_ps;
+    fn testz_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testz_si256;
+    fn testc_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testc_si256;
+    fn testnzc_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testnzc_si256;
+    fn testz_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testz_pd;
+    fn testc_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testc_pd;
+    fn testnzc_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testnzc_pd;
+    fn mm_testz_pd(a: f64x2, b: f64x2) -> i32 = _mm_testz_pd;
+    fn mm_testc_pd(a: f64x2, b: f64x2) -> i32 = _mm_testc_pd;
+    fn mm_testnzc_pd(a: f64x2, b: f64x2) -> i32 = _mm_testnzc_pd;
+    fn testz_ps(a: __m256, b: __m256) -> i32 = _mm256_testz_ps;
+    fn testc_ps(a: __m256, b: __m256) -> i32 = _mm256_testc_ps;
+    fn testnzc_ps(a: __m256, b: __m256) -> i32 = _mm256_testnzc_ps;
+    fn mm_testz_ps(a: __m128, b: __m128) -> i32 = _mm_testz_ps;
+    fn mm_testc_ps(a: __m128, b: __m128) -> i32 = _mm_testc_ps;
+    fn mm_testnzc_ps(a: __m128, b: __m128) -> i32 = _mm_testnzc_ps;
+    fn movemask_pd(a: __m256d) -> i32 = _mm256_movemask_pd;
+    fn movemask_ps(a: __m256) -> i32 = _mm256_movemask_ps;
+    fn setzero_pd() -> __m256d = _mm256_setzero_pd;
+    fn setzero_ps() -> __m256 = _mm256_setzero_ps;
+    fn setzero_si256() -> __m256i = _mm256_setzero_si256;
+    fn set_pd(e3: f64, e2: f64, e1: f64, e0: f64) -> __m256d = _mm256_set_pd;
+    fn set_ps(
+        e7: f32, e6: f32, e5: f32, e4: f32, e3: f32, e2: f32, e1: f32, e0: f32,
+    ) -> __m256 = _mm256_set_ps;
+    fn set_epi8(
+        e31: i8, e30: i8, e29: i8, e28: i8, e27: i8, e26: i8, e25: i8, e24: i8,
+        e23: i8, e22: i8, e21: i8, e20: i8, e19: i8, e18: i8, e17: i8, e16: i8,
+        e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+        e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+    ) -> i8x32 = _mm256_set_epi8;
+    fn set_epi16(
+        e15: i16, e14: i16, e13: i16, e12: i16, e11: i16, e10: i16, e9: i16,
+        e8: i16, e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16,
+        e0: i16,
+    ) -> i16x16 = _mm256_set_epi16;
+    fn set_epi32(
+        e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32,
+    ) -> i32x8 = _mm256_set_epi32;
+    fn set_epi64x(
+        e3: i64, e2: i64, e1: i64, e0: i64,
+    ) -> i64x4 = _mm256_set_epi64x;
+    fn setr_pd(e3: f64, e2: f64, e1: f64, e0: f64) -> __m256d = _mm256_setr_pd;
+    fn setr_ps(
+        e7: f32, e6: f32, e5: f32, e4: f32, e3: f32, e2: f32, e1: f32, e0: f32,
+    ) -> __m256 = _mm256_setr_ps;
+    fn setr_epi8(
+        e31: i8, e30: i8, e29: i8, e28: i8, e27: i8, e26: i8, e25: i8, e24: i8,
+        e23: i8, e22: i8, e21: i8, e20: i8, e19: i8, e18: i8, e17: i8, e16: i8,
+        e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8, e8: i8,
+        e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8, e0: i8,
+    ) -> i8x32 = _mm256_setr_epi8;
+    fn setr_epi16(
+        e15: i16, e14: i16, e13: i16, e12: i16, e11: i16, e10: i16, e9: i16,
+        e8: i16, e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16,
+        e0: i16,
+    ) -> i16x16 = _mm256_setr_epi16;
+    fn setr_epi32(
+        e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32,
+    ) -> i32x8 = _mm256_setr_epi32;
+    fn setr_epi64x(
+        e3: i64, e2: i64, e1: i64, e0: i64,
+    ) -> i64x4 = _mm256_setr_epi64x;
+    fn set1_pd(a: f64) -> __m256d = _mm256_set1_pd;
+    fn set1_ps(a: f32) -> __m256 = _mm256_set1_ps;
+    fn set1_epi8(a: i8) -> i8x32 = _mm256_set1_epi8;
+    fn set1_epi16(a: i16) -> i16x16 = _mm256_set1_epi16;
+    fn set1_epi32(a: i32) -> i32x8 = _mm256_set1_epi32;
+    fn set1_epi64x(a: i64) -> i64x4 = _mm256_set1_epi64x;
+    fn castpd_ps(a: __m256d) -> __m256 = _mm256_castpd_ps;
+    fn castps_pd(a: __m256) -> __m256d = _mm256_castps_pd;
+    fn castps_si256(a: __m256) -> __m256i = _mm256_castps_si256;
+    fn castpd_si256(a: __m256d) -> __m256i = _mm256_castpd_si256;
+    fn castsi256_ps(a: __m256i) -> __m256 = _mm256_castsi256_ps;
+    fn castsi256_pd(a: __m256i) -> __m256d = _mm256_castsi256_pd;
+    fn castps256_ps128(a: __m256) -> __m128 = _mm256_castps256_ps128;
+    fn castpd256_pd128(a: __m256d) -> f64x2 = _mm256_castpd256_pd128;
+    fn castsi256_si128(a: __m256i) -> __m128i = _mm256_castsi256_si128;
+    fn castps128_ps256(a: __m128) -> __m256 = _mm256_castps128_ps256;
+    fn castpd128_pd256(a: f64x2) -> __m256d = _mm256_castpd128_pd256;
+    fn castsi128_si256(a: __m128i) -> __m256i = _mm256_castsi128_si256;
+    fn zextps128_ps256(a: __m128) -> __m256 = _mm256_zextps128_ps256;
+    fn zextpd128_pd256(a: f64x2) -> __m256d = _mm256_zextpd128_pd256;
+    fn zextsi128_si256(a: __m128i) -> __m256i = _mm256_zextsi128_si256;
+    fn undefined_ps() -> __m256 = _mm256_undefined_ps;
+    fn undefined_pd() -> __m256d = _mm256_undefined_pd;
+    fn undefined_si256() -> __m256i = _mm256_undefined_si256;
+    fn set_m128(hi: __m128, lo: __m128) -> __m256 = _mm256_set_m128;
+    fn set_m128d(hi: f64x2, lo: f64x2) -> __m256d = _mm256_set_m128d;
+    fn set_m128i(hi: __m128i, lo: __m128i) -> __m256i = _mm256_set_m128i;
+    fn setr_m128(lo: __m128, hi: __m128) -> __m256 = _mm256_setr_m128;
+    fn setr_m128d(lo: f64x2, hi: f64x2) -> __m256d = _mm256_setr_m128d;
+    fn setr_m128i(lo: __m128i, hi: __m128i) -> __m256i = _mm256_setr_m128i;
+    unsafe fn load_pd(mem_addr: *const f64) -> __m256d = _mm256_load_pd;
+    unsafe fn store_pd(mem_addr: *mut f64, a: __m256d) -> () = _mm256_store_pd;
+    unsafe fn load_ps(mem_addr: *const f32) -> __m256 = _mm256_load_ps;
+    unsafe fn store_ps(mem_addr: *mut f32, a: __m256) -> () = _mm256_store_ps;
+    unsafe fn loadu_pd(mem_addr: *const f64) -> __m256d = _mm256_loadu_pd;
+    unsafe fn storeu_pd(
+        mem_addr: *mut f64, a: __m256d,
+    ) -> () = _mm256_storeu_pd;
+    unsafe fn loadu_ps(mem_addr: *const f32) -> __m256 = _mm256_loadu_ps;
+    unsafe fn storeu_ps(mem_addr: *mut f32, a: __m256) -> () = _mm256_storeu_ps;
+    unsafe fn load_si256(
+        mem_addr: *const __m256i,
+    ) -> __m256i = _mm256_load_si256;
+    unsafe fn store_si256(
+        mem_addr: *mut __m256i, a: __m256i,
+    ) -> () = _mm256_store_si256;
+    unsafe fn loadu_si256(
+        mem_addr: *const __m256i,
+    ) -> __m256i = _mm256_loadu_si256;
+    unsafe fn storeu_si256(
+        mem_addr: *mut __m256i, a: __m256i,
+    ) -> () = _mm256_storeu_si256;
+    unsafe fn maskload_pd(
+        mem_addr: *const f64, mask: i64x4,
+    ) -> __m256d = _mm256_maskload_pd;
+    unsafe fn maskstore_pd(
+        mem_addr: *mut f64, mask: i64x4, a: __m256d,
+    ) -> () = _mm256_maskstore_pd;
+    unsafe fn mm_maskload_pd(
+        mem_addr: *const f64, mask: i64x2,
+    ) -> f64x2 = _mm_maskload_pd;
+    unsafe fn mm_maskstore_pd(
+        mem_addr: *mut f64, mask: i64x2, a: f64x2,
+    ) -> () = _mm_maskstore_pd;
+    unsafe fn maskload_ps(
+        mem_addr: *const f32, mask: i32x8,
+    ) -> __m256 = _mm256_maskload_ps;
+    unsafe fn maskstore_ps(
+        mem_addr: *mut f32, mask: i32x8, a: __m256,
+    ) -> () = _mm256_maskstore_ps;
+    unsafe fn mm_maskload_ps(
+        mem_addr: *const f32, mask: i32x4,
+    ) -> __m128 = _mm_maskload_ps;
+    unsafe fn mm_maskstore_ps(
+        mem_addr: *mut f32, mask: i32x4, a: __m128,
+    ) -> () = _mm_maskstore_ps;
+    unsafe fn lddqu_si256(
+        mem_addr: *const __m256i,
+    ) -> __m256i = _mm256_lddqu_si256;
+    unsafe fn stream_si256(
+        mem_addr: *mut __m256i, a: __m256i,
+    ) -> () = _mm256_stream_si256;
+    unsafe fn stream_pd(
+        mem_addr: *mut f64, a: __m256d,
+    ) -> () = _mm256_stream_pd;
+    unsafe fn stream_ps(mem_addr: *mut f32, a: __m256) -> () = _mm256_stream_ps;
+    unsafe fn loadu2_m128(
+        hiaddr: *const f32, loaddr: *const f32,
+    ) -> __m256 = _mm256_loadu2_m128;
+    unsafe fn loadu2_m128d(
+        hiaddr: *const f64, loaddr: *const f64,
+    ) -> __m256d = _mm256_loadu2_m128d;
+    unsafe fn loadu2_m128i(
+        hiaddr: *const __m128i, loaddr: *const __m128i,
+    ) -> __m256i = _mm256_loadu2_m128i;
+    unsafe fn storeu2_m128(
+        hiaddr: *mut f32, loaddr: *mut f32, a: __m256,
+    ) -> () = _mm256_storeu2_m128;
+    unsafe fn storeu2_m128d(
+        hiaddr: *mut f64, loaddr: *mut f64, a: __m256d,
+    ) -> () = _mm256_storeu2_m128d;
+    unsafe fn storeu2_m128i(
+        hiaddr: *mut __m128i, loaddr: *mut __m128i, a: __m256i,
+    ) -> () = _mm256_storeu2_m128i;
+}
+
+token_methods! {
+    Avx2, "+avx2";
+    fn abs_epi32(a: i32x8) -> i32x8 = _mm256_abs_epi32;
+    fn abs_epi16(a: i16x16) -> i16x16 = _mm256_abs_epi16;
+    fn abs_epi8(a: i8x32) -> i8x32 = _mm256_abs_epi8;
+    fn add_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_add_epi64;
+    fn add_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_add_epi32;
+    fn add_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_add_epi16;
+    fn add_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_add_epi8;
+    fn adds_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_adds_epi8;
+    fn adds_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_adds_epi16;
+    fn adds_epu8(a: u8x32, b: u8x32) -> u8x32 = _mm256_adds_epu8;
+    fn adds_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_adds_epu16;
+    fn alignr_epi8(a: i8x32, b: i8x32, n: i32) -> i8x32 = _mm256_alignr_epi8;
+    fn and_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_and_si256;
+    fn andnot_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_andnot_si256;
+    fn avg_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_avg_epu16;
+    fn avg_epu8(a: u8x32, b: u8x32) -> u8x32 = _mm256_avg_epu8;
+    fn blend_epi16(
+        a: i16x16, b: i16x16, imm8: i32,
+    ) -> i16x16 = _mm256_blend_epi16;
+    fn mm_blend_epi32(a: i32x4, b: i32x4, imm8: i32) -> i32x4 = _mm_blend_epi32;
+    fn blend_epi32(a: i32x8, b: i32x8, imm8: i32) -> i32x8 = _mm256_blend_epi32;
+    fn blendv_epi8(
+        a:i8x32, b:i8x32, mask:__m256i,
+    ) -> i8x32 = _mm256_blendv_epi8;
+    fn mm_broadcastb_epi8(a: i8x16) -> i8x16 = _mm_broadcastb_epi8;
+    fn broadcastb_epi8(a: i8x16) -> i8x32 = _mm256_broadcastb_epi8;
+    fn mm_broadcastd_epi32(a: i32x4) -> i32x4 = _mm_broadcastd_epi32;
+    fn broadcastd_epi32(a: i32x4) -> i32x8 = _mm256_broadcastd_epi32;
+    fn mm_broadcastq_epi64(a: i64x2) -> i64x2 = _mm_broadcastq_epi64;
+    fn broadcastq_epi64(a: i64x2) -> i64x4 = _mm256_broadcastq_epi64;
+    fn mm_broadcastsd_pd(a: f64x2) -> f64x2 = _mm_broadcastsd_pd;
+    fn broadcastsd_pd(a: f64x2) -> __m256d = _mm256_broadcastsd_pd;
+    fn mm_broadcastsi128_si256(
+        a: __m128i,
+    ) -> __m256i = _mm_broadcastsi128_si256;
+    fn broadcastsi128_si256(
+        a: __m128i,
+    ) -> __m256i = _mm256_broadcastsi128_si256;
+    fn mm_broadcastss_ps(a: __m128) -> __m128 = _mm_broadcastss_ps;
+    fn broadcastss_ps(a: __m128) -> __m256 = _mm256_broadcastss_ps;
+    fn mm_broadcastw_epi16(a: i16x8) -> i16x8 = _mm_broadcastw_epi16;
+    fn broadcastw_epi16(a: i16x8) -> i16x16 = _mm256_broadcastw_epi16;
+    fn bslli_epi128(a: __m256i, imm8: i32) -> __m256i = _mm256_bslli_epi128;
+    fn bsrli_epi128(a: __m256i, imm8: i32) -> __m256i = _mm256_bsrli_epi128;
+    fn cmpeq_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_cmpeq_epi64;
+    fn cmpeq_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_cmpeq_epi32;
+    fn cmpeq_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_cmpeq_epi16;
+    fn cmpeq_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_cmpeq_epi8;
+    fn cmpgt_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_cmpgt_epi64;
+    fn cmpgt_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_cmpgt_epi32;
+    fn cmpgt_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_cmpgt_epi16;
+    fn cmpgt_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_cmpgt_epi8;
+    fn cvtepi16_epi32(a: i16x8) -> i32x8 = _mm256_cvtepi16_epi32;
+    fn cvtepi16_epi64(a: i16x8) -> i64x4 = _mm256_cvtepi16_epi64;
+    fn cvtepi32_epi64(a: i32x4) -> i64x4 = _mm256_cvtepi32_epi64;
+    fn cvtepi8_epi16(a: i8x16) -> i16x16 = _mm256_cvtepi8_epi16;
+    fn cvtepi8_epi32(a: i8x16) -> i32x8 = _mm256_cvtepi8_epi32;
+    fn cvtepi8_epi64(a: i8x16) -> i64x4 = _mm256_cvtepi8_epi64;
+    fn cvtepu16_epi32(a: u16x8) -> i32x8 = _mm256_cvtepu16_epi32;
+    fn cvtepu16_epi64(a: u16x8) -> i64x4 = _mm256_cvtepu16_epi64;
+    fn cvtepu32_epi64(a: u32x4) -> i64x4 = _mm256_cvtepu32_epi64;
+    fn cvtepu8_epi16(a: u8x16) -> i16x16 = _mm256_cvtepu8_epi16;
+    fn cvtepu8_epi32(a: u8x16) -> i32x8 = _mm256_cvtepu8_epi32;
+    fn cvtepu8_epi64(a: u8x16) -> i64x4 = _mm256_cvtepu8_epi64;
+    fn extracti128_si256(
+        a: __m256i, imm8: i32,
+    ) -> __m128i = _mm256_extracti128_si256;
+    fn hadd_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_hadd_epi16;
+    fn hadd_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_hadd_epi32;
+    fn hadds_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_hadds_epi16;
+    fn hsub_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_hsub_epi16;
+    fn hsub_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_hsub_epi32;
+    fn hsubs_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_hsubs_epi16;
+    fn inserti128_si256(
+        a: __m256i, b: __m128i, imm8: i32,
+    ) -> __m256i = _mm256_inserti128_si256;
+    fn madd_epi16(a: i16x16, b: i16x16) -> i32x8 = _mm256_madd_epi16;
+    fn maddubs_epi16(a: u8x32, b: u8x32) -> i16x16 = _mm256_maddubs_epi16;
+    fn max_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_max_epi16;
+    fn max_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_max_epi32;
+    fn max_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_max_epi8;
+    fn max_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_max_epu16;
+    fn max_epu32(a: u32x8, b: u32x8) -> u32x8 = _mm256_max_epu32;
+    fn max_epu8(a: u8x32, b: u8x32) -> u8x32 = _mm256_max_epu8;
+    fn min_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_min_epi16;
+    fn min_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_min_epi32;
+    fn min_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_min_epi8;
+    fn min_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_min_epu16;
+    fn min_epu32(a: u32x8, b: u32x8) -> u32x8 = _mm256_min_epu32;
+    fn min_epu8(a: u8x32, b: u8x32) -> u8x32 = _mm256_min_epu8;
+    fn movemask_epi8(a: i8x32) -> i32 = _mm256_movemask_epi8;
+    fn mpsadbw_epu8(
+        a: u8x32, b: u8x32, imm8: i32,
+    ) -> u16x16 = _mm256_mpsadbw_epu8;
+    fn mul_epi32(a: i32x8, b: i32x8) -> i64x4 = _mm256_mul_epi32;
+    fn mul_epu32(a: u32x8, b: u32x8) -> u64x4 = _mm256_mul_epu32;
+    fn mulhi_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_mulhi_epi16;
+    fn mulhi_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_mulhi_epu16;
+    fn mullo_epi16(a: i16x16, b:i16x16) -> i16x16 = _mm256_mullo_epi16;
+    fn mullo_epi32(a: i32x8, b:i32x8) -> i32x8 = _mm256_mullo_epi32;
+    fn mulhrs_epi16(a: i16x16, b:i16x16) -> i16x16 = _mm256_mulhrs_epi16;
+    fn or_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_or_si256;
+    fn packs_epi16(a: i16x16, b: i16x16) -> i8x32 = _mm256_packs_epi16;
+    fn packs_epi32(a: i32x8, b: i32x8) -> i16x16 = _mm256_packs_epi32;
+    fn packus_epi16(a: i16x16, b: i16x16) -> u8x32 = _mm256_packus_epi16;
+    fn packus_epi32(a: i32x8, b: i32x8) -> u16x16 = _mm256_packus_epi32;
+    fn permute2x128_si256(
+        a: __m256i, b: __m256i, imm8: i32,
+    ) -> __m256i = _mm256_permute2x128_si256;
+    fn permute4x64_epi64(
+        a: i64x4, imm8: i32,
+    ) -> i64x4 = _mm256_permute4x64_epi64;
+    fn permute4x64_pd(a: __m256d, imm8: i32) -> __m256d = _mm256_permute4x64_pd;
+    fn permutevar8x32_epi32(
+        a: i32x8, idx: i32x8,
+    ) -> i32x8 = _mm256_permutevar8x32_epi32;
+    fn permutevar8x32_ps(
+        a: __m256, idx: i32x8,
+    ) -> __m256 = _mm256_permutevar8x32_ps;
+    fn sad_epu8(a: u8x32, b: u8x32) -> u64x4 = _mm256_sad_epu8;
+    fn shuffle_epi32(a: i32x8, imm8: i32) -> i32x8 = _mm256_shuffle_epi32;
+    fn shuffle_epi8(a: u8x32, b: u8x32) -> u8x32 = _mm256_shuffle_epi8;
+    fn shufflehi_epi16(a: i16x16, imm8: i32) -> i16x16 = _mm256_shufflehi_epi16;
+    fn shufflelo_epi16(a: i16x16, imm8: i32) -> i16x16 = _mm256_shufflelo_epi16;
+    fn sign_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_sign_epi16;
+    fn sign_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_sign_epi32;
+    fn sign_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_sign_epi8;
+    fn sll_epi16(a: i16x16, count: i16x8) -> i16x16 = _mm256_sll_epi16;
+    fn sll_epi32(a: i32x8, count: i32x4) -> i32x8 = _mm256_sll_epi32;
+    fn sll_epi64(a: i64x4, count: i64x2) -> i64x4 = _mm256_sll_epi64;
+    fn slli_epi16(a: i16x16, imm8: i32) -> i16x16 = _mm256_slli_epi16;
+    fn slli_epi32(a: i32x8, imm8: i32) -> i32x8 = _mm256_slli_epi32;
+    fn slli_epi64(a: i64x4, imm8: i32) -> i64x4 = _mm256_slli_epi64;
+    fn slli_si256(a: __m256i, imm8: i32) -> __m256i = _mm256_slli_si256;
+    fn mm_sllv_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_sllv_epi32;
+    fn sllv_epi32(a: i32x8, count: i32x8) -> i32x8 = _mm256_sllv_epi32;
+    fn mm_sllv_epi64(a: i64x2, count: i64x2) -> i64x2 = _mm_sllv_epi64;
+    fn sllv_epi64(a: i64x4, count: i64x4) -> i64x4 = _mm256_sllv_epi64;
+    fn sra_epi16(a: i16x16, count: i16x8) -> i16x16 = _mm256_sra_epi16;
+    fn sra_epi32(a: i32x8, count: i32x4) -> i32x8 = _mm256_sra_epi32;
+    fn srai_epi16(a: i16x16, imm8: i32) -> i16x16 = _mm256_srai_epi16;
+    fn srai_epi32(a: i32x8, imm8: i32) -> i32x8 = _mm256_srai_epi32;
+    fn mm_srav_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_srav_epi32;
+    fn srav_epi32(a: i32x8, count: i32x8) -> i32x8 = _mm256_srav_epi32;
+    fn srl_epi16(a: i16x16, count: i16x8) -> i16x16 = _mm256_srl_epi16;
+    fn srl_epi32(a: i32x8, count: i32x4) -> i32x8 = _mm256_srl_epi32;
+    fn srl_epi64(a: i64x4, count: i64x2) -> i64x4 = _mm256_srl_epi64;
+    fn srli_epi16(a: i16x16, imm8: i32) -> i16x16 = _mm256_srli_epi16;
+    fn srli_epi32(a: i32x8, imm8: i32) -> i32x8 = _mm256_srli_epi32;
+    fn srli_epi64(a: i64x4, imm8: i32) -> i64x4 = _mm256_srli_epi64;
+    fn srli_si256(a: __m256i, imm8: i32) -> __m256i = _mm256_srli_si256;
+    fn mm_srlv_epi32(a: i32x4, count: i32x4) -> i32x4 = _mm_srlv_epi32;
+    fn srlv_epi32(a: i32x8, count: i32x8) -> i32x8 = _mm256_srlv_epi32;
+    fn mm_srlv_epi64(a: i64x2, count: i64x2) -> i64x2 = _mm_srlv_epi64;
+    fn srlv_epi64(a: i64x4, count: i64x4) -> i64x4 = _mm256_srlv_epi64;
+    fn sub_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_sub_epi16;
+    fn sub_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_sub_epi32;
+    fn sub_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_sub_epi64;
+    fn sub_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_sub_epi8;
+    fn subs_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_subs_epi16;
+    fn subs_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_subs_epi8;
+    fn subs_epu16(a: u16x16, b: u16x16) -> u16x16 = _mm256_subs_epu16;
+    fn subs_epu8(a: u8x32, b: u8x32) -> u8x32 = _mm256_subs_epu8;
+    fn unpackhi_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_unpackhi_epi8;
+    fn unpackhi_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_unpackhi_epi16;
+    fn unpackhi_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_unpackhi_epi32;
+    fn unpackhi_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_unpackhi_epi64;
+    fn unpacklo_epi8(a: i8x32, b: i8x32) -> i8x32 = _mm256_unpacklo_epi8;
+    fn unpacklo_epi16(a: i16x16, b: i16x16) -> i16x16 = _mm256_unpacklo_epi16;
+    fn unpacklo_epi32(a: i32x8, b: i32x8) -> i32x8 = _mm256_unpacklo_epi32;
+    fn unpacklo_epi64(a: i64x4, b: i64x4) -> i64x4 = _mm256_unpacklo_epi64;
+    fn xor_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_xor_si256;
+    unsafe fn mm_i32gather_epi32(
+        slice: *const i32, offsets: i32x4, scale: i32,
+    ) -> i32x4 = _mm_i32gather_epi32;
+    unsafe fn mm_mask_i32gather_epi32(
+        src: i32x4, slice: *const i32, offsets: i32x4, mask: i32x4, scale: i32,
+    ) -> i32x4 = _mm_mask_i32gather_epi32;
+    unsafe fn i32gather_epi32(
+        slice: *const i32, offsets: i32x8, scale: i32,
+    ) -> i32x8 = _mm256_i32gather_epi32;
+    unsafe fn mask_i32gather_epi32(
+        src: i32x8, slice: *const i32, offsets: i32x8, mask: i32x8, scale: i32,
+    ) -> i32x8 = _mm256_mask_i32gather_epi32;
+    unsafe fn mm_i32gather_ps(
+        slice: *const f32, offsets: i32x4, scale: i32,
+    ) -> f32x4 = _mm_i32gather_ps;
+    unsafe fn mm_mask_i32gather_ps(
+        src: f32x4, slice: *const f32, offsets: i32x4, mask: f32x4, scale: i32,
+    ) -> f32x4 = _mm_mask_i32gather_ps;
+    unsafe fn i32gather_ps(
+        slice: *const f32, offsets: i32x8, scale: i32,
+    ) -> f32x8 = _mm256_i32gather_ps;
+    unsafe fn mask_i32gather_ps(
+        src: f32x8, slice: *const f32, offsets: i32x8, mask: f32x8, scale: i32,
+    ) -> f32x8 = _mm256_mask_i32gather_ps;
+    unsafe fn mm_i32gather_epi64(
+        slice: *const i64, offsets: i32x4, scale: i32,
+    ) -> i64x2 = _mm_i32gather_epi64;
+    unsafe fn mm_mask_i32gather_epi64(
+        src: i64x2, slice: *const i64, offsets: i32x4, mask: i64x2, scale: i32,
+    ) -> i64x2 = _mm_mask_i32gather_epi64;
+    unsafe fn i32gather_epi64(
+        slice: *const i64, offsets: i32x4, scale: i32,
+    ) -> i64x4 = _mm256_i32gather_epi64;
+    unsafe fn mask_i32gather_epi64(
+        src: i64x4, slice: *const i64, offsets: i32x4, mask: i64x4, scale: i32,
+    ) -> i64x4 = _mm256_mask_i32gather_epi64;
+    unsafe fn mm_i32gather_pd(
+        slice: *const f64, offsets: i32x4, scale: i32,
+    ) -> f64x2 = _mm_i32gather_pd;
+    unsafe fn mm_mask_i32gather_pd(
+        src: f64x2, slice: *const f64, offsets: i32x4, mask: f64x2, scale: i32,
+    ) -> f64x2 = _mm_mask_i32gather_pd;
+    unsafe fn i32gather_pd(
+        slice: *const f64, offsets: i32x4, scale: i32,
+    ) -> f64x4 = _mm256_i32gather_pd;
+    unsafe fn mask_i32gather_pd(
+        src: f64x4, slice: *const f64, offsets: i32x4, mask: f64x4, scale: i32,
+    ) -> f64x4 = _mm256_mask_i32gather_pd;
+    unsafe fn mm_i64gather_epi32(
+        slice: *const i32, offsets: i64x2, scale: i32,
+    ) -> i32x4 = _mm_i64gather_epi32;
+    unsafe fn mm_mask_i64gather_epi32(
+        src: i32x4, slice: *const i32, offsets: i64x2, mask: i32x4, scale: i32,
+    ) -> i32x4 = _mm_mask_i64gather_epi32;
+    unsafe fn i64gather_epi32(
+        slice: *const i32, offsets: i64x4, scale: i32,
+    ) -> i32x4 = _mm256_i64gather_epi32;
+    unsafe fn mask_i64gather_epi32(
+        src: i32x4, slice: *const i32, offsets: i64x4, mask: i32x4, scale: i32,
+    ) -> i32x4 = _mm256_mask_i64gather_epi32;
+    unsafe fn mm_i64gather_epi64(
+        slice: *const i64, offsets: i64x2, scale: i32,
+    ) -> i64x2 = _mm_i64gather_epi64;
+    unsafe fn mm_mask_i64gather_epi64(
+        src: i64x2, slice: *const i64, offsets: i64x2, mask: i64x2, scale: i32,
+    ) -> i64x2 = _mm_mask_i64gather_epi64;
+    unsafe fn i64gather_epi64(
+        slice: *const i64, offsets: i64x4, scale: i32,
+    ) -> i64x4 = _mm256_i64gather_epi64;
+    unsafe fn mask_i64gather_epi64(
+        src: i64x4, slice: *const i64, offsets: i64x4, mask: i64x4, scale: i32,
+    ) -> i64x4 = _mm256_mask_i64gather_epi64;
+    unsafe fn mm_i64gather_pd(
+        slice: *const f64, offsets: i64x2, scale: i32,
+    ) -> f64x2 = _mm_i64gather_pd;
+    unsafe fn mm_mask_i64gather_pd(
+        src: f64x2, slice: *const f64, offsets: i64x2, mask: f64x2, scale: i32,
+    ) -> f64x2 = _mm_mask_i64gather_pd;
+    unsafe fn i64gather_pd(
+        slice: *const f64, offsets: i64x4, scale: i32,
+    ) -> f64x4 = _mm256_i64gather_pd;
+    unsafe fn mask_i64gather_pd(
+        src: f64x4, slice: *const f64, offsets: i64x4, mask: f64x4, scale: i32,
+    ) -> f64x4 = _mm256_mask_i64gather_pd;
+    unsafe fn mm_i64gather_ps(
+        slice: *const f32, offsets: i64x2, scale: i32,
+    ) -> f32x4 = _mm_i64gather_ps;
+    unsafe fn mm_mask_i64gather_ps(
+        src: f32x4, slice: *const f32, offsets: i64x2, mask: f32x4, scale: i32,
+    ) -> f32x4 = _mm_mask_i64gather_ps;
+    unsafe fn i64gather_ps(
+        slice: *const f32, offsets: i64x4, scale: i32,
+    ) -> f32x4 = _mm256_i64gather_ps;
+    unsafe fn mask_i64gather_ps(
+        src: f32x4, slice: *const f32, offsets: i64x4, mask: f32x4, scale: i32,
+    ) -> f32x4 = _mm256_mask_i64gather_ps;
+    unsafe fn mm_maskload_epi32(
+        mem_addr: *const i32, mask: i32x4,
+    ) -> i32x4 = _mm_maskload_epi32;
+    unsafe fn maskload_epi32(
+        mem_addr: *const i32, mask: i32x8,
+    ) -> i32x8 = _mm256_maskload_epi32;
+    unsafe fn mm_maskload_epi64(
+        mem_addr: *const i64, mask: i64x2,
+    ) -> i64x2 = _mm_maskload_epi64;
+    unsafe fn maskload_epi64(
+        mem_addr: *const i64, mask: i64x4,
+    ) -> i64x4 = _mm256_maskload_epi64;
+    unsafe fn mm_maskstore_epi32(
+        mem_addr: *mut i32, mask: i32x4, a: i32x4,
+    ) -> () = _mm_maskstore_epi32;
+    unsafe fn maskstore_epi32(
+        mem_addr: *mut i32, mask: i32x8, a: i32x8,
+    ) -> () = _mm256_maskstore_epi32;
+    unsafe fn mm_maskstore_epi64(
+        mem_addr: *mut i64, mask: i64x2, a: i64x2,
+    ) -> () = _mm_maskstore_epi64;
+    unsafe fn maskstore_epi64(
+        mem_addr: *mut i64, mask: i64x4, a: i64x4,
+    ) -> () = _mm256_maskstore_epi64;
+    unsafe fn stream_load_si256(
+        mem_addr: *const __m256i,
+    ) -> __m256i = _mm256_stream_load_si256;
+}
+
+token_methods! {
+    Fma, "+fma";
+    fn mm_fmadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmadd_pd;
+    fn fmadd_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fmadd_pd;
+    fn mm_fmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fmadd_ps;
+    fn fmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_fmadd_ps;
+    fn fmadd_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmadd_sd;
+    fn fmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fmadd_ss;
+    fn mm_fmaddsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmaddsub_pd;
+    fn fmaddsub_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fmaddsub_pd;
+    fn mm_fmaddsub_ps(
+        a: __m128, b: __m128, c: __m128,
+    ) -> __m128 = _mm_fmaddsub_ps;
+    fn fmaddsub_ps(
+        a: __m256, b: __m256, c: __m256,
+    ) -> __m256 = _mm256_fmaddsub_ps;
+    fn mm_fmsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmsub_pd;
+    fn fmsub_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fmsub_pd;
+    fn mm_fmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fmsub_ps;
+    fn fmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_fmsub_ps;
+    fn fmsub_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmsub_sd;
+    fn fmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fmsub_ss;
+    fn mm_fmsubadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fmsubadd_pd;
+    fn fmsubadd_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fmsubadd_pd;
+    fn mm_fmsubadd_ps(
+        a: __m128, b: __m128, c: __m128,
+    ) -> __m128 = _mm_fmsubadd_ps;
+    fn fmsubadd_ps(
+        a: __m256, b: __m256, c: __m256,
+    ) -> __m256 = _mm256_fmsubadd_ps;
+    fn mm_fnmadd_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fnmadd_pd;
+    fn fnmadd_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fnmadd_pd;
+    fn mm_fnmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fnmadd_ps;
+    fn fnmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_fnmadd_ps;
+    fn fnmadd_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fnmadd_sd;
+    fn fnmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fnmadd_ss;
+    fn mm_fnmsub_pd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fnmsub_pd;
+    fn fnmsub_pd(
+        a: __m256d, b: __m256d, c: __m256d,
+    ) -> __m256d = _mm256_fnmsub_pd;
+    fn mm_fnmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fnmsub_ps;
+    fn fnmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_fnmsub_ps;
+    fn fnmsub_sd(a: f64x2, b: f64x2, c: f64x2) -> f64x2 = _mm_fnmsub_sd;
+    fn fnmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_fnmsub_ss;
+}
+
+token_methods! {
+    Bmi1, "+bmi";
+    fn andn_u32(a: u32, b: u32) -> u32 = _andn_u32;
+    fn bextr_u32(a: u32, start: u32, len: u32) -> u32 = _bextr_u32;
+    fn bextr2_u32(a: u32, control: u32) -> u32 = _bextr2_u32;
+    fn blsi_u32(x: u32) -> u32 = _blsi_u32;
+    fn blsmsk_u32(x: u32) -> u32 = _blsmsk_u32;
+    fn blsr_u32(x: u32) -> u32 = _blsr_u32;
+    fn tzcnt_u32(x: u32) -> u32 = _tzcnt_u32;
+}
+
+token_methods! {
+    Bmi2, "+bmi2";
+    fn mulx_u32(a: u32, b: u32, hi: &mut u32) -> u32 = _mulx_u32;
+    fn bzhi_u32(a: u32, index: u32) -> u32 = _bzhi_u32;
+    fn pdep_u32(a: u32, mask: u32) -> u32 = _pdep_u32;
+    fn pext_u32(a: u32, mask: u32) -> u32 = _pext_u32;
+}
+
+token_methods! {
+    Lzcnt, "+lzcnt";
+    fn lzcnt_u32(x: u32) -> u32 = _lzcnt_u32;
+}
+
+token_methods! {
+    Popcnt, "+popcnt";
+    fn popcnt32(x: i32) -> i32 = _popcnt32;
+}
+
+token_methods! {
+    Aes, "+aes";
+    fn aesdec_si128(
+        a: __m128i, round_key: __m128i,
+    ) -> __m128i = _mm_aesdec_si128;
+    fn aesdeclast_si128(
+        a: __m128i, round_key: __m128i,
+    ) -> __m128i = _mm_aesdeclast_si128;
+    fn aesenc_si128(
+        a: __m128i, round_key: __m128i,
+    ) -> __m128i = _mm_aesenc_si128;
+    fn aesenclast_si128(
+        a: __m128i, round_key: __m128i,
+    ) -> __m128i = _mm_aesenclast_si128;
+    fn aesimc_si128(a: __m128i) -> __m128i = _mm_aesimc_si128;
+    fn aeskeygenassist_si128(
+        a: __m128i, imm8: i32,
+    ) -> __m128i = _mm_aeskeygenassist_si128;
+}
+
+token_methods! {
+    Pclmulqdq, "+pclmul";
+    fn clmulepi64_si128(
+        a: __m128i, b: __m128i, imm8: i32,
+    ) -> __m128i = _mm_clmulepi64_si128;
+}
+
+#[cfg(test)]
+mod tests {
+    use v128::*;
+    use x86::cpuid;
+    use x86::token::{Avx2, Sse2, Sse42};
+
+    #[test]
+    fn detect_matches_cpu_features() {
+        let f = cpuid::cpu_features();
+        assert_eq!(Sse2::detect().is_some(), f.sse2());
+        assert_eq!(Sse42::detect().is_some(), f.sse42());
+        assert_eq!(Avx2::detect().is_some(), f.avx2());
+    }
+
+    #[test]
+    fn sse2_methods() {
+        if let Some(sse2) = Sse2::detect() {
+            let r = sse2.add_epi8(i8x16::splat(1), i8x16::splat(2));
+            assert_eq!(r, i8x16::splat(3));
+        }
+    }
+}